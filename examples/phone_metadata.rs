@@ -0,0 +1,380 @@
+//! Generates the phone numbering tables in `src/validation/phone/` from
+//! libphonenumber's `PhoneNumberMetadata.xml`
+//!
+//! ```sh
+//! cargo run --example phone_metadata --features validation -- \
+//!     path/to/PhoneNumberMetadata.xml 9.0.21 src/validation/phone
+//! ```
+//!
+//! The XML lives in libphonenumber's `resources/` directory. The second
+//! argument is the libphonenumber release it came from, recorded in the
+//! generated files. `regions.rs` is always compiled; `metadata.rs` holds
+//! the numbering plans behind the `phone_metadata` feature.
+
+use regex::Regex;
+use std::fmt::Write;
+use std::path::Path;
+use std::{env, fs, process};
+
+struct Territory {
+    id: String,
+    code: u16,
+    international_prefix: Option<String>,
+    national_prefix: Option<String>,
+    main: bool,
+    leading_digits: Option<String>,
+    national_prefix_for_parsing: Option<String>,
+    national_prefix_transform_rule: Option<String>,
+    general: String,
+    lengths: Vec<u8>,
+    formats: Vec<NumberFormat>,
+    types: Vec<NumberDesc>,
+}
+
+struct NumberFormat {
+    pattern: String,
+    leading_digits: Vec<String>,
+    format: String,
+    national_format: String,
+    intl_format: Option<String>,
+}
+
+/// A number range of one type, e.g. a region's mobile numbers
+struct NumberDesc {
+    kind: String,
+    pattern: String,
+    lengths: Vec<u8>,
+}
+
+/// Number descriptions, classified in this order; their lengths together
+/// make up the region's valid lengths
+const NUMBER_TYPES: [&str; 10] = [
+    "fixedLine",
+    "mobile",
+    "tollFree",
+    "premiumRate",
+    "sharedCost",
+    "personalNumber",
+    "voip",
+    "pager",
+    "uan",
+    "voicemail",
+];
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let [xml, version, out] = &args[..] else {
+        eprintln!("usage: phone_metadata <PhoneNumberMetadata.xml> <version> <output dir>");
+        process::exit(2);
+    };
+
+    let xml = fs::read_to_string(xml).unwrap_or_else(|e| {
+        eprintln!("cannot read {xml}: {e}");
+        process::exit(1);
+    });
+    let territories = territories(&strip_comments(&xml));
+
+    let out = Path::new(out);
+    write(&out.join("regions.rs"), &regions(&territories, version));
+    write(&out.join("metadata.rs"), &metadata(&territories, version));
+}
+
+fn write(path: &Path, contents: &str) {
+    fs::write(path, contents).unwrap_or_else(|e| {
+        eprintln!("cannot write {}: {e}", path.display());
+        process::exit(1);
+    });
+    eprintln!("wrote {}", path.display());
+}
+
+fn strip_comments(xml: &str) -> String {
+    Regex::new(r"(?s)<!--.*?-->")
+        .unwrap()
+        .replace_all(xml, "")
+        .into_owned()
+}
+
+/// The value of `name="..."` inside an opening tag
+fn attr(tag: &str, name: &str) -> Option<String> {
+    let re = Regex::new(&format!(r#"\s{name}="([^"]*)""#)).unwrap();
+    re.captures(tag).map(|c| unescape(&c[1]))
+}
+
+fn unescape(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn territories(xml: &str) -> Vec<Territory> {
+    let re = Regex::new(r"(?s)<territory\s[^>]*>.*?</territory>").unwrap();
+    let tag = Regex::new(r"(?s)^<territory\s[^>]*>").unwrap();
+
+    re.find_iter(xml)
+        .map(|block| {
+            let block = block.as_str();
+            let tag = tag.find(block).unwrap().as_str();
+            let id = attr(tag, "id").expect("territory id");
+
+            let general = element(block, "generalDesc")
+                .and_then(|desc| element(&desc, "nationalNumberPattern"))
+                .map(|pattern| pattern_of(&id, &pattern))
+                .unwrap_or_else(|| panic!("{id}: no generalDesc pattern"));
+
+            let possible = Regex::new(r"<possibleLengths\s[^>]*>").unwrap();
+            let descs = NUMBER_TYPES
+                .iter()
+                .filter_map(|name| Some((*name, element(block, name)?)))
+                .map(|(name, desc)| {
+                    let lengths = possible
+                        .find(&desc)
+                        .and_then(|tag| attr(tag.as_str(), "national"))
+                        .map(|lengths| parse_lengths(&id, &lengths));
+                    let pattern = element(&desc, "nationalNumberPattern");
+                    (name, pattern.map(|p| pattern_of(&id, &p)), lengths)
+                })
+                .collect::<Vec<_>>();
+
+            let mut lengths = descs
+                .iter()
+                .flat_map(|(_, _, lengths)| lengths.iter().flatten().copied())
+                .collect::<Vec<_>>();
+            lengths.sort_unstable();
+            lengths.dedup();
+
+            // A description without lengths of its own takes the region's
+            let types = descs
+                .into_iter()
+                .filter_map(|(name, pattern, own)| {
+                    Some(NumberDesc {
+                        kind: name[..1].to_uppercase() + &name[1..],
+                        pattern: pattern?,
+                        lengths: own.unwrap_or_else(|| lengths.clone()),
+                    })
+                })
+                .collect();
+
+            let national_prefix = attr(tag, "nationalPrefix");
+            let formats = number_formats(
+                &id,
+                block,
+                national_prefix.as_deref().unwrap_or_default(),
+                attr(tag, "nationalPrefixFormattingRule"),
+            );
+
+            Territory {
+                code: attr(tag, "countryCode")
+                    .and_then(|code| code.parse().ok())
+                    .expect("territory countryCode"),
+                international_prefix: attr(tag, "internationalPrefix"),
+                national_prefix,
+                main: attr(tag, "mainCountryForCode").as_deref() == Some("true"),
+                leading_digits: attr(tag, "leadingDigits").map(|p| pattern_of(&id, &p)),
+                national_prefix_for_parsing: attr(tag, "nationalPrefixForParsing")
+                    .or_else(|| attr(tag, "nationalPrefix"))
+                    .map(|p| pattern_of(&id, &p)),
+                national_prefix_transform_rule: attr(tag, "nationalPrefixTransformRule"),
+                general,
+                lengths,
+                formats,
+                types,
+                id,
+            }
+        })
+        .collect()
+}
+
+/// A territory's `<numberFormat>`s, with the trunk prefix rule folded into
+/// a separate national format and `$1` written as `${1}` for `regex`
+fn number_formats(
+    id: &str,
+    block: &str,
+    national_prefix: &str,
+    default_rule: Option<String>,
+) -> Vec<NumberFormat> {
+    let re = Regex::new(r"(?s)<numberFormat\s([^>]*)>(.*?)</numberFormat>").unwrap();
+    let leading = Regex::new(r"(?s)<leadingDigits>(.*?)</leadingDigits>").unwrap();
+    let group = Regex::new(r"\$\d").unwrap();
+    let braced = |format: &str| {
+        Regex::new(r"\$(\d)")
+            .unwrap()
+            .replace_all(format, "$${$1}")
+            .into_owned()
+    };
+
+    re.captures_iter(block)
+        .map(|c| {
+            let tag = format!(" {}", &c[1]);
+            let body = &c[2];
+            let format = element(body, "format").unwrap_or_else(|| panic!("{id}: format"));
+
+            // `$NP` is the trunk prefix, `$FG` the first group it attaches to
+            let national_format =
+                match attr(&tag, "nationalPrefixFormattingRule").or(default_rule.clone()) {
+                    Some(rule) => {
+                        let first = group
+                            .find(&format)
+                            .unwrap_or_else(|| panic!("{id}: {format}"));
+                        let rule = rule
+                            .replace("$NP", national_prefix)
+                            .replace("$FG", first.as_str());
+                        format!(
+                            "{}{rule}{}",
+                            &format[..first.start()],
+                            &format[first.end()..]
+                        )
+                    }
+                    None => format.clone(),
+                };
+            let intl_format = match element(body, "intlFormat") {
+                Some(intl) if intl == "NA" => None,
+                Some(intl) => Some(braced(&intl)),
+                None => Some(braced(&format)),
+            };
+
+            NumberFormat {
+                pattern: pattern_of(id, &attr(&tag, "pattern").expect("numberFormat pattern")),
+                leading_digits: leading
+                    .captures_iter(body)
+                    .map(|c| pattern_of(id, &unescape(&c[1])))
+                    .collect(),
+                format: braced(&format),
+                national_format: braced(&national_format),
+                intl_format,
+            }
+        })
+        .collect()
+}
+
+/// The text inside the first `<name>...</name>` element
+fn element(xml: &str, name: &str) -> Option<String> {
+    let re = Regex::new(&format!(r"(?s)<{name}>(.*?)</{name}>")).unwrap();
+    re.captures(xml).map(|c| unescape(&c[1]))
+}
+
+/// A pattern with the XML's layout whitespace removed, checked to compile
+/// with the `regex` crate the library matches it with
+fn pattern_of(id: &str, pattern: &str) -> String {
+    let pattern = pattern.split_whitespace().collect::<String>();
+    if let Err(e) = Regex::new(&pattern) {
+        panic!("{id}: pattern {pattern:?} does not compile: {e}");
+    }
+    pattern
+}
+
+/// `"[4-8],10"` as `[4, 5, 6, 7, 8, 10]`
+fn parse_lengths(id: &str, lengths: &str) -> Vec<u8> {
+    let number = |s: &str| {
+        s.trim()
+            .parse::<u8>()
+            .unwrap_or_else(|_| panic!("{id}: bad possible length {lengths:?}"))
+    };
+
+    lengths
+        .split(',')
+        .flat_map(|part| match part.trim().strip_prefix('[') {
+            Some(range) => {
+                let (from, to) = range.trim_end_matches(']').split_once('-').unwrap();
+                (number(from)..=number(to)).collect::<Vec<_>>()
+            }
+            None => vec![number(part)],
+        })
+        .collect()
+}
+
+fn header(version: &str) -> String {
+    format!(
+        "// @generated by examples/phone_metadata.rs from libphonenumber {version}\n\
+         // PhoneNumberMetadata.xml (Apache License 2.0). Do not edit by hand.\n\n"
+    )
+}
+
+fn string(s: &Option<String>) -> String {
+    match s {
+        Some(s) => format!("Some({s:?})"),
+        None => "None".to_string(),
+    }
+}
+
+fn regions(territories: &[Territory], version: &str) -> String {
+    let mut out = header(version);
+    writeln!(out, "use super::Region;\n").unwrap();
+    writeln!(
+        out,
+        "pub(crate) static REGIONS: [Region; {}] = [",
+        territories.len()
+    )
+    .unwrap();
+
+    for t in territories {
+        writeln!(
+            out,
+            "    Region {{ id: {:?}, code: {}, international_prefix: {}, national_prefix: {}, main: {} }},",
+            t.id,
+            t.code,
+            string(&t.international_prefix),
+            string(&t.national_prefix),
+            t.main
+        )
+        .unwrap();
+    }
+
+    writeln!(out, "];").unwrap();
+    out
+}
+
+fn metadata(territories: &[Territory], version: &str) -> String {
+    let mut out = header(version);
+    writeln!(
+        out,
+        "use super::{{Metadata, NumberDesc, NumberFormat, PhoneType}};\n"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "pub(crate) static METADATA: [Metadata; {}] = [",
+        territories.len()
+    )
+    .unwrap();
+
+    for t in territories {
+        writeln!(
+            out,
+            "    // {}\n    Metadata {{ general: {:?}, lengths: &{:?}, leading_digits: {}, national_prefix_for_parsing: {}, national_prefix_transform_rule: {}, formats: &[",
+            t.id,
+            t.general,
+            t.lengths,
+            string(&t.leading_digits),
+            string(&t.national_prefix_for_parsing),
+            string(&t.national_prefix_transform_rule)
+        )
+        .unwrap();
+        for f in &t.formats {
+            writeln!(
+                out,
+                "        NumberFormat {{ pattern: {:?}, leading_digits: &{:?}, format: {:?}, national_format: {:?}, intl_format: {} }},",
+                f.pattern,
+                f.leading_digits,
+                f.format,
+                f.national_format,
+                string(&f.intl_format)
+            )
+            .unwrap();
+        }
+        writeln!(out, "    ], types: &[").unwrap();
+        for d in &t.types {
+            writeln!(
+                out,
+                "        (PhoneType::{}, NumberDesc {{ pattern: {:?}, lengths: &{:?} }}),",
+                d.kind, d.pattern, d.lengths
+            )
+            .unwrap();
+        }
+        writeln!(out, "    ] }},").unwrap();
+    }
+
+    writeln!(out, "];").unwrap();
+    out
+}
@@ -0,0 +1,230 @@
+use clap::{Args, Subcommand};
+use serde_json::json;
+use std::error::Error;
+use std::net::{IpAddr, Ipv4Addr};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use toolbox::ip::{
+    Bind, Cidr, Dialer, Family, IpValidationResult, Lookup, Provider, Proxy, ProxyConfig,
+    validate_ip_detailed,
+};
+
+#[derive(Subcommand)]
+pub enum IpCommand {
+    /// Discover this host's public address
+    Public(LookupArgs),
+
+    /// Classify an address as public, private, loopback, ...
+    Classify {
+        addr: String,
+
+        /// Print JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Show the details of a subnet, e.g. `192.0.2.0/24`
+    Cidr {
+        net: Cidr,
+
+        /// Print JSON
+        #[arg(long)]
+        json: bool,
+    },
+
+    /// Print the public address, then again whenever it changes
+    Watch {
+        #[command(flatten)]
+        lookup: LookupArgs,
+
+        /// Seconds between lookups
+        #[arg(long, default_value_t = 60, value_parser = clap::value_parser!(u64).range(1..))]
+        interval: u64,
+    },
+}
+
+#[derive(Args)]
+pub struct LookupArgs {
+    /// Only look up the IPv4 address
+    #[arg(long, conflicts_with = "v6")]
+    v4: bool,
+
+    /// Only look up the IPv6 address
+    #[arg(long)]
+    v6: bool,
+
+    /// Comma separated providers: `host`, `host:port` or `http://host:port/path`
+    #[arg(long, value_delimiter = ',')]
+    providers: Vec<Provider>,
+
+    /// How many providers must agree on the address
+    #[arg(long, default_value_t = 1)]
+    consensus: usize,
+
+    /// Start a second provider when the first is slower than usual
+    #[arg(long)]
+    hedge: bool,
+
+    /// Per-provider timeout in seconds
+    #[arg(long, default_value_t = 10)]
+    timeout: u64,
+
+    /// Proxy URL such as `http://proxy:3128` or `socks5h://127.0.0.1:1080`;
+    /// `direct` ignores HTTP_PROXY/ALL_PROXY
+    #[arg(long)]
+    proxy: Option<String>,
+
+    /// Local source address to connect from
+    #[arg(long)]
+    bind: Option<IpAddr>,
+
+    /// Network interface to connect through
+    #[arg(long)]
+    interface: Option<String>,
+
+    /// Print JSON
+    #[arg(long)]
+    json: bool,
+}
+
+impl LookupArgs {
+    fn lookup(&self) -> Result<Lookup, Box<dyn Error>> {
+        let mut dialer = Dialer::new(Some(Duration::from_secs(self.timeout)));
+
+        dialer.proxy = match self.proxy.as_deref() {
+            None => ProxyConfig::Env,
+            Some("direct") => ProxyConfig::Direct,
+            Some(url) => ProxyConfig::Proxy(url.parse::<Proxy>()?),
+        };
+
+        if self.bind.is_some() || self.interface.is_some() {
+            dialer.bind = Some(Bind {
+                addr: self.bind,
+                interface: self.interface.clone(),
+            });
+        }
+
+        let mut providers = self.providers.clone();
+        if self.v4 {
+            dialer.family = Some(Family::V4);
+        }
+        if self.v6 {
+            dialer.family = Some(Family::V6);
+            if providers.is_empty() {
+                providers = Provider::defaults_v6();
+            }
+        }
+        if providers.is_empty() {
+            providers = Provider::defaults();
+        }
+
+        Ok(Lookup::new()
+            .providers(providers)
+            .dialer(dialer)
+            .consensus(self.consensus)
+            .hedge(self.hedge))
+    }
+}
+
+pub fn run(command: IpCommand) -> Result<(), Box<dyn Error>> {
+    match command {
+        IpCommand::Public(args) => {
+            let ip = args.lookup()?.run()?;
+            print_ip(ip, args.json);
+        }
+        IpCommand::Classify { addr, json } => classify(&addr, json)?,
+        IpCommand::Cidr { net, json } => cidr(&net, json),
+        IpCommand::Watch { lookup, interval } => watch(&lookup, Duration::from_secs(interval))?,
+    }
+
+    Ok(())
+}
+
+fn print_ip(ip: IpAddr, as_json: bool) {
+    match as_json {
+        true => println!(
+            "{}",
+            json!({ "ip": ip, "version": if ip.is_ipv4() { 4 } else { 6 } })
+        ),
+        false => println!("{ip}"),
+    }
+}
+
+fn classify(addr: &str, as_json: bool) -> Result<(), Box<dyn Error>> {
+    let result = validate_ip_detailed(addr);
+    if result == IpValidationResult::InvalidFormat {
+        return Err(format!("not an IP address: {addr}").into());
+    }
+    let class = result.class();
+
+    match as_json {
+        true => println!("{}", json!({ "address": addr.trim(), "class": class })),
+        false => println!("{}: {class}", addr.trim()),
+    }
+
+    Ok(())
+}
+
+fn cidr(net: &Cidr, as_json: bool) {
+    let (first, last) = (net.network(), net.last());
+
+    // IPv4 networks larger than a /31 lose the network and broadcast addresses
+    let hosts = match (first, last) {
+        (IpAddr::V4(first), IpAddr::V4(last)) if net.prefix() < 31 => Some((
+            IpAddr::V4(Ipv4Addr::from(u32::from(first) + 1)),
+            IpAddr::V4(Ipv4Addr::from(u32::from(last) - 1)),
+            net.size() - 2,
+        )),
+        _ => None,
+    };
+    let (host_min, host_max, host_count) = hosts.unwrap_or((first, last, net.size()));
+
+    match as_json {
+        true => println!(
+            "{}",
+            json!({
+                "network": net.to_string(),
+                "netmask": net.netmask(),
+                "prefix": net.prefix(),
+                "first": first,
+                "last": last,
+                "size": net.size().to_string(),
+                "host_min": host_min,
+                "host_max": host_max,
+                "hosts": host_count.to_string(),
+            })
+        ),
+        false => {
+            println!("Network:   {net}");
+            println!("Netmask:   {}", net.netmask());
+            println!("First:     {first}");
+            println!("Last:      {last}");
+            println!("Addresses: {}", net.size());
+            println!("Hosts:     {host_min} - {host_max} ({host_count})");
+        }
+    }
+}
+
+fn watch(args: &LookupArgs, interval: Duration) -> Result<(), Box<dyn Error>> {
+    let lookup = args.lookup()?;
+    let mut current = None;
+
+    loop {
+        match lookup.run() {
+            Ok(ip) if current != Some(ip) => {
+                current = Some(ip);
+                match args.json {
+                    true => {
+                        let at = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+                        println!("{}", json!({ "ip": ip, "at": at }));
+                    }
+                    false => print_ip(ip, false),
+                }
+            }
+            Ok(_) => {}
+            Err(e) => eprintln!("error: {e}"),
+        }
+
+        thread::sleep(interval);
+    }
+}
@@ -0,0 +1,71 @@
+//! The `toolbox` command line
+
+mod ip;
+
+use clap::{Parser, Subcommand};
+use std::process::ExitCode;
+
+#[derive(Parser)]
+#[command(
+    name = "toolbox",
+    version,
+    about = "Everyday tools from the toolbox crate"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// IP address tools
+    #[command(subcommand)]
+    Ip(ip::IpCommand),
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Ip(command) => ip::run(command),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    #[test]
+    fn test_cli() {
+        Cli::command().debug_assert();
+
+        let cli = Cli::try_parse_from([
+            "toolbox",
+            "ip",
+            "public",
+            "--v6",
+            "--consensus",
+            "2",
+            "--json",
+        ]);
+        assert!(cli.is_ok());
+
+        let cli = Cli::try_parse_from(["toolbox", "ip", "public", "--v4", "--v6"]);
+        assert!(cli.is_err());
+
+        let cli = Cli::try_parse_from(["toolbox", "ip", "cidr", "10.0.0.0/33"]);
+        assert!(cli.is_err());
+
+        let cli = Cli::try_parse_from(["toolbox", "ip", "watch", "--interval", "0"]);
+        assert!(cli.is_err());
+    }
+}
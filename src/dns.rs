@@ -0,0 +1,518 @@
+//! A small DNS client (RFC 1035) for the lookups the other modules need:
+//! A/AAAA, MX and TXT records over UDP
+//!
+//! Everything goes through the [`Resolver`] trait, so callers can swap in a
+//! local stub server or a closure in tests.
+
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::BuildHasher;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, UdpSocket};
+use std::time::{Duration, Instant};
+
+/// Where nameservers are read from on Unix systems
+const RESOLV_CONF: &str = "/etc/resolv.conf";
+
+/// Largest response accepted over UDP
+const MAX_RESPONSE: usize = 4096;
+
+/// The record types this client understands
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum RecordType {
+    A,
+    Aaaa,
+    Mx,
+    Txt,
+}
+
+impl RecordType {
+    fn code(self) -> u16 {
+        match self {
+            RecordType::A => 1,
+            RecordType::Mx => 15,
+            RecordType::Txt => 16,
+            RecordType::Aaaa => 28,
+        }
+    }
+}
+
+/// One answer record
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Record {
+    A(Ipv4Addr),
+    Aaaa(Ipv6Addr),
+    Mx { preference: u16, exchange: String },
+    Txt(String),
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Record::A(ip) => write!(f, "A {ip}"),
+            Record::Aaaa(ip) => write!(f, "AAAA {ip}"),
+            Record::Mx {
+                preference,
+                exchange,
+            } => write!(f, "MX {preference} {exchange}"),
+            Record::Txt(text) => write!(f, "TXT {text:?}"),
+        }
+    }
+}
+
+/// Answers DNS questions
+///
+/// A name that does not exist (NXDOMAIN) is an empty answer, not an error.
+/// Closures implement it too, which keeps tests short:
+///
+/// ```
+/// use toolbox::dns::{Record, RecordType, Resolver};
+///
+/// let stub = |name: &str, _: RecordType| match name {
+///     "2.0.0.127.zen.example" => Ok(vec![Record::A([127, 0, 0, 2].into())]),
+///     _ => Ok(vec![]),
+/// };
+/// assert_eq!(stub.query("2.0.0.127.zen.example", RecordType::A).unwrap().len(), 1);
+/// ```
+pub trait Resolver: Send + Sync {
+    fn query(&self, name: &str, rtype: RecordType) -> io::Result<Vec<Record>>;
+
+    /// A and AAAA records of `name`
+    fn lookup_ip(&self, name: &str) -> io::Result<Vec<IpAddr>> {
+        let mut ips = Vec::new();
+        for rtype in [RecordType::A, RecordType::Aaaa] {
+            for record in self.query(name, rtype)? {
+                match record {
+                    Record::A(ip) => ips.push(IpAddr::V4(ip)),
+                    Record::Aaaa(ip) => ips.push(IpAddr::V6(ip)),
+                    _ => {}
+                }
+            }
+        }
+        Ok(ips)
+    }
+}
+
+impl<F> Resolver for F
+where
+    F: Fn(&str, RecordType) -> io::Result<Vec<Record>> + Send + Sync,
+{
+    fn query(&self, name: &str, rtype: RecordType) -> io::Result<Vec<Record>> {
+        self(name, rtype)
+    }
+}
+
+/// Queries nameservers directly over UDP, trying each in turn
+///
+/// Each query gets a random ID, and only a response with that ID and the
+/// same question is taken; anything else is ignored as stray or forged.
+/// There is no TCP fallback, so a truncated response is an error.
+#[derive(Clone, Debug)]
+pub struct UdpResolver {
+    pub servers: Vec<SocketAddr>,
+    pub timeout: Duration,
+
+    /// Extra attempts per server after a timeout
+    pub retries: usize,
+}
+
+impl UdpResolver {
+    pub fn new(servers: Vec<SocketAddr>) -> Self {
+        Self {
+            servers,
+            timeout: Duration::from_secs(3),
+            retries: 1,
+        }
+    }
+
+    /// The nameservers from `/etc/resolv.conf`
+    pub fn system() -> io::Result<Self> {
+        let conf = std::fs::read_to_string(RESOLV_CONF)?;
+        let servers = parse_resolv_conf(&conf);
+        if servers.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("no nameserver in {RESOLV_CONF}"),
+            ));
+        }
+        Ok(Self::new(servers))
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    fn exchange(
+        &self,
+        server: SocketAddr,
+        name: &str,
+        rtype: RecordType,
+    ) -> io::Result<Vec<Record>> {
+        let id = query_id();
+        let deadline = Instant::now() + self.timeout;
+
+        let local: SocketAddr = match server {
+            SocketAddr::V4(_) => (Ipv4Addr::UNSPECIFIED, 0).into(),
+            SocketAddr::V6(_) => (Ipv6Addr::UNSPECIFIED, 0).into(),
+        };
+        let socket = UdpSocket::bind(local)?;
+        socket.connect(server)?;
+        socket.send(&encode_query(id, name, rtype)?)?;
+
+        let mut buf = [0u8; MAX_RESPONSE];
+        loop {
+            // Stray datagrams must not keep us past the timeout
+            let left = deadline.saturating_duration_since(Instant::now());
+            if left.is_zero() {
+                return Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    format!("no answer from {server}"),
+                ));
+            }
+            socket.set_read_timeout(Some(left))?;
+
+            let n = socket.recv(&mut buf)?;
+            if let Some(records) = decode_response(id, name, rtype, &buf[..n]) {
+                return records;
+            }
+        }
+    }
+}
+
+impl Resolver for UdpResolver {
+    fn query(&self, name: &str, rtype: RecordType) -> io::Result<Vec<Record>> {
+        let mut last = io::Error::new(io::ErrorKind::NotFound, "no nameservers configured");
+
+        for server in &self.servers {
+            for _ in 0..=self.retries {
+                match self.exchange(*server, name, rtype) {
+                    Ok(records) => return Ok(records),
+                    Err(e)
+                        if matches!(
+                            e.kind(),
+                            io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut
+                        ) =>
+                    {
+                        last = e
+                    }
+                    Err(e) => {
+                        last = e;
+                        break;
+                    }
+                }
+            }
+        }
+
+        Err(last)
+    }
+}
+
+fn parse_resolv_conf(conf: &str) -> Vec<SocketAddr> {
+    conf.lines()
+        .filter_map(|line| line.trim().strip_prefix("nameserver"))
+        .filter_map(|addr| addr.split_whitespace().next())
+        // Drop a zone index, e.g. `fe80::1%eth0`
+        .filter_map(|addr| addr.split('%').next()?.parse::<IpAddr>().ok())
+        .map(|ip| SocketAddr::new(ip, 53))
+        .collect()
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.to_string())
+}
+
+/// An ID an off-path attacker cannot guess; `RandomState` keys are seeded
+/// from the OS's random source
+fn query_id() -> u16 {
+    RandomState::new().hash_one(Instant::now()) as u16
+}
+
+/// A recursive query for one question
+fn encode_query(id: u16, name: &str, rtype: RecordType) -> io::Result<Vec<u8>> {
+    let mut packet = Vec::with_capacity(32 + name.len());
+    packet.extend_from_slice(&id.to_be_bytes());
+    // Standard query, recursion desired
+    packet.extend_from_slice(&[0x01, 0x00]);
+    // One question, no answer, authority or additional records
+    packet.extend_from_slice(&[0, 1, 0, 0, 0, 0, 0, 0]);
+
+    for label in name.trim_end_matches('.').split('.') {
+        if label.is_empty() || label.len() > 63 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid DNS name {name}"),
+            ));
+        }
+        packet.push(label.len() as u8);
+        packet.extend_from_slice(label.as_bytes());
+    }
+    packet.push(0);
+
+    packet.extend_from_slice(&rtype.code().to_be_bytes());
+    packet.extend_from_slice(&1u16.to_be_bytes());
+    Ok(packet)
+}
+
+/// The answers in a response to query `id` for `name` and `rtype`, or
+/// `None` if the packet answers something else or does not parse, as a
+/// stray or forged datagram might not
+fn decode_response(
+    id: u16,
+    name: &str,
+    rtype: RecordType,
+    packet: &[u8],
+) -> Option<io::Result<Vec<Record>>> {
+    if packet.len() < 12 {
+        return None;
+    }
+    let u16_at = |at: usize| u16::from_be_bytes([packet[at], packet[at + 1]]);
+
+    if u16_at(0) != id || packet[2] & 0x80 == 0 || u16_at(4) != 1 {
+        return None;
+    }
+    let (asked, next) = read_name(packet, 12).ok()?;
+    let question = [rtype.code().to_be_bytes(), 1u16.to_be_bytes()].concat();
+    if !asked.eq_ignore_ascii_case(name.trim_end_matches('.'))
+        || packet.get(next..next + 4)? != question
+    {
+        return None;
+    }
+
+    // TC: the answer did not fit in a datagram
+    if packet[2] & 0x02 != 0 {
+        return Some(Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("truncated DNS response for {name}, and TCP is not supported"),
+        )));
+    }
+    match packet[3] & 0x0f {
+        0 => {}
+        // NXDOMAIN
+        3 => return Some(Ok(Vec::new())),
+        rcode => {
+            return Some(Err(io::Error::other(format!(
+                "DNS server answered with rcode {rcode}"
+            ))));
+        }
+    }
+
+    decode_answers(packet, u16_at(6), next + 4).ok().map(Ok)
+}
+
+/// The `answers` records starting at `at`
+fn decode_answers(packet: &[u8], answers: u16, mut at: usize) -> io::Result<Vec<Record>> {
+    let u16_at = |at: usize| u16::from_be_bytes([packet[at], packet[at + 1]]);
+
+    let mut records = Vec::new();
+    for _ in 0..answers {
+        let (_, next) = read_name(packet, at)?;
+        if next + 10 > packet.len() {
+            return Err(invalid("truncated DNS record"));
+        }
+        let (rtype, len) = (u16_at(next), u16_at(next + 8) as usize);
+        let data = next + 10;
+        if data + len > packet.len() {
+            return Err(invalid("truncated DNS record"));
+        }
+        let rdata = &packet[data..data + len];
+
+        match (rtype, len) {
+            (1, 4) => records.push(Record::A(Ipv4Addr::new(
+                rdata[0], rdata[1], rdata[2], rdata[3],
+            ))),
+            (28, 16) => {
+                let octets: [u8; 16] = rdata.try_into().expect("length checked");
+                records.push(Record::Aaaa(Ipv6Addr::from(octets)));
+            }
+            (15, 3..) => records.push(Record::Mx {
+                preference: u16_at(data),
+                exchange: read_name(packet, data + 2)?.0,
+            }),
+            (16, _) => {
+                // One or more length-prefixed strings
+                let mut text = Vec::new();
+                let mut i = 0;
+                while i < rdata.len() {
+                    let n = rdata[i] as usize;
+                    text.extend_from_slice(
+                        rdata
+                            .get(i + 1..i + 1 + n)
+                            .ok_or_else(|| invalid("truncated TXT record"))?,
+                    );
+                    i += 1 + n;
+                }
+                records.push(Record::Txt(String::from_utf8_lossy(&text).into_owned()));
+            }
+            // CNAMEs and anything else we did not ask for
+            _ => {}
+        }
+        at = data + len;
+    }
+
+    Ok(records)
+}
+
+/// Read a possibly compressed name at `at`, returning it and the offset just
+/// past it
+fn read_name(packet: &[u8], mut at: usize) -> io::Result<(String, usize)> {
+    let mut labels = Vec::new();
+    let mut end = None;
+
+    // Bounded so a pointer loop cannot spin forever
+    for _ in 0..128 {
+        let len = *packet
+            .get(at)
+            .ok_or_else(|| invalid("truncated DNS name"))? as usize;
+        match len {
+            0 => {
+                return Ok((labels.join("."), end.unwrap_or(at + 1)));
+            }
+            0xc0.. => {
+                let low = *packet
+                    .get(at + 1)
+                    .ok_or_else(|| invalid("truncated DNS name"))?;
+                end.get_or_insert(at + 2);
+                at = ((len & 0x3f) << 8) | low as usize;
+            }
+            1..=63 => {
+                let label = packet
+                    .get(at + 1..at + 1 + len)
+                    .ok_or_else(|| invalid("truncated DNS name"))?;
+                labels.push(String::from_utf8_lossy(label).to_lowercase());
+                at += 1 + len;
+            }
+            _ => return Err(invalid("invalid DNS label")),
+        }
+    }
+
+    Err(invalid("DNS name compression loop"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    /// Answer one query with the datagrams `reply` makes from it, in order
+    fn serve(reply: impl FnOnce(&[u8]) -> Vec<Vec<u8>> + Send + 'static) -> SocketAddr {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let addr = socket.local_addr().unwrap();
+
+        thread::spawn(move || {
+            let mut buf = [0u8; 512];
+            let (n, peer) = socket.recv_from(&mut buf).unwrap();
+            for datagram in reply(&buf[..n]) {
+                socket.send_to(&datagram, peer).unwrap();
+            }
+        });
+
+        addr
+    }
+
+    /// A response to `query` with a canned answer section; `tc` sets the
+    /// truncated bit
+    fn respond(query: &[u8], answers: &[(u16, Vec<u8>)], tc: bool, rcode: u8) -> Vec<u8> {
+        let mut response = query[..2].to_vec();
+        response.extend_from_slice(&[0x81 | u8::from(tc) << 1, 0x80 | rcode, 0, 1]);
+        response.extend_from_slice(&(answers.len() as u16).to_be_bytes());
+        response.extend_from_slice(&[0, 0, 0, 0]);
+        response.extend_from_slice(&query[12..]);
+        for (rtype, rdata) in answers {
+            // Name as a pointer to the question
+            response.extend_from_slice(&[0xc0, 12]);
+            response.extend_from_slice(&rtype.to_be_bytes());
+            response.extend_from_slice(&[0, 1, 0, 0, 1, 0]);
+            response.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+            response.extend_from_slice(rdata);
+        }
+        response
+    }
+
+    fn stub(answers: Vec<(u16, Vec<u8>)>, rcode: u8) -> SocketAddr {
+        serve(move |query| vec![respond(query, &answers, false, rcode)])
+    }
+
+    #[test]
+    fn test_query() {
+        let mx = [&[0u8, 10][..], b"\x02mx\xc0\x0c"].concat();
+        let server = stub(vec![(15, mx), (16, b"\x05hello\x06 world".to_vec())], 0);
+        let records = UdpResolver::new(vec![server])
+            .query("Example.COM", RecordType::Mx)
+            .unwrap();
+
+        assert_eq!(
+            records,
+            [
+                Record::Mx {
+                    preference: 10,
+                    exchange: "mx.example.com".to_string()
+                },
+                Record::Txt("hello world".to_string()),
+            ]
+        );
+
+        let server = stub(vec![], 3);
+        let resolver = UdpResolver::new(vec![server]);
+        assert_eq!(
+            resolver.query("missing.example", RecordType::A).unwrap(),
+            []
+        );
+
+        assert_eq!(
+            parse_resolv_conf(
+                "# local\nnameserver 127.0.0.53\nnameserver fe80::1%eth0\nsearch lan\n"
+            ),
+            [
+                "127.0.0.53:53".parse().unwrap(),
+                "[fe80::1]:53".parse().unwrap()
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stray_packets() {
+        let real = vec![(1, vec![192, 0, 2, 1])];
+        let forged = vec![(1, vec![192, 0, 2, 66])];
+        let server = serve(move |query| {
+            let mut other_id = query.to_vec();
+            other_id[0] ^= 0xff;
+            let mut other_name = query.to_vec();
+            other_name[13] = b'z';
+            let mut other_type = query.to_vec();
+            other_type[query.len() - 3] = 28;
+            let mut cut_short = respond(query, &forged, false, 0);
+            cut_short.truncate(cut_short.len() - 2);
+
+            vec![
+                vec![0; 5],
+                respond(&other_id, &forged, false, 0),
+                respond(&other_name, &forged, false, 0),
+                respond(&other_type, &forged, false, 0),
+                cut_short,
+                respond(query, &real, false, 0),
+            ]
+        });
+        let resolver = UdpResolver::new(vec![server]);
+        assert_eq!(
+            resolver.query("example.com", RecordType::A).unwrap(),
+            [Record::A([192, 0, 2, 1].into())]
+        );
+
+        let server = serve(|query| vec![respond(query, &[], true, 0)]);
+        let e = UdpResolver::new(vec![server])
+            .query("example.com", RecordType::Txt)
+            .unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::Unsupported);
+
+        // Nothing but strays ends in a timeout
+        let server = serve(|_| vec![vec![0; 5]]);
+        let mut resolver = UdpResolver::new(vec![server]).timeout(Duration::from_millis(100));
+        resolver.retries = 0;
+        let e = resolver.query("example.com", RecordType::A).unwrap_err();
+        assert!(matches!(
+            e.kind(),
+            io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock
+        ));
+    }
+}
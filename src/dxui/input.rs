@@ -1,9 +1,17 @@
 use dioxus::prelude::*;
 
 /// Input component
+///
+/// A `hint` shows under the field, e.g. a typo suggestion for an email
+/// address; clicking it calls `onhint`, so the suggestion can be applied:
+///
+/// ```ignore
+/// let hint = toolbox::validation::suggest_email(&email()).map(|s| s.to_string());
+/// rsx! { Input { value: email(), hint, onhint: move |_| accept_suggestion() } }
+/// ```
 #[component]
 pub fn Input(props: InputProps) -> Element {
-    let mut response = use_signal(|| String::new());
+    let response = use_signal(String::new);
     // let oninput = match props.oninput {
     //     Some(x) => x,
     //     None => move |e: Event<FormData>| {
@@ -30,7 +38,19 @@ pub fn Input(props: InputProps) -> Element {
             }
 
             if let Some(err) = props.error {
-                p { class:"error" }
+                p { class:"error", "{err}" }
+            }
+
+            if let Some(hint) = props.hint {
+                p {
+                    class:"hint",
+                    onclick: move |e| {
+                        if let Some(onhint) = props.onhint {
+                            onhint(e)
+                        }
+                    },
+                    "{hint}"
+                }
             }
 
 
@@ -61,6 +81,12 @@ pub struct InputProps {
     /// The error note for input
     pub error: Option<String>,
 
+    /// A suggestion shown under the input, e.g. "did you mean john@gmail.com?"
+    pub hint: Option<String>,
+
+    /// The handler for a click on the hint, e.g. to accept the suggestion
+    pub onhint: Option<EventHandler<MouseEvent>>,
+
     /// The onclick event handler.
     pub onclick: Option<EventHandler<MouseEvent>>,
 
@@ -0,0 +1,237 @@
+//! Offline prefix → origin AS mapping, loaded from BGP table dumps
+//!
+//! Two formats are understood, both uncompressed:
+//! - the iptoasn.com TSV (`ip2asn-v4.tsv`, `ip2asn-combined.tsv`):
+//!   `range_start  range_end  asn  country  description`
+//! - pyasn `.dat` files: `prefix/len  asn`, with `;` comments
+//!
+//! ```
+//! use toolbox::ip::asn::AsnDb;
+//!
+//! let mut db = AsnDb::new();
+//! db.parse_iptoasn("1.1.1.0\t1.1.1.255\t13335\tUS\tCLOUDFLARENET\n").unwrap();
+//!
+//! let origin = db.asn_of("1.1.1.1".parse().unwrap()).unwrap();
+//! assert_eq!(origin.asn, 13335);
+//! assert_eq!(origin.name.as_deref(), Some("CLOUDFLARENET"));
+//! ```
+
+use super::cidr::Cidr;
+use std::collections::{BTreeSet, HashMap};
+use std::fs::File;
+use std::io::{self, BufRead, BufReader};
+use std::net::IpAddr;
+use std::path::Path;
+
+/// The origin of the most specific route covering an address
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AsnInfo {
+    pub asn: u32,
+    pub name: Option<String>,
+
+    /// ISO 3166 alpha-2 code of the registrant
+    pub country: Option<String>,
+
+    /// The matching route
+    pub network: Cidr,
+}
+
+/// A longest-prefix index of routes to their origin AS
+#[derive(Clone, Debug, Default)]
+pub struct AsnDb {
+    routes: HashMap<Cidr, u32>,
+
+    /// Prefix lengths in use, per family (`true` for IPv4), so lookups only
+    /// probe lengths that exist
+    lengths: BTreeSet<(bool, u8)>,
+
+    names: HashMap<u32, (Option<String>, Option<String>)>,
+}
+
+impl AsnDb {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a route; a later route for the same prefix replaces the earlier one
+    pub fn insert(&mut self, network: Cidr, asn: u32) {
+        self.lengths
+            .insert((network.network().is_ipv4(), network.prefix()));
+        self.routes.insert(network, asn);
+    }
+
+    /// Set the name and country reported for `asn`
+    pub fn describe(&mut self, asn: u32, name: Option<String>, country: Option<String>) {
+        self.names.insert(asn, (name, country));
+    }
+
+    /// Number of routes
+    pub fn len(&self) -> usize {
+        self.routes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.routes.is_empty()
+    }
+
+    /// The origin AS of the most specific route containing `ip`
+    pub fn asn_of(&self, ip: IpAddr) -> Option<AsnInfo> {
+        let (network, asn) = self
+            .lengths
+            .iter()
+            .rev()
+            .filter(|(v4, _)| *v4 == ip.is_ipv4())
+            .find_map(|(_, prefix)| {
+                let network = Cidr::new(ip, *prefix)?;
+                self.routes.get(&network).map(|asn| (network, *asn))
+            })?;
+
+        let (name, country) = self.names.get(&asn).cloned().unwrap_or_default();
+        Some(AsnInfo {
+            asn,
+            name,
+            country,
+            network,
+        })
+    }
+
+    /// Add routes from iptoasn TSV text. Unrouted ranges (AS 0) are skipped;
+    /// ranges that are not CIDR aligned are split into networks.
+    pub fn parse_iptoasn(&mut self, text: &str) -> io::Result<()> {
+        self.read_iptoasn(text.as_bytes())
+    }
+
+    pub fn load_iptoasn(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.read_iptoasn(BufReader::new(File::open(path)?))
+    }
+
+    fn read_iptoasn(&mut self, reader: impl BufRead) -> io::Result<()> {
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let err = || invalid_line(number, &line);
+
+            let fields = line.splitn(5, '\t').collect::<Vec<_>>();
+            let [start, end, asn, country, ..] = fields[..] else {
+                return Err(err());
+            };
+            let start = start.trim().parse::<IpAddr>().map_err(|_| err())?;
+            let end = end.trim().parse::<IpAddr>().map_err(|_| err())?;
+            let asn = asn.trim().parse::<u32>().map_err(|_| err())?;
+            if asn == 0 {
+                continue;
+            }
+
+            for network in Cidr::from_range(start, end) {
+                self.insert(network, asn);
+            }
+
+            let known =
+                |s: &str| Some(s.trim().to_string()).filter(|s| !s.is_empty() && s != "None");
+            let name = fields.get(4).and_then(|name| known(name));
+            self.names
+                .entry(asn)
+                .or_insert_with(|| (name, known(country)));
+        }
+
+        Ok(())
+    }
+
+    /// Add routes from pyasn `.dat` text. The format has no names; add them
+    /// with [`describe`](Self::describe).
+    pub fn parse_pyasn(&mut self, text: &str) -> io::Result<()> {
+        self.read_pyasn(text.as_bytes())
+    }
+
+    pub fn load_pyasn(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.read_pyasn(BufReader::new(File::open(path)?))
+    }
+
+    fn read_pyasn(&mut self, reader: impl BufRead) -> io::Result<()> {
+        for (number, line) in reader.lines().enumerate() {
+            let line = line?;
+            let entry = line.trim();
+            if entry.is_empty() || entry.starts_with(';') {
+                continue;
+            }
+            let err = || invalid_line(number, &line);
+
+            let mut fields = entry.split_whitespace();
+            let (Some(network), Some(asn)) = (fields.next(), fields.next()) else {
+                return Err(err());
+            };
+            let network = network.parse::<Cidr>().map_err(|_| err())?;
+            // Multi-origin (AS set) routes look like `{64496,64497}`; keep the first
+            let asn = asn
+                .trim_matches(['{', '}'])
+                .split(',')
+                .next()
+                .and_then(|asn| asn.parse::<u32>().ok())
+                .ok_or_else(err)?;
+
+            self.insert(network, asn);
+        }
+
+        Ok(())
+    }
+}
+
+fn invalid_line(number: usize, line: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("line {}: unrecognized route {line:?}", number + 1),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iptoasn() {
+        let tsv = "\
+1.0.0.0\t1.0.0.255\t13335\tUS\tCLOUDFLARENET
+1.0.1.0\t1.0.3.255\t0\tNone\tNot routed
+1.0.4.0\t1.0.6.255\t38803\tAU\tWPL-AS-AP Wirefreebroadband Pty Ltd
+2001:db8::\t2001:db8:ffff:ffff:ffff:ffff:ffff:ffff\t64496\tNone\tDOC-AS
+";
+        let mut db = AsnDb::new();
+        db.parse_iptoasn(tsv).unwrap();
+
+        let origin = db.asn_of("1.0.5.9".parse().unwrap()).unwrap();
+        assert_eq!(origin.asn, 38803);
+        assert_eq!(origin.country.as_deref(), Some("AU"));
+        assert_eq!(origin.network.to_string(), "1.0.4.0/23");
+
+        assert_eq!(db.asn_of("1.0.2.1".parse().unwrap()), None);
+        let origin = db.asn_of("2001:db8::1".parse().unwrap()).unwrap();
+        assert_eq!((origin.asn, origin.country), (64496, None));
+
+        assert!(db.parse_iptoasn("1.0.0.0 garbage\n").is_err());
+    }
+
+    #[test]
+    fn test_pyasn() {
+        let dat = "\
+; IP-ASN32-DAT file
+; Original source: rib.20240501.0000.bz2
+10.0.0.0/8\t64500
+10.1.0.0/16\t64501
+10.1.2.0/24\t{64502,64503}
+";
+        let mut db = AsnDb::new();
+        db.parse_pyasn(dat).unwrap();
+        db.describe(64501, Some("EXAMPLE-NET".into()), Some("NL".into()));
+
+        assert_eq!(db.len(), 3);
+        assert_eq!(db.asn_of("10.1.2.3".parse().unwrap()).unwrap().asn, 64502);
+        assert_eq!(db.asn_of("10.9.9.9".parse().unwrap()).unwrap().asn, 64500);
+
+        let origin = db.asn_of("10.1.9.9".parse().unwrap()).unwrap();
+        assert_eq!(origin.name.as_deref(), Some("EXAMPLE-NET"));
+        assert_eq!(db.asn_of("192.0.2.1".parse().unwrap()), None);
+    }
+}
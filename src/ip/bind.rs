@@ -0,0 +1,163 @@
+use super::dialer::Dialer;
+use super::error::Error;
+use socket2::{Domain, Protocol, SockAddr, Socket, Type};
+use std::io;
+use std::net::{IpAddr, SocketAddr, TcpStream};
+use std::time::Duration;
+
+/// Where outbound connections originate on a multi-homed host
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Bind {
+    /// Local source address; only remote addresses of the same family are tried
+    pub addr: Option<IpAddr>,
+
+    /// Network interface to send through (`SO_BINDTODEVICE`). Only supported on
+    /// Linux, Android and Fuchsia, and may need `CAP_NET_RAW` on older kernels.
+    pub interface: Option<String>,
+}
+
+impl Bind {
+    /// Bind to a local source address
+    pub fn addr(addr: IpAddr) -> Self {
+        Self {
+            addr: Some(addr),
+            interface: None,
+        }
+    }
+
+    /// Bind to a network interface by name, e.g. `eth1` or `wwan0`
+    pub fn interface(name: &str) -> Self {
+        Self {
+            addr: None,
+            interface: Some(name.to_string()),
+        }
+    }
+
+    /// Whether a remote address can be reached from this binding
+    pub(crate) fn allows(&self, remote: &SocketAddr) -> bool {
+        self.addr
+            .is_none_or(|addr| addr.is_ipv4() == remote.is_ipv4())
+    }
+
+    /// Open a bound TCP connection to `remote`
+    pub(crate) fn connect(
+        &self,
+        remote: &SocketAddr,
+        timeout: Option<Duration>,
+    ) -> io::Result<TcpStream> {
+        let socket = Socket::new(
+            Domain::for_address(*remote),
+            Type::STREAM,
+            Some(Protocol::TCP),
+        )?;
+
+        if let Some(name) = &self.interface {
+            bind_device(&socket, name)?;
+        }
+
+        if let Some(addr) = self.addr {
+            socket.bind(&SockAddr::from(SocketAddr::new(addr, 0)))?;
+        }
+
+        match timeout {
+            Some(timeout) => socket.connect_timeout(&SockAddr::from(*remote), timeout)?,
+            None => socket.connect(&SockAddr::from(*remote))?,
+        }
+
+        Ok(socket.into())
+    }
+}
+
+#[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+fn bind_device(socket: &Socket, name: &str) -> io::Result<()> {
+    socket.bind_device(Some(name.as_bytes()))
+}
+
+#[cfg(not(any(target_os = "android", target_os = "fuchsia", target_os = "linux")))]
+fn bind_device(_socket: &Socket, name: &str) -> io::Result<()> {
+    Err(io::Error::new(
+        io::ErrorKind::Unsupported,
+        format!("binding to interface {name} is not supported on this platform"),
+    ))
+}
+
+/// A local interface address usable as an uplink
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LocalAddr {
+    pub interface: String,
+    pub addr: IpAddr,
+}
+
+/// The outcome of a public address lookup through one uplink
+#[derive(Debug)]
+pub struct Uplink {
+    pub local: LocalAddr,
+    pub public: Result<IpAddr, Error>,
+}
+
+/// List the non-loopback, non-link-local addresses of every local interface
+pub fn local_addrs() -> io::Result<Vec<LocalAddr>> {
+    let addrs = if_addrs::get_if_addrs()?
+        .into_iter()
+        .filter(|iface| !iface.is_loopback() && !is_link_local(&iface.ip()))
+        .map(|iface| LocalAddr {
+            addr: iface.ip(),
+            interface: iface.name,
+        })
+        .collect();
+
+    Ok(addrs)
+}
+
+/// Discover the public address behind every local interface address
+///
+/// Each lookup is bound to the interface address and, where supported, to the
+/// interface itself so it leaves through that uplink even without source based
+/// routing. The `bind` setting of `dialer` is replaced for every lookup.
+pub fn get_public_ip_per_interface(dialer: &Dialer) -> io::Result<Vec<Uplink>> {
+    let results = local_addrs()?
+        .into_iter()
+        .map(|local| {
+            let bind = Bind {
+                addr: Some(local.addr),
+                interface: cfg!(any(
+                    target_os = "android",
+                    target_os = "fuchsia",
+                    target_os = "linux"
+                ))
+                .then(|| local.interface.clone()),
+            };
+            let public = super::get_public_ip_with(&dialer.clone().bind(bind));
+            Uplink { local, public }
+        })
+        .collect();
+
+    Ok(results)
+}
+
+fn is_link_local(ip: &IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => ip.is_link_local(),
+        IpAddr::V6(ip) => ip.is_unicast_link_local(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+
+    #[test]
+    fn test_bind_addr() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let remote = listener.local_addr().unwrap();
+
+        let bind = Bind::addr("127.0.0.1".parse().unwrap());
+        assert!(bind.allows(&remote));
+        assert!(!bind.allows(&"[::1]:80".parse().unwrap()));
+
+        let stream = bind.connect(&remote, Some(Duration::from_secs(5))).unwrap();
+        let (_, peer) = listener.accept().unwrap();
+        assert_eq!(stream.local_addr().unwrap(), peer);
+    }
+}
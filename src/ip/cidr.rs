@@ -0,0 +1,272 @@
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+/// An IP network in CIDR notation, e.g. `192.0.2.0/24` or `2001:db8::/32`
+///
+/// The address is always stored masked to its prefix, so `192.0.2.7/24`
+/// parses to `192.0.2.0/24`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Cidr {
+    addr: IpAddr,
+    prefix: u8,
+}
+
+impl Cidr {
+    /// Create a network, masking `addr` to `prefix` bits. Returns `None` if
+    /// the prefix is longer than the address family allows.
+    pub fn new(addr: IpAddr, prefix: u8) -> Option<Self> {
+        if prefix > max_prefix(&addr) {
+            return None;
+        }
+
+        let addr = from_bits(&addr, to_bits(&addr) & mask(prefix, max_prefix(&addr)));
+        Some(Self { addr, prefix })
+    }
+
+    /// For constants; `addr` must already be the network address
+    pub(crate) const fn new_unchecked(addr: IpAddr, prefix: u8) -> Self {
+        Self { addr, prefix }
+    }
+
+    /// The network (first) address
+    pub fn network(&self) -> IpAddr {
+        self.addr
+    }
+
+    /// The prefix length
+    pub fn prefix(&self) -> u8 {
+        self.prefix
+    }
+
+    /// The last address in the network (the broadcast address for IPv4)
+    pub fn last(&self) -> IpAddr {
+        let host = !mask(self.prefix, max_prefix(&self.addr)) & full_mask(&self.addr);
+        from_bits(&self.addr, to_bits(&self.addr) | host)
+    }
+
+    /// The netmask, e.g. `255.255.255.0` for a `/24`
+    pub fn netmask(&self) -> IpAddr {
+        from_bits(&self.addr, mask(self.prefix, max_prefix(&self.addr)))
+    }
+
+    /// Number of addresses covered, saturating at `u128::MAX` for `::/0`
+    pub fn size(&self) -> u128 {
+        let host_bits = (max_prefix(&self.addr) - self.prefix) as u32;
+        1u128.checked_shl(host_bits).unwrap_or(u128::MAX)
+    }
+
+    /// Check whether the network contains an address
+    pub fn contains(&self, ip: &IpAddr) -> bool {
+        self.addr.is_ipv4() == ip.is_ipv4()
+            && to_bits(ip) & mask(self.prefix, max_prefix(ip)) == to_bits(&self.addr)
+    }
+
+    /// Check whether the network fully covers another network
+    pub fn covers(&self, other: &Cidr) -> bool {
+        self.prefix <= other.prefix && self.contains(&other.addr)
+    }
+
+    /// Summarize an inclusive address range into the smallest list of networks
+    /// covering exactly that range. Returns an empty list if the bounds are of
+    /// different families or out of order.
+    pub fn from_range(start: IpAddr, end: IpAddr) -> Vec<Cidr> {
+        let mut nets = Vec::new();
+        if start.is_ipv4() != end.is_ipv4() || to_bits(&start) > to_bits(&end) {
+            return nets;
+        }
+
+        let bits = max_prefix(&start) as u32;
+        let (mut lo, hi) = (to_bits(&start), to_bits(&end));
+        loop {
+            // Largest block aligned on `lo` that does not run past `hi`
+            let mut size = lo.trailing_zeros().min(bits);
+            while block_last(lo, size) > hi {
+                size -= 1;
+            }
+
+            nets.push(Cidr {
+                addr: from_bits(&start, lo),
+                prefix: (bits - size) as u8,
+            });
+
+            match block_last(lo, size).checked_add(1) {
+                Some(next) if next <= hi => lo = next,
+                _ => break,
+            }
+        }
+
+        nets
+    }
+}
+
+impl fmt::Display for Cidr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix)
+    }
+}
+
+impl From<IpAddr> for Cidr {
+    fn from(addr: IpAddr) -> Self {
+        Self {
+            addr,
+            prefix: max_prefix(&addr),
+        }
+    }
+}
+
+/// Error returned when a string is not a valid CIDR network
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CidrParseError(String);
+
+impl fmt::Display for CidrParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid CIDR network: {}", self.0)
+    }
+}
+
+impl std::error::Error for CidrParseError {}
+
+impl FromStr for Cidr {
+    type Err = CidrParseError;
+
+    /// Parse `addr/prefix`, or a bare address as a single-host network
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || CidrParseError(s.to_string());
+        let s = s.trim();
+
+        match s.split_once('/') {
+            Some((addr, prefix)) => {
+                let addr = addr.parse::<IpAddr>().map_err(|_| err())?;
+                let prefix = prefix.parse::<u8>().map_err(|_| err())?;
+                Cidr::new(addr, prefix).ok_or_else(err)
+            }
+            None => s.parse::<IpAddr>().map(Cidr::from).map_err(|_| err()),
+        }
+    }
+}
+
+/// Serialized as `"192.0.2.0/24"`; deserialized from that or from
+/// `{"network": "192.0.2.0", "prefix": 24}`
+#[cfg(feature = "serde")]
+impl serde::Serialize for Cidr {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Cidr {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+
+        #[derive(serde::Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Text(String),
+            Parts { network: IpAddr, prefix: u8 },
+        }
+
+        match Repr::deserialize(deserializer)? {
+            Repr::Text(s) => s.parse().map_err(D::Error::custom),
+            Repr::Parts { network, prefix } => Cidr::new(network, prefix)
+                .ok_or_else(|| D::Error::custom(format!("invalid prefix /{prefix} for {network}"))),
+        }
+    }
+}
+
+fn max_prefix(addr: &IpAddr) -> u8 {
+    match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+fn full_mask(addr: &IpAddr) -> u128 {
+    mask(max_prefix(addr), max_prefix(addr))
+}
+
+fn mask(prefix: u8, bits: u8) -> u128 {
+    if prefix == 0 {
+        return 0;
+    }
+    (u128::MAX << (128 - prefix as u32)) >> (128 - bits as u32)
+}
+
+fn block_last(start: u128, size: u32) -> u128 {
+    start | u128::MAX.checked_shr(128 - size).unwrap_or(0)
+}
+
+fn to_bits(addr: &IpAddr) -> u128 {
+    match addr {
+        IpAddr::V4(ip) => u32::from(*ip) as u128,
+        IpAddr::V6(ip) => u128::from(*ip),
+    }
+}
+
+fn from_bits(like: &IpAddr, bits: u128) -> IpAddr {
+    match like {
+        IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::from(bits as u32)),
+        IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::from(bits)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display() {
+        let net: Cidr = "192.0.2.77/24".parse().unwrap();
+        assert_eq!(net.to_string(), "192.0.2.0/24");
+        assert_eq!(net.last(), "192.0.2.255".parse::<IpAddr>().unwrap());
+        assert_eq!(net.netmask(), "255.255.255.0".parse::<IpAddr>().unwrap());
+        assert_eq!(net.size(), 256);
+
+        let net: Cidr = "2001:db8::1/32".parse().unwrap();
+        assert_eq!(net.to_string(), "2001:db8::/32");
+        assert!(net.contains(&"2001:db8:ffff::1".parse().unwrap()));
+        assert!(!net.contains(&"192.0.2.1".parse().unwrap()));
+
+        assert_eq!("10.0.0.1".parse::<Cidr>().unwrap().prefix(), 32);
+        assert!("10.0.0.0/33".parse::<Cidr>().is_err());
+        assert!("not/8".parse::<Cidr>().is_err());
+    }
+
+    #[test]
+    fn test_from_range() {
+        let nets = Cidr::from_range("192.0.2.0".parse().unwrap(), "192.0.2.255".parse().unwrap());
+        assert_eq!(nets, vec!["192.0.2.0/24".parse().unwrap()]);
+
+        let nets = Cidr::from_range("10.0.0.1".parse().unwrap(), "10.0.0.6".parse().unwrap());
+        let nets: Vec<String> = nets.iter().map(|n| n.to_string()).collect();
+        assert_eq!(
+            nets,
+            ["10.0.0.1/32", "10.0.0.2/31", "10.0.0.4/31", "10.0.0.6/32"]
+        );
+
+        let nets = Cidr::from_range(
+            "0.0.0.0".parse().unwrap(),
+            "255.255.255.255".parse().unwrap(),
+        );
+        assert_eq!(nets, vec!["0.0.0.0/0".parse().unwrap()]);
+
+        let nets = Cidr::from_range(
+            "::".parse().unwrap(),
+            "ffff:ffff:ffff:ffff:ffff:ffff:ffff:ffff".parse().unwrap(),
+        );
+        assert_eq!(nets, vec!["::/0".parse().unwrap()]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let net: Cidr = "192.0.2.0/24".parse().unwrap();
+        assert_eq!(serde_json::to_string(&net).unwrap(), r#""192.0.2.0/24""#);
+        assert_eq!(serde_json::from_str::<Cidr>(r#""192.0.2.7/24""#).unwrap(), net);
+
+        let parts = r#"{"network": "192.0.2.0", "prefix": 24}"#;
+        assert_eq!(serde_json::from_str::<Cidr>(parts).unwrap(), net);
+        assert!(serde_json::from_str::<Cidr>(r#"{"network": "192.0.2.0", "prefix": 40}"#).is_err());
+    }
+}
@@ -0,0 +1,131 @@
+use super::bind::Bind;
+use super::proxy::{Proxy, ProxyConfig};
+use std::io;
+use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+use std::time::Duration;
+
+/// How the ip module opens outbound TCP connections
+#[derive(Clone, Debug, Default)]
+pub struct Dialer {
+    /// Applied to connecting as well as to every read and write
+    pub timeout: Option<Duration>,
+
+    /// Proxy selection, read from the environment by default
+    pub proxy: ProxyConfig,
+
+    /// Local address or interface to connect from. With a proxy, this applies
+    /// to the connection to the proxy.
+    pub bind: Option<Bind>,
+
+    /// Only reach the target over this address family. With a proxy, the
+    /// target is resolved locally so the proxy is handed an address of it.
+    pub family: Option<Family>,
+}
+
+/// An IP address family
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Family {
+    V4,
+    V6,
+}
+
+impl Family {
+    pub fn matches(&self, addr: &SocketAddr) -> bool {
+        match self {
+            Family::V4 => addr.is_ipv4(),
+            Family::V6 => addr.is_ipv6(),
+        }
+    }
+}
+
+impl Dialer {
+    pub fn new(timeout: Option<Duration>) -> Self {
+        Self {
+            timeout,
+            ..Default::default()
+        }
+    }
+
+    pub fn proxy(mut self, proxy: ProxyConfig) -> Self {
+        self.proxy = proxy;
+        self
+    }
+
+    pub fn bind(mut self, bind: Bind) -> Self {
+        self.bind = Some(bind);
+        self
+    }
+
+    pub fn family(mut self, family: Family) -> Self {
+        self.family = Some(family);
+        self
+    }
+
+    /// Open a connection to `host:port`, tunnelling through the configured
+    /// proxy. Environment proxies are picked as for a non-HTTP protocol.
+    pub fn connect(&self, host: &str, port: u16) -> io::Result<TcpStream> {
+        self.open("tcp", host, port)
+    }
+
+    /// Open a connection for a protocol `scheme` such as `http` or `whois`
+    pub(crate) fn open(&self, scheme: &str, host: &str, port: u16) -> io::Result<TcpStream> {
+        let proxy = match &self.proxy {
+            ProxyConfig::Env => Proxy::from_env(scheme, host, port),
+            ProxyConfig::Direct => None,
+            ProxyConfig::Proxy(proxy) => Some(proxy.clone()),
+        };
+
+        match (proxy, self.family) {
+            (Some(proxy), None) => {
+                let stream = self.direct(&proxy.host, proxy.port, None)?;
+                proxy.tunnel(stream, host, port)
+            }
+            (Some(proxy), Some(family)) => {
+                let target = (host, port)
+                    .to_socket_addrs()?
+                    .find(|addr| family.matches(addr))
+                    .ok_or_else(|| no_address(host))?;
+                let stream = self.direct(&proxy.host, proxy.port, None)?;
+                proxy.tunnel(stream, &target.ip().to_string(), port)
+            }
+            (None, family) => self.direct(host, port, family),
+        }
+    }
+
+    /// Connect without a proxy, trying every resolved address the family and
+    /// binding allow
+    fn direct(&self, host: &str, port: u16, family: Option<Family>) -> io::Result<TcpStream> {
+        let mut last_err = no_address(host);
+
+        for addr in (host, port).to_socket_addrs()? {
+            if family.is_some_and(|family| !family.matches(&addr)) {
+                continue;
+            }
+
+            let stream = match (&self.bind, self.timeout) {
+                (Some(bind), _) if !bind.allows(&addr) => continue,
+                (Some(bind), timeout) => bind.connect(&addr, timeout),
+                (None, Some(timeout)) => TcpStream::connect_timeout(&addr, timeout),
+                (None, None) => TcpStream::connect(addr),
+            };
+
+            match stream {
+                Ok(stream) => {
+                    stream.set_read_timeout(self.timeout)?;
+                    stream.set_write_timeout(self.timeout)?;
+                    return Ok(stream);
+                }
+                Err(e) => last_err = e,
+            }
+        }
+
+        Err(last_err)
+    }
+}
+
+fn no_address(host: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::NotFound,
+        format!("no usable address for {host}"),
+    )
+}
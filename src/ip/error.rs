@@ -0,0 +1,165 @@
+use std::fmt;
+use std::io;
+use std::net::IpAddr;
+
+/// Why a public address lookup failed
+#[derive(Debug)]
+pub enum Error {
+    /// Could not open a connection to the provider (or to the proxy in front of it)
+    Connect { provider: String, source: io::Error },
+
+    /// The provider did not answer within the timeout
+    Timeout { provider: String },
+
+    /// The connection failed after it was established, e.g. it was reset
+    Io { provider: String, source: io::Error },
+
+    /// The provider answered with a non-success HTTP status
+    Status { provider: String, status: u16 },
+
+    /// The provider's answer was not an IP address or not valid HTTP
+    Parse { provider: String, body: String },
+
+    /// Every provider was tried and failed; holds each provider's failure in
+    /// the order they were tried
+    Exhausted(Vec<Error>),
+
+    /// Providers answered, but fewer than `required` agreed on one address
+    NoConsensus {
+        required: usize,
+        answers: Vec<(String, IpAddr)>,
+        attempts: Vec<Error>,
+    },
+}
+
+impl Error {
+    /// The provider this failure is about, `None` for failures across providers
+    pub fn provider(&self) -> Option<&str> {
+        match self {
+            Error::Connect { provider, .. }
+            | Error::Timeout { provider }
+            | Error::Io { provider, .. }
+            | Error::Status { provider, .. }
+            | Error::Parse { provider, .. } => Some(provider),
+            Error::Exhausted(_) | Error::NoConsensus { .. } => None,
+        }
+    }
+
+    /// The individual provider failures
+    pub fn attempts(&self) -> &[Error] {
+        match self {
+            Error::Exhausted(attempts) | Error::NoConsensus { attempts, .. } => attempts,
+            _ => std::slice::from_ref(self),
+        }
+    }
+
+    /// Classify an I/O failure while connecting
+    pub(crate) fn connect(provider: &str, source: io::Error) -> Self {
+        match is_timeout(&source) {
+            true => Error::Timeout {
+                provider: provider.to_string(),
+            },
+            false => Error::Connect {
+                provider: provider.to_string(),
+                source,
+            },
+        }
+    }
+
+    /// Classify an I/O failure on an established connection
+    pub(crate) fn io(provider: &str, source: io::Error) -> Self {
+        match (is_timeout(&source), source.kind()) {
+            (true, _) => Error::Timeout {
+                provider: provider.to_string(),
+            },
+            (false, io::ErrorKind::InvalidData) => Error::Parse {
+                provider: provider.to_string(),
+                body: source.to_string(),
+            },
+            (false, _) => Error::Io {
+                provider: provider.to_string(),
+                source,
+            },
+        }
+    }
+}
+
+fn is_timeout(e: &io::Error) -> bool {
+    matches!(
+        e.kind(),
+        io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock
+    )
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Connect { provider, source } => {
+                write!(f, "{provider}: failed to connect: {source}")
+            }
+            Error::Timeout { provider } => write!(f, "{provider}: timed out"),
+            Error::Io { provider, source } => write!(f, "{provider}: connection failed: {source}"),
+            Error::Status { provider, status } => write!(f, "{provider}: HTTP status {status}"),
+            Error::Parse { provider, body } => write!(f, "{provider}: not an IP address: {body:?}"),
+            Error::Exhausted(attempts) if attempts.is_empty() => {
+                write!(f, "Failed to find public ip: no providers")
+            }
+            Error::Exhausted(attempts) => {
+                write!(f, "Failed to find public ip")?;
+                for attempt in attempts {
+                    write!(f, "; {attempt}")?;
+                }
+                Ok(())
+            }
+            Error::NoConsensus {
+                required,
+                answers,
+                attempts,
+            } => {
+                write!(f, "Fewer than {required} providers agreed on the public ip")?;
+                for (provider, ip) in answers {
+                    write!(f, "; {provider}: {ip}")?;
+                }
+                for attempt in attempts {
+                    write!(f, "; {attempt}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Connect { source, .. } | Error::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exhausted() {
+        let e = Error::Exhausted(vec![
+            Error::connect(
+                "checkip.amazonaws.com",
+                io::Error::from(io::ErrorKind::ConnectionRefused),
+            ),
+            Error::io("api.ipify.org", io::Error::from(io::ErrorKind::TimedOut)),
+        ]);
+
+        assert_eq!(e.provider(), None);
+        assert_eq!(e.attempts().len(), 2);
+        assert!(matches!(e.attempts()[0], Error::Connect { .. }));
+        assert!(matches!(e.attempts()[1], Error::Timeout { .. }));
+        assert_eq!(e.attempts()[1].provider(), Some("api.ipify.org"));
+        assert!(e.to_string().contains("api.ipify.org: timed out"));
+
+        #[cfg(feature = "result")]
+        assert!(crate::result::Error::from(e).has_meta());
+    }
+}
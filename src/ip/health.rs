@@ -0,0 +1,203 @@
+use super::lookup::Provider;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+/// How many recent latencies are kept per provider for the median
+const LATENCY_WINDOW: usize = 32;
+
+/// Latency assumed for providers that have never answered
+const UNKNOWN_LATENCY: Duration = Duration::from_secs(1);
+
+/// Running statistics for one provider
+#[derive(Clone, Debug, Default)]
+pub struct ProviderScore {
+    pub successes: u64,
+    pub failures: u64,
+
+    /// Failures since the last success
+    pub consecutive_failures: u32,
+
+    /// When the provider was last seen failing
+    pub last_failure: Option<Instant>,
+
+    /// Skipped (tried last) until then
+    pub quarantined_until: Option<Instant>,
+
+    recent: VecDeque<Duration>,
+}
+
+impl ProviderScore {
+    /// Share of successful queries, smoothed so one early result does not
+    /// decide everything
+    pub fn success_rate(&self) -> f64 {
+        (self.successes as f64 + 1.0) / ((self.successes + self.failures) as f64 + 2.0)
+    }
+
+    /// Median latency of recent successful queries
+    pub fn p50(&self) -> Option<Duration> {
+        let mut recent = self.recent.iter().copied().collect::<Vec<_>>();
+        recent.sort();
+        recent.get(recent.len().saturating_sub(1) / 2).copied()
+    }
+
+    /// Whether the provider is sitting out at `now`
+    pub fn is_quarantined(&self, now: Instant) -> bool {
+        self.quarantined_until.is_some_and(|until| now < until)
+    }
+
+    /// Higher is better: success rate over latency, halved for every failure
+    /// in a row
+    pub fn score(&self) -> f64 {
+        let latency = self.p50().unwrap_or(UNKNOWN_LATENCY).as_secs_f64();
+        let penalty = 0.5f64.powi(self.consecutive_failures.min(16) as i32);
+        self.success_rate() * penalty / (latency + 0.05)
+    }
+}
+
+/// When a failing provider is taken out of rotation
+#[derive(Clone, Copy, Debug)]
+pub struct HealthPolicy {
+    /// Consecutive failures before quarantine
+    pub quarantine_after: u32,
+
+    /// How long the first quarantine lasts; it doubles with every further
+    /// failure, up to `max_quarantine`
+    pub quarantine_for: Duration,
+    pub max_quarantine: Duration,
+}
+
+impl Default for HealthPolicy {
+    fn default() -> Self {
+        Self {
+            quarantine_after: 3,
+            quarantine_for: Duration::from_secs(30),
+            max_quarantine: Duration::from_secs(15 * 60),
+        }
+    }
+}
+
+/// Shared provider health, used by [`Lookup`](super::Lookup) to try the best
+/// providers first
+///
+/// Clones share the same statistics.
+#[derive(Clone, Debug, Default)]
+pub struct Health {
+    scores: Arc<Mutex<HashMap<Provider, ProviderScore>>>,
+    pub policy: HealthPolicy,
+}
+
+impl Health {
+    pub fn new(policy: HealthPolicy) -> Self {
+        Self {
+            scores: Default::default(),
+            policy,
+        }
+    }
+
+    /// The process-wide tracker used by lookups by default
+    pub fn global() -> &'static Health {
+        static GLOBAL: OnceLock<Health> = OnceLock::new();
+        GLOBAL.get_or_init(Health::default)
+    }
+
+    pub fn record_success(&self, provider: &Provider, latency: Duration) {
+        let mut scores = self.scores.lock().unwrap_or_else(|e| e.into_inner());
+        let score = scores.entry(provider.clone()).or_default();
+
+        score.successes += 1;
+        score.consecutive_failures = 0;
+        score.quarantined_until = None;
+        if score.recent.len() == LATENCY_WINDOW {
+            score.recent.pop_front();
+        }
+        score.recent.push_back(latency);
+    }
+
+    pub fn record_failure(&self, provider: &Provider) {
+        let mut scores = self.scores.lock().unwrap_or_else(|e| e.into_inner());
+        let score = scores.entry(provider.clone()).or_default();
+        let now = Instant::now();
+
+        score.failures += 1;
+        score.consecutive_failures += 1;
+        score.last_failure = Some(now);
+
+        if let Some(extra) = score
+            .consecutive_failures
+            .checked_sub(self.policy.quarantine_after)
+        {
+            let backoff = self
+                .policy
+                .quarantine_for
+                .saturating_mul(2u32.saturating_pow(extra.min(16)))
+                .min(self.policy.max_quarantine);
+            score.quarantined_until = Some(now + backoff);
+        }
+    }
+
+    /// The statistics of one provider
+    pub fn score(&self, provider: &Provider) -> ProviderScore {
+        let scores = self.scores.lock().unwrap_or_else(|e| e.into_inner());
+        scores.get(provider).cloned().unwrap_or_default()
+    }
+
+    /// The statistics of every provider seen so far, best first
+    pub fn scores(&self) -> Vec<(Provider, ProviderScore)> {
+        let scores = self.scores.lock().unwrap_or_else(|e| e.into_inner());
+        let mut scores = scores
+            .iter()
+            .map(|(provider, score)| (provider.clone(), score.clone()))
+            .collect::<Vec<_>>();
+        scores.sort_by(|a, b| b.1.score().total_cmp(&a.1.score()));
+        scores
+    }
+
+    /// Order providers best first, with quarantined ones moved to the end.
+    /// Providers with equal scores keep their relative order.
+    pub fn order(&self, providers: &[Provider]) -> Vec<Provider> {
+        let now = Instant::now();
+        let mut ranked = providers
+            .iter()
+            .map(|provider| (self.score(provider), provider.clone()))
+            .collect::<Vec<_>>();
+
+        ranked.sort_by(|(a, _), (b, _)| {
+            a.is_quarantined(now)
+                .cmp(&b.is_quarantined(now))
+                .then(b.score().total_cmp(&a.score()))
+        });
+
+        ranked.into_iter().map(|(_, provider)| provider).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order() {
+        let health = Health::default();
+        let (slow, fast, dead) = (
+            Provider::new("slow.example"),
+            Provider::new("fast.example"),
+            Provider::new("dead.example"),
+        );
+
+        health.record_success(&slow, Duration::from_millis(800));
+        health.record_success(&fast, Duration::from_millis(40));
+        for _ in 0..3 {
+            health.record_failure(&dead);
+        }
+
+        let order = health.order(&[dead.clone(), slow.clone(), fast.clone()]);
+        assert_eq!(order, [fast.clone(), slow.clone(), dead.clone()]);
+        assert!(health.score(&dead).is_quarantined(Instant::now()));
+        assert_eq!(health.score(&fast).p50(), Some(Duration::from_millis(40)));
+
+        health.record_success(&dead, Duration::from_millis(10));
+        assert!(!health.score(&dead).is_quarantined(Instant::now()));
+        assert_eq!(health.scores().len(), 3);
+    }
+}
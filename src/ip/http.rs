@@ -0,0 +1,327 @@
+#[cfg(feature = "rdap")]
+use super::dialer::Dialer;
+#[cfg(feature = "rdap")]
+use rustls::pki_types::ServerName;
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+#[cfg(feature = "rdap")]
+use std::sync::{Arc, LazyLock};
+
+/// TLS settings for `https://`, trusting the Mozilla root store bundled by
+/// webpki-roots
+#[cfg(feature = "rdap")]
+static TLS: LazyLock<Arc<rustls::ClientConfig>> = LazyLock::new(|| {
+    let roots = rustls::RootCertStore {
+        roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+    };
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let config = rustls::ClientConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()
+        .expect("ring supports the default protocol versions")
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    Arc::new(config)
+});
+
+/// A minimal HTTP/1.1 response
+#[derive(Debug)]
+pub(crate) struct Response {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl Response {
+    /// Get the first header with the given name (case-insensitive)
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, val)| val.as_str())
+    }
+}
+
+/// Connect to an `http://` or `https://` URL, send a `GET` request and read
+/// the whole response. Certificates are checked against the bundled roots.
+#[cfg(feature = "rdap")]
+pub(crate) fn get(dialer: &Dialer, url: &str) -> io::Result<Response> {
+    if let Some((host, port, path)) = split_url(url) {
+        let mut stream = dialer.open("http", &host, port)?;
+        return request(&mut stream, &host, port, &path);
+    }
+
+    let (host, port, path) = url
+        .strip_prefix("https://")
+        .and_then(|rest| split_authority(rest, 443))
+        .ok_or_else(|| {
+            io::Error::new(io::ErrorKind::Unsupported, format!("unsupported url {url}"))
+        })?;
+    let name = ServerName::try_from(host.clone())
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+    let connection = rustls::ClientConnection::new(TLS.clone(), name).map_err(io::Error::other)?;
+
+    let stream = dialer.open("https", &host, port)?;
+    let mut stream = rustls::StreamOwned::new(connection, stream);
+    send(&mut stream, &authority(&host, port, 443), &path)
+}
+
+/// Send a `GET` request on an open connection and read the whole response
+pub(crate) fn request(
+    stream: &mut TcpStream,
+    host: &str,
+    port: u16,
+    path: &str,
+) -> io::Result<Response> {
+    send(stream, &authority(host, port, 80), path)
+}
+
+/// The `Host` header value, leaving out the scheme's default port
+fn authority(host: &str, port: u16, default_port: u16) -> String {
+    match (host.contains(':'), port == default_port) {
+        (true, true) => format!("[{host}]"),
+        (true, false) => format!("[{host}]:{port}"),
+        (false, true) => host.to_string(),
+        (false, false) => format!("{host}:{port}"),
+    }
+}
+
+fn send(stream: &mut (impl Read + Write), authority: &str, path: &str) -> io::Result<Response> {
+    let request = format!(
+        "GET {path} HTTP/1.1\r\nHost: {authority}\r\nAccept: */*\r\nUser-Agent: toolbox\r\nConnection: close\r\n\r\n"
+    );
+    stream.write_all(request.as_bytes())?;
+
+    let mut raw = Vec::new();
+    match stream.read_to_end(&mut raw) {
+        Ok(_) => {}
+        // Many servers close TLS connections without a close_notify; a body
+        // cut short is caught against its length or chunking instead
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof && !raw.is_empty() => {}
+        Err(e) => return Err(e),
+    }
+
+    parse_response(&raw)
+}
+
+/// Parse a raw HTTP/1.x response, decoding a chunked body if needed
+pub(crate) fn parse_response(raw: &[u8]) -> io::Result<Response> {
+    let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_string());
+
+    let split = raw
+        .windows(4)
+        .position(|w| w == b"\r\n\r\n")
+        .ok_or_else(|| invalid("incomplete HTTP response head"))?;
+    let head = std::str::from_utf8(&raw[..split]).map_err(|_| invalid("HTTP head is not UTF-8"))?;
+    let body = &raw[split + 4..];
+
+    let mut lines = head.split("\r\n");
+    let status = lines
+        .next()
+        .filter(|line| line.starts_with("HTTP/1."))
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|code| code.parse::<u16>().ok())
+        .ok_or_else(|| invalid("malformed HTTP status line"))?;
+
+    let headers = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(key, val)| (key.trim().to_string(), val.trim().to_string()))
+        .collect::<Vec<_>>();
+
+    let mut response = Response {
+        status,
+        headers,
+        body: String::new(),
+    };
+
+    let body = match response.header("transfer-encoding") {
+        Some(te) if te.eq_ignore_ascii_case("chunked") => {
+            decode_chunked(body).ok_or_else(|| invalid("malformed chunked body"))?
+        }
+        _ => match response.header("content-length").map(str::parse::<usize>) {
+            Some(Ok(len)) => body
+                .get(..len)
+                .ok_or_else(|| invalid("truncated HTTP body"))?
+                .to_vec(),
+            _ => body.to_vec(),
+        },
+    };
+    response.body = String::from_utf8(body).map_err(|_| invalid("HTTP body is not UTF-8"))?;
+
+    Ok(response)
+}
+
+fn decode_chunked(mut raw: &[u8]) -> Option<Vec<u8>> {
+    let mut body = Vec::new();
+
+    loop {
+        let line_end = raw.windows(2).position(|w| w == b"\r\n")?;
+        let size = std::str::from_utf8(&raw[..line_end]).ok()?;
+        let size = usize::from_str_radix(size.split(';').next()?.trim(), 16).ok()?;
+        raw = &raw[line_end + 2..];
+
+        if size == 0 {
+            return Some(body);
+        }
+
+        body.extend_from_slice(raw.get(..size)?);
+        raw = raw.get(size + 2..)?;
+    }
+}
+
+/// Split an `http://host[:port]/path` URL into its parts
+pub(crate) fn split_url(url: &str) -> Option<(String, u16, String)> {
+    split_authority(url.strip_prefix("http://")?, 80)
+}
+
+/// Split `host[:port]/path`, the part of a URL after its scheme
+fn split_authority(rest: &str, default_port: u16) -> Option<(String, u16, String)> {
+    let (authority, path) = match rest.find('/') {
+        Some(pos) => (&rest[..pos], &rest[pos..]),
+        None => (rest, "/"),
+    };
+
+    let (host, port) = match authority.strip_prefix('[') {
+        Some(bracketed) => {
+            let (host, port) = bracketed.split_once(']')?;
+            (host, port.strip_prefix(':'))
+        }
+        None => match authority.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (authority, None),
+        },
+    };
+    let port = match port {
+        Some(port) => port.parse::<u16>().ok()?,
+        None => default_port,
+    };
+
+    if host.is_empty() {
+        return None;
+    }
+
+    Some((host.to_string(), port, path.to_string()))
+}
+
+/// Resolve a `Location` header against the URL that sent it (RFC 3986
+/// section 5.2): an absolute URL is kept, `//host/…` takes the request's
+/// scheme, `/path` its authority, and anything else its directory
+#[cfg(feature = "rdap")]
+pub(crate) fn resolve_url(base: &str, location: &str) -> String {
+    let has_scheme = location.split_once(':').is_some_and(|(scheme, _)| {
+        scheme.starts_with(|ch: char| ch.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || "+-.".contains(ch))
+    });
+    if has_scheme {
+        return location.to_string();
+    }
+
+    let (scheme, rest) = base.split_once("://").unwrap_or(("http", base));
+    if let Some(network_path) = location.strip_prefix("//") {
+        return format!("{scheme}://{network_path}");
+    }
+    let (authority, path) = match rest.find('/') {
+        Some(pos) => (&rest[..pos], &rest[pos..]),
+        None => (rest, "/"),
+    };
+
+    let merged = match location.starts_with('/') {
+        true => location.to_string(),
+        false => {
+            let path = path.split(['?', '#']).next().unwrap_or_default();
+            let dir = &path[..path.rfind('/').map_or(0, |pos| pos + 1)];
+            format!("{dir}{location}")
+        }
+    };
+
+    // Drop `.` and `..` segments, keeping any query on the last one
+    let (merged, query) = match merged.find(['?', '#']) {
+        Some(pos) => merged.split_at(pos),
+        None => (merged.as_str(), ""),
+    };
+    let mut segments: Vec<&str> = Vec::new();
+    let parts: Vec<&str> = merged.split('/').skip(1).collect();
+    for (i, segment) in parts.iter().enumerate() {
+        let last = i + 1 == parts.len();
+        match *segment {
+            "." if last => segments.push(""),
+            "." => {}
+            ".." => {
+                segments.pop();
+                if last {
+                    segments.push("");
+                }
+            }
+            segment => segments.push(segment),
+        }
+    }
+    format!("{scheme}://{authority}/{}{query}", segments.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_response() {
+        let raw = b"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nTransfer-Encoding: chunked\r\n\r\n4\r\n1.2.\r\n3\r\n3.4\r\n0\r\n\r\n";
+        let response = parse_response(raw).unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.header("content-type"), Some("text/plain"));
+        assert_eq!(response.body, "1.2.3.4");
+
+        assert!(parse_response(b"garbage").is_err());
+
+        let raw = b"HTTP/1.1 200 OK\r\nContent-Length: 7\r\n\r\n1.2.3";
+        assert!(parse_response(raw).is_err());
+    }
+
+    #[test]
+    fn test_split_url() {
+        assert_eq!(
+            split_url("http://127.0.0.1:8080/ip/1.2.3.4"),
+            Some(("127.0.0.1".to_string(), 8080, "/ip/1.2.3.4".to_string()))
+        );
+        assert_eq!(
+            split_url("http://rdap.org"),
+            Some(("rdap.org".to_string(), 80, "/".to_string()))
+        );
+        assert_eq!(
+            split_url("http://[::1]:80/"),
+            Some(("::1".to_string(), 80, "/".to_string()))
+        );
+        assert_eq!(split_url("https://rdap.org/"), None);
+        assert_eq!(
+            split_authority("rdap.org/ip/1.2.3.4", 443),
+            Some(("rdap.org".to_string(), 443, "/ip/1.2.3.4".to_string()))
+        );
+        assert_eq!(authority("rdap.org", 443, 443), "rdap.org");
+        assert_eq!(authority("::1", 8443, 443), "[::1]:8443");
+    }
+
+    #[test]
+    #[cfg(feature = "rdap")]
+    fn test_resolve_url() {
+        let base = "http://127.0.0.1:8080/rdap/ip/1.2.3.4?x=1";
+        for (location, resolved) in [
+            (
+                "https://rdap.arin.net/registry/ip/1.2.3.4",
+                "https://rdap.arin.net/registry/ip/1.2.3.4",
+            ),
+            ("//mirror.test/ip/1.2.3.4", "http://mirror.test/ip/1.2.3.4"),
+            (
+                "/registry/ip/1.2.3.4",
+                "http://127.0.0.1:8080/registry/ip/1.2.3.4",
+            ),
+            ("5.6.7.8", "http://127.0.0.1:8080/rdap/ip/5.6.7.8"),
+            (
+                "../net/1.2.3.0?full",
+                "http://127.0.0.1:8080/rdap/net/1.2.3.0?full",
+            ),
+            ("./", "http://127.0.0.1:8080/rdap/ip/"),
+        ] {
+            assert_eq!(resolve_url(base, location), resolved, "{location}");
+        }
+    }
+}
@@ -0,0 +1,418 @@
+//! IPv6 helpers: canonical text form (RFC 5952), IPv4 addresses embedded by
+//! transition mechanisms, and what an interface identifier gives away
+
+use super::cidr::Cidr;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+/// The NAT64 well-known prefix, `64:ff9b::/96` (RFC 6052)
+pub const NAT64_WELL_KNOWN: Cidr = Cidr::new_unchecked(
+    IpAddr::V6(Ipv6Addr::new(0x64, 0xff9b, 0, 0, 0, 0, 0, 0)),
+    96,
+);
+
+/// The NAT64 local-use prefix, `64:ff9b:1::/48` (RFC 8215)
+pub const NAT64_LOCAL_USE: Cidr = Cidr::new_unchecked(
+    IpAddr::V6(Ipv6Addr::new(0x64, 0xff9b, 1, 0, 0, 0, 0, 0)),
+    48,
+);
+
+/// Format an address the way RFC 5952 recommends: lowercase, no leading
+/// zeros, the longest run of two or more zero groups compressed to `::`
+/// (the first one on a tie), and IPv4-mapped addresses in dotted form
+pub fn canonical(addr: &Ipv6Addr) -> String {
+    let segments = addr.segments();
+
+    if let Some(ipv4) = addr.to_ipv4_mapped() {
+        return format!("::ffff:{ipv4}");
+    }
+
+    // Longest run of zero groups
+    let (mut best, mut run) = ((0, 0), (0, 0));
+    for (i, segment) in segments.iter().enumerate() {
+        match *segment == 0 {
+            true if run.1 == 0 => run = (i, 1),
+            true => run.1 += 1,
+            false => run = (0, 0),
+        }
+        if run.1 > best.1 {
+            best = run;
+        }
+    }
+
+    let hex = |segments: &[u16]| {
+        segments
+            .iter()
+            .map(|s| format!("{s:x}"))
+            .collect::<Vec<_>>()
+            .join(":")
+    };
+
+    match best {
+        (start, len) if len >= 2 => format!(
+            "{}::{}",
+            hex(&segments[..start]),
+            hex(&segments[start + len..])
+        ),
+        _ => hex(&segments),
+    }
+}
+
+/// Re-format any IPv6 notation (brackets, zone index, uppercase, leading
+/// zeros, embedded dotted quad) in canonical form. The zone index is kept.
+pub fn canonicalize(s: &str) -> Option<String> {
+    let s = s.trim();
+    let s = s
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .unwrap_or(s);
+
+    let (addr, zone) = match s.split_once('%') {
+        Some((addr, zone)) if !zone.is_empty() => (addr, Some(zone)),
+        Some(_) => return None,
+        None => (s, None),
+    };
+
+    let addr = canonical(&addr.parse().ok()?);
+    Some(match zone {
+        Some(zone) => format!("{addr}%{zone}"),
+        None => addr,
+    })
+}
+
+/// An IPv4 address carried inside an IPv6 one
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "kind", rename_all = "snake_case")
+)]
+pub enum Embedded {
+    /// `::ffff:a.b.c.d`
+    Mapped { ipv4: Ipv4Addr },
+
+    /// Synthesized by a NAT64 translator under `prefix` (RFC 6052)
+    Nat64 { prefix: Cidr, ipv4: Ipv4Addr },
+
+    /// A 6to4 site prefix, `2002:aabb:ccdd::/48` (RFC 3056)
+    #[cfg_attr(feature = "serde", serde(rename = "6to4"))]
+    SixToFour { ipv4: Ipv4Addr },
+
+    /// A Teredo client (RFC 4380): the server it uses and its own NAT
+    /// mapping, which are stored obfuscated
+    Teredo {
+        server: Ipv4Addr,
+        client: Ipv4Addr,
+        port: u16,
+    },
+}
+
+impl Embedded {
+    /// The embedded address; for Teredo, the client's public address
+    pub fn ipv4(&self) -> Ipv4Addr {
+        match *self {
+            Embedded::Mapped { ipv4 }
+            | Embedded::SixToFour { ipv4 }
+            | Embedded::Nat64 { ipv4, .. } => ipv4,
+            Embedded::Teredo { client, .. } => client,
+        }
+    }
+}
+
+/// Find an IPv4 address embedded in `addr` by a well-known mechanism.
+/// NAT64 is only recognized under its well-known and local-use prefixes; use
+/// [`nat64_ipv4`] for a network-specific one.
+pub fn embedded_ipv4(addr: &Ipv6Addr) -> Option<Embedded> {
+    let segments = addr.segments();
+    let octets = addr.octets();
+
+    if let Some(ipv4) = addr.to_ipv4_mapped() {
+        return Some(Embedded::Mapped { ipv4 });
+    }
+
+    for prefix in [NAT64_WELL_KNOWN, NAT64_LOCAL_USE] {
+        if let Some(ipv4) = nat64_ipv4(addr, &prefix) {
+            return Some(Embedded::Nat64 { prefix, ipv4 });
+        }
+    }
+
+    if segments[0] == 0x2002 {
+        return Some(Embedded::SixToFour {
+            ipv4: Ipv4Addr::new(octets[2], octets[3], octets[4], octets[5]),
+        });
+    }
+
+    if segments[0] == 0x2001 && segments[1] == 0 {
+        let client = !u32::from_be_bytes([octets[12], octets[13], octets[14], octets[15]]);
+        return Some(Embedded::Teredo {
+            server: Ipv4Addr::new(octets[4], octets[5], octets[6], octets[7]),
+            client: Ipv4Addr::from(client),
+            port: !segments[5],
+        });
+    }
+
+    None
+}
+
+/// The IPv4 address a NAT64 translator embedded in `addr` under `prefix`,
+/// which must be /32, /40, /48, /56, /64 or /96 (RFC 6052 section 2.2)
+pub fn nat64_ipv4(addr: &Ipv6Addr, prefix: &Cidr) -> Option<Ipv4Addr> {
+    if !prefix.contains(&IpAddr::V6(*addr)) {
+        return None;
+    }
+
+    let octets = addr.octets();
+    let start = nat64_offset(prefix.prefix())?;
+
+    // Bits 64..72 (the "u" octet) are skipped and must be zero
+    if prefix.prefix() < 96 && octets[8] != 0 {
+        return None;
+    }
+    let v4 = (start..)
+        .filter(|i| *i != 8)
+        .take(4)
+        .map(|i| octets[i])
+        .collect::<Vec<_>>();
+
+    Some(Ipv4Addr::new(v4[0], v4[1], v4[2], v4[3]))
+}
+
+/// Embed `ipv4` under a NAT64 `prefix`, as a translator would
+pub fn nat64(prefix: &Cidr, ipv4: Ipv4Addr) -> Option<Ipv6Addr> {
+    let IpAddr::V6(network) = prefix.network() else {
+        return None;
+    };
+    let start = nat64_offset(prefix.prefix())?;
+
+    let mut octets = network.octets();
+    let positions = (start..).filter(|i| *i != 8).take(4);
+    for (i, byte) in positions.zip(ipv4.octets()) {
+        octets[i] = byte;
+    }
+    Some(Ipv6Addr::from(octets))
+}
+
+fn nat64_offset(prefix: u8) -> Option<usize> {
+    match prefix {
+        32 | 40 | 48 | 56 | 64 => Some(prefix as usize / 8),
+        96 => Some(12),
+        _ => None,
+    }
+}
+
+/// A 48-bit hardware address
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Mac(pub [u8; 6]);
+
+impl fmt::Display for Mac {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [a, b, c, d, e, g] = self.0;
+        write!(f, "{a:02x}:{b:02x}:{c:02x}:{d:02x}:{e:02x}:{g:02x}")
+    }
+}
+
+impl FromStr for Mac {
+    type Err = String;
+
+    /// Parse six hex bytes separated by `:` or `-`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || format!("invalid MAC address: {s}");
+        let parts = s.trim().split([':', '-']).collect::<Vec<_>>();
+        if parts.len() != 6 {
+            return Err(err());
+        }
+
+        let mut mac = [0u8; 6];
+        for (byte, part) in mac.iter_mut().zip(parts) {
+            if part.len() != 2 {
+                return Err(err());
+            }
+            *byte = u8::from_str_radix(part, 16).map_err(|_| err())?;
+        }
+        Ok(Mac(mac))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Mac {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Mac {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// What the low 64 bits of an address say about how it was assigned
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "kind", content = "value", rename_all = "snake_case")
+)]
+pub enum InterfaceId {
+    /// Modified EUI-64, derived from this hardware address (RFC 4291)
+    Eui64(Mac),
+
+    /// Mostly zero, like `::1` or `::53`; configured by hand
+    LowByte,
+
+    /// ISATAP, carrying an IPv4 address (RFC 5214)
+    Isatap(Ipv4Addr),
+
+    /// Random-looking: a privacy/temporary address (RFC 8981) or a stable
+    /// opaque one (RFC 7217); the two cannot be told apart from the outside
+    Opaque,
+}
+
+/// Look at the interface identifier of `addr`
+pub fn interface_id(addr: &Ipv6Addr) -> InterfaceId {
+    let o = addr.octets();
+
+    if o[11] == 0xff && o[12] == 0xfe {
+        return InterfaceId::Eui64(Mac([o[8] ^ 0x02, o[9], o[10], o[13], o[14], o[15]]));
+    }
+
+    if o[9] == 0x00 && o[10] == 0x5e && o[11] == 0xfe && o[8] & !0x02 == 0 {
+        return InterfaceId::Isatap(Ipv4Addr::new(o[12], o[13], o[14], o[15]));
+    }
+
+    // At most the last 16 bits set
+    if o[8..14].iter().all(|b| *b == 0) {
+        return InterfaceId::LowByte;
+    }
+
+    InterfaceId::Opaque
+}
+
+/// The address SLAAC would form from `mac` under a /64 `prefix`
+pub fn eui64(prefix: &Cidr, mac: Mac) -> Option<Ipv6Addr> {
+    let IpAddr::V6(network) = prefix.network() else {
+        return None;
+    };
+    if prefix.prefix() > 64 {
+        return None;
+    }
+
+    let m = mac.0;
+    let mut octets = network.octets();
+    octets[8..].copy_from_slice(&[m[0] ^ 0x02, m[1], m[2], 0xff, 0xfe, m[3], m[4], m[5]]);
+    Some(Ipv6Addr::from(octets))
+}
+
+/// Whether `addr` looks like a privacy/temporary address: global unicast,
+/// outside the transition ranges, with a random-looking interface
+/// identifier whose universal/local bit is clear
+pub fn is_likely_temporary(addr: &Ipv6Addr) -> bool {
+    let o = addr.octets();
+
+    // Global unicast, 2000::/3
+    o[0] & 0xe0 == 0x20
+        && embedded_ipv4(addr).is_none()
+        && interface_id(addr) == InterfaceId::Opaque
+        && o[8] & 0x02 == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v6(s: &str) -> Ipv6Addr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_canonical() {
+        let cases = [
+            ("2001:0DB8:0000:0000:0000:0000:0000:0001", "2001:db8::1"),
+            ("2001:db8:0:0:1:0:0:1", "2001:db8::1:0:0:1"),
+            ("2001:db8:0:1:1:1:1:1", "2001:db8:0:1:1:1:1:1"),
+            ("2001:0:0:1:0:0:0:1", "2001:0:0:1::1"),
+            ("0:0:0:0:0:0:0:0", "::"),
+            ("::ffff:c000:0280", "::ffff:192.0.2.128"),
+            ("fe80:0:0:0:0:0:0:0", "fe80::"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(canonical(&v6(input)), expected, "{input}");
+        }
+
+        assert_eq!(
+            canonicalize("[FE80:0000::0001%eth0]").as_deref(),
+            Some("fe80::1%eth0")
+        );
+        assert_eq!(canonicalize("192.0.2.1"), None);
+    }
+
+    #[test]
+    fn test_embedded() {
+        let ipv4 = Ipv4Addr::new(192, 0, 2, 33);
+
+        let wkp = v6("64:ff9b::c000:221");
+        assert_eq!(
+            embedded_ipv4(&wkp),
+            Some(Embedded::Nat64 {
+                prefix: NAT64_WELL_KNOWN,
+                ipv4
+            })
+        );
+
+        // RFC 6052 section 2.4 examples
+        for (prefix, addr) in [
+            ("2001:db8::/32", "2001:db8:c000:221::"),
+            ("2001:db8:100::/40", "2001:db8:1c0:2:21::"),
+            ("2001:db8:122::/48", "2001:db8:122:c000:2:2100::"),
+            ("2001:db8:122:300::/56", "2001:db8:122:3c0:0:221::"),
+            ("2001:db8:122:344::/64", "2001:db8:122:344:c0:2:2100:0"),
+            ("2001:db8:122:344::/96", "2001:db8:122:344::192.0.2.33"),
+        ] {
+            let prefix = prefix.parse::<Cidr>().unwrap();
+            assert_eq!(nat64_ipv4(&v6(addr), &prefix), Some(ipv4), "{prefix}");
+            assert_eq!(nat64(&prefix, ipv4), Some(v6(addr)), "{prefix}");
+        }
+
+        assert_eq!(
+            embedded_ipv4(&v6("2002:c000:221::1")),
+            Some(Embedded::SixToFour { ipv4 })
+        );
+
+        // RFC 4380 section 4 example
+        assert_eq!(
+            embedded_ipv4(&v6("2001:0:4136:e378:8000:63bf:3fff:fdd2")),
+            Some(Embedded::Teredo {
+                server: Ipv4Addr::new(65, 54, 227, 120),
+                client: Ipv4Addr::new(192, 0, 2, 45),
+                port: 40000,
+            })
+        );
+        assert_eq!(embedded_ipv4(&v6("2001:db8::1")), None);
+    }
+
+    #[test]
+    fn test_interface_id() {
+        let mac = Mac([0x00, 0x1b, 0x21, 0x3c, 0x4d, 0x5e]);
+        let prefix = "2001:db8:1:2::/64".parse::<Cidr>().unwrap();
+        let slaac = eui64(&prefix, mac).unwrap();
+
+        assert_eq!(slaac, v6("2001:db8:1:2:21b:21ff:fe3c:4d5e"));
+        assert_eq!(interface_id(&slaac), InterfaceId::Eui64(mac));
+        assert_eq!(mac.to_string(), "00:1b:21:3c:4d:5e");
+        assert_eq!("00-1B-21-3C-4D-5E".parse::<Mac>(), Ok(mac));
+
+        assert_eq!(interface_id(&v6("2001:db8::53")), InterfaceId::LowByte);
+        assert_eq!(
+            interface_id(&v6("fe80::5efe:c000:221")),
+            InterfaceId::Isatap(Ipv4Addr::new(192, 0, 2, 33))
+        );
+
+        let temporary = v6("2001:db8:1:2:a4c1:9d3e:7f20:1b6c");
+        assert!(is_likely_temporary(&temporary));
+        assert!(!is_likely_temporary(&slaac));
+        assert!(!is_likely_temporary(&v6("fd00::a4c1:9d3e:7f20:1b6c")));
+    }
+}
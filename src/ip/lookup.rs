@@ -0,0 +1,313 @@
+use super::dialer::Dialer;
+use super::error::Error;
+use super::health::Health;
+use super::http;
+use std::fmt;
+use std::io;
+use std::net::IpAddr;
+use std::str::FromStr;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Hosts answering `GET /` on port 80 with the caller's address as plain text
+pub const PROVIDERS: [&str; 2] = ["checkip.amazonaws.com", "api.ipify.org"];
+
+/// Like [`PROVIDERS`], but reachable over IPv6
+pub const PROVIDERS_V6: [&str; 2] = ["api6.ipify.org", "ipv6.icanhazip.com"];
+
+/// A plain-text "what is my IP" endpoint
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Provider {
+    pub host: String,
+    pub port: u16,
+    pub path: String,
+}
+
+impl Provider {
+    /// A provider serving `GET /` on port 80
+    pub fn new(host: &str) -> Self {
+        Self::at(host, 80, "/")
+    }
+
+    /// A provider at a specific port and path, e.g. a local stand-in
+    pub fn at(host: &str, port: u16, path: &str) -> Self {
+        Self {
+            host: host.to_string(),
+            port,
+            path: path.to_string(),
+        }
+    }
+
+    /// The built-in [`PROVIDERS`]
+    pub fn defaults() -> Vec<Provider> {
+        PROVIDERS.iter().map(|host| Provider::new(host)).collect()
+    }
+
+    /// The built-in [`PROVIDERS_V6`]
+    pub fn defaults_v6() -> Vec<Provider> {
+        PROVIDERS_V6.iter().map(|host| Provider::new(host)).collect()
+    }
+
+    /// Ask this provider for our address
+    pub fn query(&self, dialer: &Dialer) -> Result<IpAddr, Error> {
+        let name = self.to_string();
+
+        let mut stream = dialer
+            .open("http", &self.host, self.port)
+            .map_err(|e| Error::connect(&name, e))?;
+
+        let response = http::request(&mut stream, &self.host, self.port, &self.path)
+            .map_err(|e| Error::io(&name, e))?;
+        if response.status != 200 {
+            return Err(Error::Status {
+                provider: name,
+                status: response.status,
+            });
+        }
+
+        // Extract IP from response
+        let body = response.body.trim();
+        body.parse::<IpAddr>().map_err(|_| Error::Parse {
+            provider: name,
+            body: body.chars().take(64).collect(),
+        })
+    }
+}
+
+impl fmt::Display for Provider {
+    /// Formats as `host`, adding the port and path only when not the defaults
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.host.contains(':') {
+            true => write!(f, "[{}]", self.host)?,
+            false => write!(f, "{}", self.host)?,
+        }
+        if self.port != 80 {
+            write!(f, ":{}", self.port)?;
+        }
+        if self.path != "/" {
+            write!(f, "{}", self.path)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Provider {
+    type Err = io::Error;
+
+    /// Parse `host`, `host:port` or `http://host[:port]/path`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let url = match s.starts_with("http://") {
+            true => s.to_string(),
+            false => format!("http://{s}"),
+        };
+
+        http::split_url(&url)
+            .map(|(host, port, path)| Provider::at(&host, port, &path))
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidInput, format!("invalid provider {s}"))
+            })
+    }
+}
+
+/// Serialized in its [`Display`](fmt::Display) form, so config files can
+/// list providers as plain strings
+#[cfg(feature = "serde")]
+impl serde::Serialize for Provider {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Provider {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// A configurable public address lookup
+///
+/// ```no_run
+/// use toolbox::ip::{Lookup, Provider};
+///
+/// let ip = Lookup::new()
+///     .providers(vec![Provider::new("api.ipify.org"), Provider::new("checkip.amazonaws.com")])
+///     .consensus(2)
+///     .run()?;
+/// # Ok::<(), toolbox::ip::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Lookup {
+    /// Tried in order, or best first when `health` is set
+    pub providers: Vec<Provider>,
+
+    /// How connections are opened, including the per-provider timeout
+    pub dialer: Dialer,
+
+    /// How many providers must report the same address before it is trusted
+    pub consensus: usize,
+
+    /// Where provider latency and failures are recorded and read back to
+    /// order providers; [`Health::global`] unless replaced
+    pub health: Option<Health>,
+
+    /// Start the next provider if the current one has not answered within
+    /// its median latency. Only used when `consensus` is 1.
+    pub hedge: bool,
+}
+
+impl Default for Lookup {
+    fn default() -> Self {
+        Self {
+            providers: Provider::defaults(),
+            dialer: Dialer::default(),
+            consensus: 1,
+            health: Some(Health::global().clone()),
+            hedge: false,
+        }
+    }
+}
+
+impl Lookup {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn providers(mut self, providers: Vec<Provider>) -> Self {
+        self.providers = providers;
+        self
+    }
+
+    pub fn dialer(mut self, dialer: Dialer) -> Self {
+        self.dialer = dialer;
+        self
+    }
+
+    pub fn consensus(mut self, consensus: usize) -> Self {
+        self.consensus = consensus.max(1);
+        self
+    }
+
+    pub fn health(mut self, health: Option<Health>) -> Self {
+        self.health = health;
+        self
+    }
+
+    pub fn hedge(mut self, hedge: bool) -> Self {
+        self.hedge = hedge;
+        self
+    }
+
+    /// Providers in the order they will be tried
+    pub fn ordered(&self) -> Vec<Provider> {
+        match &self.health {
+            Some(health) => health.order(&self.providers),
+            None => self.providers.clone(),
+        }
+    }
+
+    /// Query providers in order until `consensus` of them agree on an address
+    ///
+    /// Fails with [`Error::Exhausted`] if no provider answered, or with
+    /// [`Error::NoConsensus`] if some did but too few agreed.
+    pub fn run(&self) -> Result<IpAddr, Error> {
+        let providers = self.ordered();
+        if self.hedge && self.consensus == 1 {
+            return self.run_hedged(providers);
+        }
+
+        let mut answers: Vec<(String, IpAddr)> = Vec::new();
+        let mut attempts = Vec::new();
+
+        for provider in &providers {
+            match query(provider, &self.dialer, self.health.as_ref()) {
+                Ok(ip) => {
+                    answers.push((provider.to_string(), ip));
+                    if answers.iter().filter(|(_, found)| *found == ip).count() >= self.consensus {
+                        return Ok(ip);
+                    }
+                }
+                Err(e) => attempts.push(e),
+            }
+        }
+
+        match answers.is_empty() {
+            true => Err(Error::Exhausted(attempts)),
+            false => Err(Error::NoConsensus {
+                required: self.consensus,
+                answers,
+                attempts,
+            }),
+        }
+    }
+
+    /// Like [`run`](Self::run) for a single answer, but with up to two
+    /// providers in flight: the next one starts once the latest has been
+    /// quiet for its median latency, or as soon as it fails
+    fn run_hedged(&self, providers: Vec<Provider>) -> Result<IpAddr, Error> {
+        let (tx, rx) = mpsc::channel();
+        let mut pending = providers.into_iter();
+        let mut in_flight = 0;
+        let mut attempts = Vec::new();
+
+        // Median latency of the latest provider started, if it has one
+        let mut start = |in_flight: &mut usize| -> Option<Option<Duration>> {
+            let provider = pending.next()?;
+            let p50 = self.health.as_ref().and_then(|h| h.score(&provider).p50());
+            let (tx, dialer, health) = (tx.clone(), self.dialer.clone(), self.health.clone());
+
+            thread::spawn(move || {
+                let _ = tx.send(query(&provider, &dialer, health.as_ref()));
+            });
+            *in_flight += 1;
+            Some(p50)
+        };
+
+        let mut hedge_after = start(&mut in_flight).flatten();
+        while in_flight > 0 {
+            let answer = match hedge_after.filter(|_| in_flight < 2) {
+                Some(wait) => match rx.recv_timeout(wait) {
+                    Ok(answer) => answer,
+                    Err(_) => {
+                        hedge_after = start(&mut in_flight).flatten();
+                        continue;
+                    }
+                },
+                None => match rx.recv() {
+                    Ok(answer) => answer,
+                    Err(_) => break,
+                },
+            };
+
+            in_flight -= 1;
+            match answer {
+                Ok(ip) => return Ok(ip),
+                Err(e) => {
+                    attempts.push(e);
+                    if let Some(p50) = start(&mut in_flight) {
+                        hedge_after = p50;
+                    }
+                }
+            }
+        }
+
+        Err(Error::Exhausted(attempts))
+    }
+}
+
+/// Query `provider`, recording the outcome in `health`
+fn query(provider: &Provider, dialer: &Dialer, health: Option<&Health>) -> Result<IpAddr, Error> {
+    let started = Instant::now();
+    let result = provider.query(dialer);
+
+    if let Some(health) = health {
+        match &result {
+            Ok(_) => health.record_success(provider, started.elapsed()),
+            Err(_) => health.record_failure(provider),
+        }
+    }
+    result
+}
@@ -32,62 +32,184 @@
 */
 
 /// Get public address
-pub fn get_public_ip(_timeout: Option<std::time::Duration>) -> Result<IpAddr, Box<dyn std::error::Error>> {
-    let providers = vec!["checkip.amazonaws.com", "api.ipify.org"];
-    // let found: Option<String> = None;
-
-    for (index, provider) in providers.iter().enumerate() {
-        let address = format!("{provider}:80");
-        // println!("PROVIDER => {provider} \nADDRESS => {address}");
-
-        let mut stream = TcpStream::connect(&address)?;
-    
-        // Manual HTTP request
-        let request = format!("GET / HTTP/1.1\r\nHost: {provider}\r\nConnection: close\r\n\r\n");
-        stream.write_all(request.as_bytes())?;
-    
-        let mut response = String::new();
-        stream.read_to_string(&mut response)?;
-    
-        // Extract IP from response
-        let raw_public_ip = response
-            .lines()
-            .last()
-            .map(|ip| ip.trim().to_string());
-
-        return match raw_public_ip{
-            Some(ip) => {
-                match ip.parse::<IpAddr>(){
-                    Ok(ip) => Ok(ip),
-                    Err(_e) => continue
-                }
-
-                // // Type to find ip twice and compare both just to be sure
-                // if index < providers.len() && found.is_none() {
-                //     found = Some()
-                // }
-                
-                
-            },
-            None => continue
-        }
-    }
+pub fn get_public_ip(timeout: Option<std::time::Duration>) -> Result<IpAddr, Error> {
+    get_public_ip_with(&Dialer::new(timeout))
+}
 
-    Err("Failed to find public ip".into())
+/// Get public address, connecting through `dialer` (e.g. to use a proxy)
+///
+/// Providers are tried in order until one answers with an address. If none
+/// does, [`Error::Exhausted`] holds every provider's failure. Use [`Lookup`]
+/// to pick providers or require several of them to agree.
+pub fn get_public_ip_with(dialer: &Dialer) -> Result<IpAddr, Error> {
+    Lookup::new().dialer(dialer.clone()).run()
 }
 
 
-#[test]
-fn test_get_public_address() {
-    let x = get_public_ip(None);
-    // println!("PUBLIC IP => {x:#?}");
-    assert!(x.is_ok());
-} 
+#[cfg(test)]
+mod tests {
+    use super::testing::FakeProvider;
+    use super::*;
+    use std::time::Duration;
+
+    fn dialer(timeout: Duration) -> Dialer {
+        Dialer::new(Some(timeout)).proxy(ProxyConfig::Direct)
+    }
 
+    #[test]
+    fn test_get_public_address() {
+        let fake = FakeProvider::ip("203.0.113.7".parse().unwrap());
+
+        let x = Lookup::new()
+            .providers(vec![fake.provider()])
+            .dialer(dialer(Duration::from_secs(5)))
+            .run();
+        // println!("PUBLIC IP => {x:#?}");
+        assert_eq!(x.unwrap(), "203.0.113.7".parse::<IpAddr>().unwrap());
+    }
+
+    #[test]
+    fn test_fallback() {
+        let reset = FakeProvider::reset();
+        let malformed = FakeProvider::malformed();
+        let garbage = FakeProvider::body("<html>rate limited</html>");
+        let unavailable = FakeProvider::status(503);
+        let live = FakeProvider::ip("2001:db8::7".parse().unwrap());
+
+        let ip = Lookup::new()
+            .providers(vec![
+                reset.provider(),
+                malformed.provider(),
+                garbage.provider(),
+                unavailable.provider(),
+                live.provider(),
+            ])
+            .dialer(dialer(Duration::from_secs(5)))
+            .run()
+            .unwrap();
+        assert_eq!(ip, "2001:db8::7".parse::<IpAddr>().unwrap());
+        assert_eq!(live.hits(), 1);
+    }
 
-// pub mod validation;
+    #[test]
+    fn test_exhausted() {
+        let slow = FakeProvider::slow(Duration::from_secs(2), "203.0.113.7".parse().unwrap());
+        let malformed = FakeProvider::malformed();
+        let unavailable = FakeProvider::status(503);
+
+        let err = Lookup::new()
+            .providers(vec![slow.provider(), malformed.provider(), unavailable.provider()])
+            .dialer(dialer(Duration::from_millis(200)))
+            .health(None)
+            .run()
+            .unwrap_err();
+
+        let attempts = err.attempts();
+        assert!(matches!(err, Error::Exhausted(_)));
+        assert!(matches!(attempts[0], Error::Timeout { .. }));
+        assert!(matches!(attempts[1], Error::Parse { .. }));
+        assert!(matches!(attempts[2], Error::Status { status: 503, .. }));
+        assert_eq!(attempts[0].provider(), Some(slow.provider().to_string().as_str()));
+    }
+
+    #[test]
+    fn test_consensus() {
+        let wrong = FakeProvider::ip("198.51.100.1".parse().unwrap());
+        let first = FakeProvider::ip("203.0.113.7".parse().unwrap());
+        let second = FakeProvider::ip("203.0.113.7".parse().unwrap());
+        let unused = FakeProvider::ip("203.0.113.7".parse().unwrap());
+
+        let ip = Lookup::new()
+            .providers(vec![wrong.provider(), first.provider(), second.provider(), unused.provider()])
+            .dialer(dialer(Duration::from_secs(5)))
+            .consensus(2)
+            .health(None)
+            .run()
+            .unwrap();
+        assert_eq!(ip, "203.0.113.7".parse::<IpAddr>().unwrap());
+        assert_eq!(unused.hits(), 0);
+
+        let reset = FakeProvider::reset();
+        let err = Lookup::new()
+            .providers(vec![wrong.provider(), first.provider(), reset.provider()])
+            .dialer(dialer(Duration::from_secs(5)))
+            .consensus(2)
+            .health(None)
+            .run()
+            .unwrap_err();
+        match err {
+            Error::NoConsensus { required, answers, attempts } => {
+                assert_eq!(required, 2);
+                assert_eq!(answers.len(), 2);
+                assert_eq!(attempts.len(), 1);
+            }
+            e => panic!("expected NoConsensus, got {e}"),
+        }
+    }
+
+    #[test]
+    fn test_health() {
+        let health = Health::default();
+        let dead = FakeProvider::reset();
+        let live = FakeProvider::ip("203.0.113.7".parse().unwrap());
+        let lookup = Lookup::new()
+            .providers(vec![dead.provider(), live.provider()])
+            .dialer(dialer(Duration::from_secs(5)))
+            .health(Some(health.clone()));
+
+        for _ in 0..3 {
+            lookup.run().unwrap();
+        }
+        // The dead provider sinks after its first failure
+        assert_eq!(dead.hits(), 1);
+        assert_eq!(lookup.ordered()[0], live.provider());
+        assert_eq!(health.score(&live.provider()).successes, 3);
+
+        // A provider that is usually fast but stalls gets hedged
+        let health = Health::default();
+        let stalled = FakeProvider::slow(Duration::from_secs(3), "198.51.100.1".parse().unwrap());
+        health.record_success(&stalled.provider(), Duration::from_millis(50));
+        let started = std::time::Instant::now();
+        let ip = Lookup::new()
+            .providers(vec![stalled.provider(), live.provider()])
+            .dialer(dialer(Duration::from_secs(5)))
+            .health(Some(health.clone()))
+            .hedge(true)
+            .run()
+            .unwrap();
+        assert_eq!(ip, "203.0.113.7".parse::<IpAddr>().unwrap());
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+}
 
 
-// use validation::{IpValidationResult, validate_ip_detailed};
-use std::io::{Read, Write};
-use std::net::{TcpStream, SocketAddr, IpAddr};
+pub mod validation;
+pub mod asn;
+pub mod bind;
+pub mod cidr;
+pub mod dialer;
+pub mod health;
+pub mod ipv6;
+pub mod proxy;
+pub mod reputation;
+pub mod whois;
+#[cfg(feature = "rdap")]
+pub mod rdap;
+mod error;
+mod http;
+mod lookup;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
+pub use bind::{Bind, get_public_ip_per_interface};
+pub use cidr::Cidr;
+pub use dialer::{Dialer, Family};
+pub use error::Error;
+pub use health::{Health, HealthPolicy, ProviderScore};
+pub use lookup::{Lookup, PROVIDERS, PROVIDERS_V6, Provider};
+pub use proxy::{Proxy, ProxyConfig, ProxyKind};
+pub use validation::{IpValidationResult, validate_ip_address, validate_ip_detailed};
+pub use whois::Registration;
+
+
+use std::net::IpAddr;
@@ -0,0 +1,247 @@
+use super::cidr::Cidr;
+use super::dialer::Dialer;
+use super::http;
+use super::whois::Registration;
+use serde_json::Value;
+use std::io;
+use std::net::IpAddr;
+use std::time::Duration;
+
+/// Public RDAP bootstrap service (RFC 7484), which redirects to the registry
+/// responsible for an address
+pub const RDAP_BOOTSTRAP: &str = "https://rdap.org/";
+
+/// Maximum number of redirects followed before giving up
+const MAX_REDIRECTS: usize = 5;
+
+/// Look up an address over RDAP through the public bootstrap service
+pub fn lookup(ip: IpAddr, timeout: Option<Duration>) -> io::Result<Registration> {
+    lookup_from(RDAP_BOOTSTRAP, ip, &Dialer::new(timeout))
+}
+
+/// Look up an address over RDAP against `base`, e.g. `https://rdap.arin.net/registry/`,
+/// by fetching `{base}ip/{ip}` and following redirects, relative ones
+/// included. Both `https://` and plain `http://` endpoints work.
+pub fn lookup_from(base: &str, ip: IpAddr, dialer: &Dialer) -> io::Result<Registration> {
+    let mut url = format!("{}/ip/{ip}", base.trim_end_matches('/'));
+
+    for _ in 0..=MAX_REDIRECTS {
+        let response = http::get(dialer, &url)?;
+
+        match response.status {
+            200 => {
+                let json = serde_json::from_str::<Value>(&response.body)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                let mut registration = parse(&json, ip);
+                registration.source = url;
+                return Ok(registration);
+            }
+            301 | 302 | 303 | 307 | 308 => {
+                let location = response.header("location").ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, "redirect without location")
+                })?;
+                url = http::resolve_url(&url, location);
+            }
+            404 => {
+                return Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("no RDAP record for {ip}"),
+                ));
+            }
+            status => {
+                return Err(io::Error::other(format!(
+                    "RDAP server answered with status {status}"
+                )));
+            }
+        }
+    }
+
+    Err(io::Error::other("too many RDAP redirects"))
+}
+
+/// Parse an RDAP IP network object (RFC 9083 section 5.4) into a registration
+/// record
+///
+/// The network comes from the `cidr0_cidrs` extension when present, otherwise
+/// from `startAddress`/`endAddress`. The ASN comes from ARIN's
+/// `arin_originas0_originautnums` extension, as the base protocol has none.
+pub fn parse(json: &Value, ip: IpAddr) -> Registration {
+    let mut networks = json["cidr0_cidrs"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|cidr| {
+            let prefix = cidr["v4prefix"].as_str().or(cidr["v6prefix"].as_str())?;
+            let length = cidr["length"].as_u64()?;
+            format!("{prefix}/{length}").parse::<Cidr>().ok()
+        })
+        .collect::<Vec<_>>();
+
+    if networks.is_empty()
+        && let (Some(Ok(start)), Some(Ok(end))) = (
+            json["startAddress"].as_str().map(str::parse),
+            json["endAddress"].as_str().map(str::parse),
+        )
+    {
+        networks = Cidr::from_range(start, end);
+    }
+
+    let network = networks
+        .into_iter()
+        .filter(|net| net.contains(&ip))
+        .max_by_key(|net| net.prefix());
+
+    let entities = flatten_entities(json);
+
+    let org = entities
+        .iter()
+        .filter(|entity| has_role(entity, "registrant"))
+        .find_map(|entity| vcard_field(entity, "fn"))
+        .or_else(|| json["name"].as_str().map(str::to_string));
+
+    let abuse_contact = entities
+        .iter()
+        .filter(|entity| has_role(entity, "abuse"))
+        .find_map(|entity| vcard_field(entity, "email"));
+
+    let asn = json["arin_originas0_originautnums"]
+        .as_array()
+        .and_then(|asns| asns.first())
+        .and_then(Value::as_u64)
+        .and_then(|asn| u32::try_from(asn).ok());
+
+    Registration {
+        network,
+        org,
+        abuse_contact,
+        asn,
+        source: String::new(),
+    }
+}
+
+/// Collect the entities of an object, including nested ones, depth first
+fn flatten_entities(json: &Value) -> Vec<&Value> {
+    let mut entities = Vec::new();
+    for entity in json["entities"].as_array().into_iter().flatten() {
+        entities.push(entity);
+        entities.extend(flatten_entities(entity));
+    }
+    entities
+}
+
+fn has_role(entity: &Value, role: &str) -> bool {
+    entity["roles"]
+        .as_array()
+        .is_some_and(|roles| roles.iter().any(|r| r.as_str() == Some(role)))
+}
+
+/// Read a text property out of a jCard (RFC 7095): `["vcard", [[name, params, type, value], ...]]`
+fn vcard_field(entity: &Value, name: &str) -> Option<String> {
+    entity["vcardArray"][1]
+        .as_array()?
+        .iter()
+        .find(|prop| prop[0].as_str() == Some(name))
+        .and_then(|prop| prop[3].as_str())
+        .filter(|val| !val.is_empty())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ip::ProxyConfig;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    const ARIN_8_8_8_8: &str = r#"{
+        "objectClassName": "ip network",
+        "handle": "NET-8-8-8-0-2",
+        "startAddress": "8.8.8.0",
+        "endAddress": "8.8.8.255",
+        "ipVersion": "v4",
+        "name": "GOGL",
+        "cidr0_cidrs": [{ "v4prefix": "8.8.8.0", "length": 24 }],
+        "arin_originas0_originautnums": [15169],
+        "entities": [{
+            "handle": "GOGL",
+            "roles": ["registrant"],
+            "vcardArray": ["vcard", [["version", {}, "text", "4.0"], ["fn", {}, "text", "Google LLC"]]],
+            "entities": [{
+                "handle": "ABUSE5250-ARIN",
+                "roles": ["abuse"],
+                "vcardArray": ["vcard", [["fn", {}, "text", "Abuse"], ["email", {}, "text", "network-abuse@google.com"]]]
+            }]
+        }]
+    }"#;
+
+    #[test]
+    fn test_parse() {
+        let json = serde_json::from_str(ARIN_8_8_8_8).unwrap();
+        let registration = parse(&json, "8.8.8.8".parse().unwrap());
+        assert_eq!(registration.network, Some("8.8.8.0/24".parse().unwrap()));
+        assert_eq!(registration.org.as_deref(), Some("Google LLC"));
+        assert_eq!(
+            registration.abuse_contact.as_deref(),
+            Some("network-abuse@google.com")
+        );
+        assert_eq!(registration.asn, Some(15169));
+    }
+
+    #[test]
+    fn test_lookup_follows_redirect() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+
+        // Relative, as some registries send it
+        let location = "../registry/ip/8.8.8.8";
+        thread::spawn(move || {
+            for stream in listener.incoming().take(2) {
+                let mut stream = stream.unwrap();
+                let mut buf = [0; 1024];
+                let n = stream.read(&mut buf).unwrap();
+                let request = String::from_utf8_lossy(&buf[..n]);
+
+                let response = match request.starts_with("GET /ip/8.8.8.8 ") {
+                    true => format!(
+                        "HTTP/1.1 302 Found\r\nLocation: {location}\r\nContent-Length: 0\r\n\r\n"
+                    ),
+                    false => format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/rdap+json\r\n\r\n{ARIN_8_8_8_8}"
+                    ),
+                };
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        let registration = lookup_from(
+            &base,
+            "8.8.8.8".parse().unwrap(),
+            &Dialer::new(Some(Duration::from_secs(5))).proxy(ProxyConfig::Direct),
+        )
+        .unwrap();
+        assert!(registration.source.ends_with("/registry/ip/8.8.8.8"));
+        assert_eq!(registration.org.as_deref(), Some("Google LLC"));
+    }
+
+    #[test]
+    fn test_lookup_over_tls() {
+        // A server speaking plain HTTP fails the handshake rather than being
+        // read as a registry
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("https://{}", listener.local_addr().unwrap());
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buf = [0; 1024];
+            let _ = stream.read(&mut buf);
+            let _ = stream.write_all(b"HTTP/1.1 200 OK\r\n\r\n{}");
+        });
+
+        let dialer = Dialer::new(Some(Duration::from_secs(5))).proxy(ProxyConfig::Direct);
+        let e = lookup_from(&base, "8.8.8.8".parse().unwrap(), &dialer).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+
+        let e = lookup_from("ftp://rdap.example/", "8.8.8.8".parse().unwrap(), &dialer);
+        assert_eq!(e.unwrap_err().kind(), io::ErrorKind::Unsupported);
+    }
+}
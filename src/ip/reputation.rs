@@ -0,0 +1,394 @@
+//! Address reputation from locally loaded blocklists and DNS blocklists
+//!
+//! ```
+//! use toolbox::dns::{Record, RecordType};
+//! use toolbox::ip::reputation::{Blocklist, Reputation};
+//!
+//! let tor = Blocklist::parse("tor", "ExitAddress 192.0.2.10 2024-05-01 12:00:00\n");
+//! let zen = |name: &str, rtype: RecordType| match (name, rtype) {
+//!     ("20.2.0.192.zen.example", RecordType::A) => Ok(vec![Record::A([127, 0, 0, 4].into())]),
+//!     _ => Ok(vec![]),
+//! };
+//!
+//! let reputation = Reputation::new().list(tor).dnsbl("zen.example").resolver(zen);
+//! assert!(reputation.check("192.0.2.10".parse().unwrap()).is_listed());
+//! assert!(reputation.check("192.0.2.20".parse().unwrap()).is_listed());
+//! assert!(!reputation.check("192.0.2.30".parse().unwrap()).is_listed());
+//! ```
+
+use super::cidr::Cidr;
+use crate::dns::{Record, RecordType, Resolver, UdpResolver};
+use std::collections::{BTreeSet, HashMap};
+use std::io;
+use std::net::{IpAddr, Ipv4Addr};
+use std::path::Path;
+use std::sync::Arc;
+
+/// A named set of networks loaded from a file or string
+#[derive(Clone, Debug, Default)]
+pub struct Blocklist {
+    pub name: String,
+    entries: HashMap<Cidr, Option<String>>,
+
+    /// Prefix lengths in use, per family (`true` for IPv4), so lookups only
+    /// probe lengths that exist
+    lengths: BTreeSet<(bool, u8)>,
+}
+
+impl Blocklist {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Self::default()
+        }
+    }
+
+    /// Add a network, with an optional note on why it is listed; a later
+    /// entry for the same network replaces the earlier one
+    pub fn insert(&mut self, network: Cidr, reason: Option<String>) {
+        self.lengths
+            .insert((network.network().is_ipv4(), network.prefix()));
+        self.entries.insert(network, reason);
+    }
+
+    /// Parse a list with one address or network per line. Understands
+    /// FireHOL netsets, Tor's bulk exit list and `exit-addresses` format
+    /// (`ExitAddress <ip> <date>`), and plain denylists. `#` starts a comment;
+    /// a comment or text after the address becomes the reason.
+    pub fn parse(name: &str, text: &str) -> Self {
+        let mut list = Self::new(name);
+
+        for line in text.lines() {
+            let (entry, comment) = match line.split_once('#') {
+                Some((entry, comment)) => (entry, Some(comment.trim())),
+                None => (line, None),
+            };
+
+            let mut fields = entry.split_whitespace();
+            let Some(mut first) = fields.next() else {
+                continue;
+            };
+            if first == "ExitAddress" {
+                match fields.next() {
+                    Some(addr) => first = addr,
+                    None => continue,
+                }
+                fields = "".split_whitespace();
+            }
+            let Ok(network) = first.parse::<Cidr>() else {
+                continue;
+            };
+
+            let rest = fields.collect::<Vec<_>>().join(" ");
+            let reason = comment
+                .filter(|c| !c.is_empty())
+                .map(str::to_string)
+                .or(Some(rest).filter(|r| !r.is_empty()));
+            list.insert(network, reason);
+        }
+
+        list
+    }
+
+    /// Read and [`parse`](Self::parse) a list from disk, named after the file
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        Ok(Self::parse(&name, &std::fs::read_to_string(path)?))
+    }
+
+    /// The most specific entry containing `ip`
+    pub fn find(&self, ip: IpAddr) -> Option<(Cidr, Option<&str>)> {
+        self.lengths
+            .iter()
+            .rev()
+            .filter(|(v4, _)| *v4 == ip.is_ipv4())
+            .find_map(|(_, prefix)| {
+                let network = Cidr::new(ip, *prefix)?;
+                let reason = self.entries.get(&network)?;
+                Some((network, reason.as_deref()))
+            })
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Why an address was listed
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Source {
+    /// A local [`Blocklist`] entry
+    Local { network: Cidr },
+
+    /// A DNSBL answer; the return codes tell which of the zone's lists hit,
+    /// e.g. `127.0.0.4` for Spamhaus XBL
+    Dnsbl { codes: Vec<Ipv4Addr> },
+}
+
+/// One list that matched
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Match {
+    /// The blocklist name or DNSBL zone
+    pub list: String,
+    pub source: Source,
+
+    /// The entry's comment, or the DNSBL's TXT record
+    pub reason: Option<String>,
+}
+
+/// The outcome of a reputation check
+#[derive(Debug)]
+pub struct Verdict {
+    pub ip: IpAddr,
+    pub matches: Vec<Match>,
+
+    /// DNSBL zones that could not be asked, with the reason. An address is
+    /// not known to be clean while this is non-empty.
+    pub errors: Vec<(String, io::Error)>,
+}
+
+impl Verdict {
+    pub fn is_listed(&self) -> bool {
+        !self.matches.is_empty()
+    }
+}
+
+/// Checks addresses against local lists and DNSBL zones
+#[derive(Clone)]
+pub struct Reputation {
+    pub lists: Vec<Blocklist>,
+
+    /// Zones queried with the reversed address, e.g. `zen.spamhaus.org`
+    pub dnsbls: Vec<String>,
+
+    /// How DNSBL zones are queried; the system nameservers unless replaced
+    pub resolver: Option<Arc<dyn Resolver>>,
+}
+
+impl Default for Reputation {
+    fn default() -> Self {
+        Self {
+            lists: Vec::new(),
+            dnsbls: Vec::new(),
+            resolver: UdpResolver::system()
+                .ok()
+                .map(|resolver| Arc::new(resolver) as Arc<dyn Resolver>),
+        }
+    }
+}
+
+impl Reputation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn list(mut self, list: Blocklist) -> Self {
+        self.lists.push(list);
+        self
+    }
+
+    pub fn dnsbl(mut self, zone: &str) -> Self {
+        self.dnsbls.push(zone.trim_end_matches('.').to_string());
+        self
+    }
+
+    pub fn resolver(mut self, resolver: impl Resolver + 'static) -> Self {
+        self.resolver = Some(Arc::new(resolver));
+        self
+    }
+
+    /// Check `ip` against every local list, then every DNSBL zone
+    pub fn check(&self, ip: IpAddr) -> Verdict {
+        let mut verdict = Verdict {
+            ip,
+            matches: Vec::new(),
+            errors: Vec::new(),
+        };
+
+        for list in &self.lists {
+            if let Some((network, reason)) = list.find(ip) {
+                verdict.matches.push(Match {
+                    list: list.name.clone(),
+                    source: Source::Local { network },
+                    reason: reason.map(str::to_string),
+                });
+            }
+        }
+
+        for zone in &self.dnsbls {
+            let Some(resolver) = &self.resolver else {
+                let e = io::Error::new(io::ErrorKind::NotFound, "no DNS resolver configured");
+                verdict.errors.push((zone.clone(), e));
+                continue;
+            };
+
+            match dnsbl(resolver.as_ref(), zone, ip) {
+                Ok(Some(found)) => verdict.matches.push(found),
+                Ok(None) => {}
+                Err(e) => verdict.errors.push((zone.clone(), e)),
+            }
+        }
+
+        verdict
+    }
+}
+
+/// The name queried for `ip` in `zone`: reversed octets for IPv4, reversed
+/// nibbles for IPv6
+pub fn dnsbl_name(ip: IpAddr, zone: &str) -> String {
+    let reversed = match ip {
+        IpAddr::V4(ip) => {
+            let [a, b, c, d] = ip.octets();
+            format!("{d}.{c}.{b}.{a}")
+        }
+        IpAddr::V6(ip) => ip
+            .octets()
+            .iter()
+            .rev()
+            .map(|byte| format!("{:x}.{:x}", byte & 0x0f, byte >> 4))
+            .collect::<Vec<_>>()
+            .join("."),
+    };
+    format!("{reversed}.{zone}")
+}
+
+fn dnsbl(resolver: &dyn Resolver, zone: &str, ip: IpAddr) -> io::Result<Option<Match>> {
+    let name = dnsbl_name(ip, zone);
+    let codes = resolver
+        .query(&name, RecordType::A)?
+        .into_iter()
+        .filter_map(|record| match record {
+            Record::A(code) => Some(code),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    // 127.255.255.0/24 is how Spamhaus and others refuse a query (public
+    // resolver, over quota), which says nothing about the address
+    if let Some(code) = codes
+        .iter()
+        .find(|code| code.octets()[..3] == [127, 255, 255])
+    {
+        return Err(io::Error::other(format!(
+            "{zone} refused the query ({code})"
+        )));
+    }
+
+    let codes = codes
+        .into_iter()
+        .filter(|code| code.octets()[0] == 127)
+        .collect::<Vec<_>>();
+    if codes.is_empty() {
+        return Ok(None);
+    }
+
+    // The explanation is a nicety; a listing stands without it
+    let reason = resolver
+        .query(&name, RecordType::Txt)
+        .unwrap_or_default()
+        .into_iter()
+        .find_map(|record| match record {
+            Record::Txt(text) => Some(text),
+            _ => None,
+        });
+
+    Ok(Some(Match {
+        list: zone.to_string(),
+        source: Source::Dnsbl { codes },
+        reason,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocklist() {
+        let netset = "\
+# FireHOL level1
+#
+10.0.0.0/8
+192.0.2.0/24 # documentation
+192.0.2.66 scanner seen 2024-05-01
+ExitAddress 198.51.100.9 2024-05-01 12:00:00
+2001:db8::/32
+10.1.2.0/24 # stale
+10.1.2.0/24 # relisted
+";
+        let list = Blocklist::parse("level1", netset);
+        assert_eq!(list.len(), 6);
+
+        let (network, reason) = list.find("192.0.2.66".parse().unwrap()).unwrap();
+        assert_eq!(network.to_string(), "192.0.2.66/32");
+        assert_eq!(reason, Some("scanner seen 2024-05-01"));
+
+        let (_, reason) = list.find("192.0.2.1".parse().unwrap()).unwrap();
+        assert_eq!(reason, Some("documentation"));
+        assert!(list.find("198.51.100.9".parse().unwrap()).is_some());
+        assert!(list.find("203.0.113.1".parse().unwrap()).is_none());
+        assert!(list.find("2001:db8::1".parse().unwrap()).is_some());
+        assert_eq!(
+            list.find("10.1.2.3".parse().unwrap()).unwrap().1,
+            Some("relisted")
+        );
+        assert_eq!(
+            list.find("10.9.9.9".parse().unwrap())
+                .unwrap()
+                .0
+                .to_string(),
+            "10.0.0.0/8"
+        );
+    }
+
+    #[test]
+    fn test_dnsbl() {
+        assert_eq!(
+            dnsbl_name("192.0.2.99".parse().unwrap(), "zen.example"),
+            "99.2.0.192.zen.example"
+        );
+        assert!(
+            dnsbl_name("2001:db8::1".parse().unwrap(), "zen.example")
+                .starts_with("1.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.0.8.b.d.0.1.0.0.2.")
+        );
+
+        let zen = |name: &str, rtype: RecordType| match (name, rtype) {
+            ("99.2.0.192.zen.example", RecordType::A) => Ok(vec![
+                Record::A([127, 0, 0, 2].into()),
+                Record::A([127, 0, 0, 4].into()),
+            ]),
+            ("99.2.0.192.zen.example", RecordType::Txt) => {
+                Ok(vec![Record::Txt("Listed, see https://zen.example/".into())])
+            }
+            ("1.2.0.192.zen.example", RecordType::A) => {
+                Ok(vec![Record::A([127, 255, 255, 254].into())])
+            }
+            (_, _) => Ok(vec![]),
+        };
+        let reputation = Reputation::new().dnsbl("zen.example").resolver(zen);
+
+        let verdict = reputation.check("192.0.2.99".parse().unwrap());
+        assert_eq!(
+            verdict.matches,
+            [Match {
+                list: "zen.example".into(),
+                source: Source::Dnsbl {
+                    codes: vec![[127, 0, 0, 2].into(), [127, 0, 0, 4].into()]
+                },
+                reason: Some("Listed, see https://zen.example/".into()),
+            }]
+        );
+
+        let verdict = reputation.check("192.0.2.1".parse().unwrap());
+        assert!(!verdict.is_listed());
+        assert_eq!(verdict.errors.len(), 1);
+    }
+}
@@ -0,0 +1,161 @@
+//! Local stand-ins for public address providers, so lookups can be tested
+//! without network access
+//!
+//! ```
+//! use toolbox::ip::testing::FakeProvider;
+//! use toolbox::ip::{Dialer, Lookup, ProxyConfig};
+//!
+//! let dead = FakeProvider::reset();
+//! let live = FakeProvider::ip("203.0.113.7".parse().unwrap());
+//!
+//! let ip = Lookup::new()
+//!     .providers(vec![dead.provider(), live.provider()])
+//!     .dialer(Dialer::default().proxy(ProxyConfig::Direct))
+//!     .run()
+//!     .unwrap();
+//! assert_eq!(ip.to_string(), "203.0.113.7");
+//! ```
+
+use super::lookup::Provider;
+use std::io::{Read, Write};
+use std::net::{IpAddr, TcpListener, TcpStream};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+/// How a fake provider answers each request
+#[derive(Clone, Debug)]
+pub enum Behavior {
+    /// `200 OK` with this body
+    Body(String),
+    /// Wait, then behave as the inner behavior
+    Slow(Duration, Box<Behavior>),
+    /// Answer with this HTTP status and an empty body
+    Status(u16),
+    /// Answer with bytes that are not HTTP at all
+    Malformed,
+    /// Reset the connection without answering
+    Reset,
+}
+
+/// A provider served from a local port until dropped
+pub struct FakeProvider {
+    port: u16,
+    hits: Arc<AtomicUsize>,
+    stop: Arc<AtomicBool>,
+}
+
+impl FakeProvider {
+    /// Serve `behavior` for every request
+    pub fn new(behavior: Behavior) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("bind fake provider");
+        let port = listener.local_addr().expect("fake provider address").port();
+        let hits = Arc::new(AtomicUsize::new(0));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let (thread_hits, thread_stop) = (hits.clone(), stop.clone());
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                if thread_stop.load(Ordering::SeqCst) {
+                    break;
+                }
+                let Ok(stream) = stream else { continue };
+                thread_hits.fetch_add(1, Ordering::SeqCst);
+
+                let behavior = behavior.clone();
+                thread::spawn(move || respond(stream, &behavior));
+            }
+        });
+
+        Self { port, hits, stop }
+    }
+
+    /// Answer with an address
+    pub fn ip(ip: IpAddr) -> Self {
+        Self::new(Behavior::Body(format!("{ip}\n")))
+    }
+
+    /// Answer with a `200 OK` body that is not an address
+    pub fn body(body: &str) -> Self {
+        Self::new(Behavior::Body(body.to_string()))
+    }
+
+    /// Answer with an address after `delay`
+    pub fn slow(delay: Duration, ip: IpAddr) -> Self {
+        Self::new(Behavior::Slow(
+            delay,
+            Box::new(Behavior::Body(format!("{ip}\n"))),
+        ))
+    }
+
+    /// Answer with an HTTP error status
+    pub fn status(status: u16) -> Self {
+        Self::new(Behavior::Status(status))
+    }
+
+    /// Answer with garbage instead of HTTP
+    pub fn malformed() -> Self {
+        Self::new(Behavior::Malformed)
+    }
+
+    /// Reset every connection
+    pub fn reset() -> Self {
+        Self::new(Behavior::Reset)
+    }
+
+    /// The provider to hand to a [`Lookup`](super::Lookup)
+    pub fn provider(&self) -> Provider {
+        Provider::at("127.0.0.1", self.port, "/")
+    }
+
+    /// How many connections this fake has accepted
+    pub fn hits(&self) -> usize {
+        self.hits.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for FakeProvider {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        // Wake the accept loop so it sees the flag
+        let _ = TcpStream::connect(("127.0.0.1", self.port));
+    }
+}
+
+fn respond(mut stream: TcpStream, behavior: &Behavior) {
+    // Read the request head before answering
+    let mut head = Vec::new();
+    let mut buf = [0u8; 512];
+    while !head.windows(4).any(|w| w == b"\r\n\r\n") {
+        match stream.read(&mut buf) {
+            Ok(0) | Err(_) => return,
+            Ok(n) => head.extend_from_slice(&buf[..n]),
+        }
+    }
+
+    let mut behavior = behavior;
+    while let Behavior::Slow(delay, inner) = behavior {
+        thread::sleep(*delay);
+        behavior = inner;
+    }
+
+    let response = match behavior {
+        Behavior::Body(body) => format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            body.len()
+        ),
+        Behavior::Status(status) => {
+            format!("HTTP/1.1 {status} Error\r\nContent-Length: 0\r\nConnection: close\r\n\r\n")
+        }
+        Behavior::Malformed => "this is not http\n".to_string(),
+        Behavior::Reset => {
+            // Linger of zero turns the close into a RST
+            let _ = socket2::SockRef::from(&stream).set_linger(Some(Duration::ZERO));
+            return;
+        }
+        Behavior::Slow(..) => unreachable!(),
+    };
+
+    let _ = stream.write_all(response.as_bytes());
+}
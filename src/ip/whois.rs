@@ -0,0 +1,316 @@
+use super::cidr::Cidr;
+use super::dialer::Dialer;
+use std::io::{self, Read, Write};
+use std::net::IpAddr;
+use std::time::Duration;
+
+/// The IANA WHOIS server, which refers queries on to the right regional registry
+pub const IANA_WHOIS: &str = "whois.iana.org";
+
+/// The registered port for WHOIS (RFC 3912)
+pub const WHOIS_PORT: u16 = 43;
+
+/// Maximum number of referrals followed before giving up
+const MAX_REFERRALS: usize = 5;
+
+/// Ownership details for an IP address, as reported by WHOIS or RDAP
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Registration {
+    /// The most specific registered network containing the address
+    pub network: Option<Cidr>,
+
+    /// The organization holding the network
+    pub org: Option<String>,
+
+    /// Where to report abuse, usually an email address
+    pub abuse_contact: Option<String>,
+
+    /// The autonomous system announcing the network, if reported
+    pub asn: Option<u32>,
+
+    /// The server the final answer came from
+    pub source: String,
+}
+
+/// Look up an address over WHOIS, starting at IANA and following referrals
+pub fn lookup(ip: IpAddr, timeout: Option<Duration>) -> io::Result<Registration> {
+    lookup_from(
+        &format!("{IANA_WHOIS}:{WHOIS_PORT}"),
+        ip,
+        &Dialer::new(timeout),
+    )
+}
+
+/// Look up an address over WHOIS, starting at `server` (`host` or `host:port`)
+/// and following referrals between the regional registries
+pub fn lookup_from(server: &str, ip: IpAddr, dialer: &Dialer) -> io::Result<Registration> {
+    let mut server = server.to_string();
+    let mut visited = Vec::new();
+
+    loop {
+        let response = query(&server, &ip.to_string(), dialer)?;
+        visited.push(server.clone());
+
+        match referral(&response) {
+            Some(next) if !visited.contains(&next) && visited.len() <= MAX_REFERRALS => {
+                server = next
+            }
+            _ => {
+                let mut registration = parse(&response, ip);
+                registration.source = server;
+                return Ok(registration);
+            }
+        }
+    }
+}
+
+/// Send a raw WHOIS query and return the server's response text
+pub fn query(server: &str, query: &str, dialer: &Dialer) -> io::Result<String> {
+    let (host, port) = split_server(server)?;
+    let mut stream = dialer.open("whois", host, port)?;
+
+    stream.write_all(format!("{query}\r\n").as_bytes())?;
+
+    let mut raw = Vec::new();
+    stream.read_to_end(&mut raw)?;
+
+    // Registries still serve Latin-1 here and there, so never fail on encoding
+    Ok(String::from_utf8_lossy(&raw).into_owned())
+}
+
+/// Parse a WHOIS response into a registration record
+///
+/// Understands the ARIN (`NetRange`, `OrgName`, `OrgAbuseEmail`, `OriginAS`),
+/// RPSL (`inetnum`, `org-name`, `abuse-mailbox`, `origin`) and LACNIC
+/// (`owner`, `e-mail`) field names.
+pub fn parse(response: &str, ip: IpAddr) -> Registration {
+    let mut networks = Vec::new();
+    let mut org = None;
+    let mut org_fallback = None;
+    let mut abuse_contact = None;
+    let mut asn = None;
+
+    for line in response.lines() {
+        // RIPE puts the abuse contact in a comment: % Abuse contact for '...' is 'abuse@example.net'
+        if line.starts_with('%') && line.contains("Abuse contact for") {
+            if let Some(contact) = line.rsplit('\'').nth(1).filter(|c| c.contains('@')) {
+                abuse_contact.get_or_insert_with(|| contact.to_string());
+            }
+            continue;
+        }
+
+        if line.starts_with(['%', '#']) {
+            continue;
+        }
+
+        let Some((key, val)) = line.split_once(':') else {
+            continue;
+        };
+        let val = val.trim();
+        if val.is_empty() {
+            continue;
+        }
+
+        match key.trim().to_ascii_lowercase().as_str() {
+            "inetnum" | "inet6num" | "netrange" | "cidr" => networks.extend(parse_networks(val)),
+            "orgname" | "org-name" | "owner" => {
+                org.get_or_insert_with(|| val.to_string());
+            }
+            "descr" | "netname" => {
+                org_fallback.get_or_insert_with(|| val.to_string());
+            }
+            "orgabuseemail" | "abuse-mailbox" => {
+                abuse_contact.get_or_insert_with(|| val.to_string());
+            }
+            "e-mail" if abuse_contact.is_none() => abuse_contact = Some(val.to_string()),
+            "originas" | "origin" => {
+                asn = asn.or_else(|| parse_asn(val));
+            }
+            _ => {}
+        }
+    }
+
+    let network = networks
+        .into_iter()
+        .filter(|net| net.contains(&ip))
+        .max_by_key(|net| net.prefix());
+
+    Registration {
+        network,
+        org: org.or(org_fallback),
+        abuse_contact,
+        asn,
+        source: String::new(),
+    }
+}
+
+/// Find the server a response refers the query on to, as `host:port`
+fn referral(response: &str) -> Option<String> {
+    for line in response.lines() {
+        let Some((key, val)) = line.split_once(':') else {
+            continue;
+        };
+        let val = val.trim();
+
+        let server = match key.trim().to_ascii_lowercase().as_str() {
+            // IANA
+            "refer" | "whois" => val,
+            // ARIN, e.g. `ReferralServer: whois://whois.ripe.net`; rwhois is not supported
+            "referralserver" => match val.strip_prefix("whois://") {
+                Some(server) => server.trim_end_matches('/'),
+                None => continue,
+            },
+            _ => continue,
+        };
+
+        if server.is_empty() {
+            continue;
+        }
+
+        return Some(match server.contains(':') {
+            true => server.to_string(),
+            false => format!("{server}:{WHOIS_PORT}"),
+        });
+    }
+
+    None
+}
+
+/// Parse `a - b` ranges, comma separated CIDR lists and LACNIC's abbreviated
+/// `200.0.0/16` form
+fn parse_networks(val: &str) -> Vec<Cidr> {
+    if let Some((start, end)) = val.split_once(" - ") {
+        return match (start.trim().parse(), end.trim().parse()) {
+            (Ok(start), Ok(end)) => Cidr::from_range(start, end),
+            _ => Vec::new(),
+        };
+    }
+
+    val.split(',')
+        .filter_map(|net| {
+            let net = net.trim();
+            net.parse::<Cidr>().ok().or_else(|| {
+                let (addr, prefix) = net.split_once('/')?;
+                let mut octets = addr.split('.').collect::<Vec<_>>();
+                if octets.len() >= 4 || addr.contains(':') {
+                    return None;
+                }
+                octets.resize(4, "0");
+                format!("{}/{prefix}", octets.join(".")).parse().ok()
+            })
+        })
+        .collect()
+}
+
+fn parse_asn(val: &str) -> Option<u32> {
+    let val = val.split([',', ' ']).next()?;
+    let digits = val
+        .strip_prefix("AS")
+        .or_else(|| val.strip_prefix("as"))
+        .unwrap_or(val);
+    digits.parse().ok()
+}
+
+fn split_server(server: &str) -> io::Result<(&str, u16)> {
+    match server.rsplit_once(':') {
+        Some((host, port)) if !host.contains(':') || host.ends_with(']') => {
+            let port = port.parse().map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("invalid WHOIS server {server}"),
+                )
+            })?;
+            Ok((host.trim_start_matches('[').trim_end_matches(']'), port))
+        }
+        _ => Ok((server, WHOIS_PORT)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ip::ProxyConfig;
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serve one canned WHOIS response per connection
+    fn serve(responses: Vec<String>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap().to_string();
+
+        thread::spawn(move || {
+            for (stream, response) in listener.incoming().zip(responses) {
+                let mut stream = stream.unwrap();
+                let mut buf = [0; 128];
+                let _ = stream.read(&mut buf);
+                let _ = stream.write_all(response.as_bytes());
+            }
+        });
+
+        addr
+    }
+
+    #[test]
+    fn test_parse_rpsl() {
+        let response = "\
+% Abuse contact for '193.0.0.0 - 193.0.7.255' is 'abuse@ripe.net'
+
+inetnum:        193.0.0.0 - 193.0.7.255
+netname:        RIPE-NCC
+descr:          RIPE Network Coordination Centre
+org:            ORG-RIEN1-RIPE
+
+organisation:   ORG-RIEN1-RIPE
+org-name:       Reseaux IP Europeens Network Coordination Centre (RIPE NCC)
+
+route:          193.0.0.0/21
+origin:         AS3333
+";
+        let registration = parse(response, "193.0.6.139".parse().unwrap());
+        assert_eq!(registration.network, Some("193.0.0.0/21".parse().unwrap()));
+        assert_eq!(
+            registration.org.as_deref(),
+            Some("Reseaux IP Europeens Network Coordination Centre (RIPE NCC)")
+        );
+        assert_eq!(
+            registration.abuse_contact.as_deref(),
+            Some("abuse@ripe.net")
+        );
+        assert_eq!(registration.asn, Some(3333));
+    }
+
+    #[test]
+    fn test_lookup_follows_referrals() {
+        let arin = serve(vec![
+            "\
+NetRange:       8.0.0.0 - 8.127.255.255
+CIDR:           8.0.0.0/9
+NetRange:       8.8.8.0 - 8.8.8.255
+CIDR:           8.8.8.0/24
+OriginAS:       AS15169
+OrgName:        Google LLC
+OrgAbuseEmail:  network-abuse@google.com
+"
+            .to_string(),
+        ]);
+        let iana = serve(vec![format!(
+            "% IANA WHOIS server\n\nrefer:        {arin}\n\ninetnum:      8.0.0.0 - 8.255.255.255\n"
+        )]);
+
+        let registration = lookup_from(
+            &iana,
+            "8.8.8.8".parse().unwrap(),
+            &Dialer::new(Some(Duration::from_secs(5))).proxy(ProxyConfig::Direct),
+        )
+        .unwrap();
+        assert_eq!(registration.source, arin);
+        assert_eq!(registration.network, Some("8.8.8.0/24".parse().unwrap()));
+        assert_eq!(registration.org.as_deref(), Some("Google LLC"));
+        assert_eq!(
+            registration.abuse_contact.as_deref(),
+            Some("network-abuse@google.com")
+        );
+        assert_eq!(registration.asn, Some(15169));
+    }
+}
@@ -1,6 +1,9 @@
 #[cfg(feature = "ip")]
 pub mod ip;
 
+#[cfg(feature = "dns")]
+pub mod dns;
+
 #[cfg(feature = "dxui")]
 pub mod dxui;
 
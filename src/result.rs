@@ -65,7 +65,7 @@ impl Error {
     }
 
     pub fn has_meta(&self) -> bool {
-        self.meta.len() > 0
+        !self.meta.is_empty()
     }
 }
 
@@ -172,6 +172,32 @@ impl From<std::num::ParseIntError> for Error {
 //     }
 // }
 
+#[cfg(feature = "ip")]
+impl From<crate::ip::Error> for Error {
+    fn from(e: crate::ip::Error) -> Self {
+        let mut err = Error::tech("");
+        err.add_meta("from", "ip");
+        err.add_meta("error", &e.to_string());
+        for (index, attempt) in e.attempts().iter().enumerate() {
+            err.add_meta(&format!("attempt.{index}"), &attempt.to_string());
+        }
+        err
+    }
+}
+
+#[cfg(feature = "validation")]
+impl From<crate::validation::ValidationError> for Error {
+    fn from(e: crate::validation::ValidationError) -> Self {
+        let mut err = Error::user(&e.to_string());
+        err.add_meta("field", &e.field);
+        err.add_meta("reason", e.reason.code());
+        if let crate::validation::Reason::BadCharacter { pos, .. } = e.reason {
+            err.add_meta("pos", &pos.to_string());
+        }
+        err
+    }
+}
+
 #[cfg(feature = "surreal")]
 impl From<surrealdb::Error> for Error {
     fn from(e: surrealdb::Error) -> Self {
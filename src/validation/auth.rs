@@ -0,0 +1,210 @@
+//! Login identifiers that may be an email address or a phone number
+//!
+//! ```
+//! use toolbox::validation::{AuthIdentifier, parse_auth_identifier};
+//!
+//! let email = parse_auth_identifier(" John.Doe@Example.com ").unwrap();
+//! assert_eq!(email.to_string(), "John.Doe@example.com");
+//! assert_eq!(email, parse_auth_identifier("john.doe@example.com").unwrap());
+//! assert!(email.as_email().is_some());
+//!
+//! let phone = AuthIdentifier::parse("020 7946 0958", Some("GB")).unwrap();
+//! assert_eq!(phone.to_string(), "+442079460958");
+//! ```
+
+use super::email::{CanonicalRules, EmailAddress};
+use super::error::{Reason, ValidationError};
+use super::phone::PhoneNumber;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::mem;
+use std::str::FromStr;
+
+/// An email address or phone number, normalized so one account has one
+/// identifier: numbers in E.164, and addresses as [`EmailAddress::parse`]
+/// gives them, compared and hashed without regard to the case of their
+/// local part. Provider rules such as Gmail ignoring dots are not applied;
+/// key accounts with [`canonicalize_email`](super::canonicalize_email) for
+/// those.
+#[derive(Clone, Debug)]
+pub enum AuthIdentifier {
+    Email(EmailAddress),
+    Phone(PhoneNumber),
+}
+
+impl AuthIdentifier {
+    /// Parse `value` as whichever it is. Anything with an `@` is an email
+    /// address, and anything else a phone number, national ones read in
+    /// `default_region`. When neither parses, the error is for the one the
+    /// input looks more like: `john.doe` misses its `@`, while
+    /// `+44 20 79` is a short phone number.
+    pub fn parse(value: &str, default_region: Option<&str>) -> Result<Self, ValidationError> {
+        let trimmed = value.trim();
+        let email = match trimmed.get(..7) {
+            Some(scheme) if scheme.eq_ignore_ascii_case("mailto:") => Some(&trimmed[7..]),
+            _ => trimmed.contains('@').then_some(trimmed),
+        };
+        if let Some(email) = email {
+            return EmailAddress::parse(email).map(Self::Email);
+        }
+
+        let e = match PhoneNumber::parse(trimmed, default_region) {
+            Ok(number) => return check_phone(number).map(Self::Phone),
+            Err(e) => e,
+        };
+
+        // Extensions aside, letters outnumbering digits means a name
+        let letters = trimmed.chars().filter(|ch| ch.is_alphabetic()).count();
+        let digits = trimmed.chars().filter(char::is_ascii_digit).count();
+        match !trimmed.is_empty() && letters > digits {
+            true => Err(ValidationError::new("email", Reason::MissingAt)),
+            false => Err(e),
+        }
+    }
+
+    pub fn as_email(&self) -> Option<&EmailAddress> {
+        match self {
+            AuthIdentifier::Email(address) => Some(address),
+            AuthIdentifier::Phone(_) => None,
+        }
+    }
+
+    pub fn as_phone(&self) -> Option<&PhoneNumber> {
+        match self {
+            AuthIdentifier::Email(_) => None,
+            AuthIdentifier::Phone(number) => Some(number),
+        }
+    }
+}
+
+/// The case folded address an email identifier is compared by
+fn identity(address: &EmailAddress) -> String {
+    CanonicalRules::empty().key(address)
+}
+
+impl PartialEq for AuthIdentifier {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (AuthIdentifier::Email(a), AuthIdentifier::Email(b)) => identity(a) == identity(b),
+            (AuthIdentifier::Phone(a), AuthIdentifier::Phone(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for AuthIdentifier {}
+
+impl Hash for AuthIdentifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        mem::discriminant(self).hash(state);
+        match self {
+            AuthIdentifier::Email(address) => identity(address).hash(state),
+            AuthIdentifier::Phone(number) => number.hash(state),
+        }
+    }
+}
+
+/// With numbering plans at hand, the number must also be one its region
+/// hands out
+#[cfg(feature = "phone_metadata")]
+fn check_phone(number: PhoneNumber) -> Result<PhoneNumber, ValidationError> {
+    match number.is_valid() {
+        true => Ok(number),
+        false => {
+            let region = number.region().unwrap_or_default().to_string();
+            Err(ValidationError::new(
+                "phone",
+                Reason::InvalidForRegion(region),
+            ))
+        }
+    }
+}
+
+#[cfg(not(feature = "phone_metadata"))]
+fn check_phone(number: PhoneNumber) -> Result<PhoneNumber, ValidationError> {
+    Ok(number)
+}
+
+impl fmt::Display for AuthIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AuthIdentifier::Email(address) => address.fmt(f),
+            AuthIdentifier::Phone(number) => number.fmt(f),
+        }
+    }
+}
+
+impl FromStr for AuthIdentifier {
+    type Err = ValidationError;
+
+    /// Parse an address or an international number; see
+    /// [`AuthIdentifier::parse`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, None)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for AuthIdentifier {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AuthIdentifier {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    fn reason(value: &str, region: Option<&str>) -> (String, Reason) {
+        let e = AuthIdentifier::parse(value, region).unwrap_err();
+        (e.field, e.reason)
+    }
+
+    #[test]
+    fn test_parse() {
+        let parse = |value, region| AuthIdentifier::parse(value, region).unwrap();
+
+        let email = parse("John.Doe@Example.COM", None);
+        assert_eq!(email.as_email().unwrap().local(), "John.Doe");
+        assert_eq!(email.as_email().unwrap().domain(), "example.com");
+        assert_eq!(email, parse("john.doe@example.com", None));
+        assert_ne!(email, parse("johndoe@example.com", None));
+        let accounts = HashSet::from([email, parse("JOHN.DOE@example.com", None)]);
+        assert_eq!(accounts.len(), 1);
+        assert_eq!(
+            parse("mailto:jane@example.com", None).to_string(),
+            "jane@example.com"
+        );
+
+        let phone = parse("+44 20 7946 0958", None);
+        assert_eq!(phone.as_phone().unwrap().country_code(), 44);
+        assert_eq!(parse("020 7946 0958", Some("GB")), phone);
+        assert_eq!(
+            parse("+44 20 7946 0958 ext. 12", None).to_string(),
+            "+442079460958 ext. 12"
+        );
+
+        assert_eq!(
+            reason("john.doe", None),
+            ("email".into(), Reason::MissingAt)
+        );
+        assert_eq!(
+            reason("john..doe@example.com", None).1,
+            Reason::BadCharacter { pos: 5, ch: '.' }
+        );
+        assert_eq!(
+            reason("020 7946 0958", None),
+            ("phone".into(), Reason::MissingCountryCode)
+        );
+        assert_eq!(reason("", None), ("phone".into(), Reason::Empty));
+    }
+}
@@ -0,0 +1,220 @@
+//! Canonical keys for spotting one mailbox written several ways
+//!
+//! ```
+//! use toolbox::validation::{CanonicalRules, ProviderRule};
+//!
+//! let rules = CanonicalRules::new();
+//! let a = rules.canonicalize("John.Doe+1@gmail.com").unwrap();
+//! let b = rules.canonicalize("johndoe@googlemail.com").unwrap();
+//! assert_eq!(a.key, b.key);
+//! assert_eq!(a.address.to_string(), "John.Doe+1@gmail.com");
+//!
+//! let rules = rules.rule(ProviderRule::new("example.com").tags('-'));
+//! assert_eq!(rules.canonicalize("bob-news@example.com").unwrap().key, "bob@example.com");
+//! ```
+
+use super::EmailAddress;
+use crate::validation::error::ValidationError;
+use std::sync::LazyLock;
+
+static GLOBAL: LazyLock<CanonicalRules> = LazyLock::new(CanonicalRules::new);
+
+/// How one provider reads the local parts of its addresses
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProviderRule {
+    /// The domain addresses are keyed under, e.g. `gmail.com`
+    pub domain: String,
+
+    /// Other domains delivering to the same mailboxes, e.g. `googlemail.com`
+    pub aliases: Vec<String>,
+
+    /// Dots in the local part are ignored, so `j.doe` is `jdoe`
+    pub ignore_dots: bool,
+
+    /// Starts a subaddress tag that is dropped, e.g. `+` in `jdoe+news`
+    pub tag_separator: Option<char>,
+}
+
+impl ProviderRule {
+    /// A provider that takes local parts as written
+    pub fn new(domain: &str) -> Self {
+        Self {
+            domain: domain.to_ascii_lowercase(),
+            aliases: Vec::new(),
+            ignore_dots: false,
+            tag_separator: None,
+        }
+    }
+
+    pub fn alias(mut self, domain: &str) -> Self {
+        self.aliases.push(domain.to_ascii_lowercase());
+        self
+    }
+
+    pub fn ignore_dots(mut self, ignore_dots: bool) -> Self {
+        self.ignore_dots = ignore_dots;
+        self
+    }
+
+    pub fn tags(mut self, separator: char) -> Self {
+        self.tag_separator = Some(separator);
+        self
+    }
+
+    fn covers(&self, domain: &str) -> bool {
+        self.domain == domain || self.aliases.iter().any(|alias| alias == domain)
+    }
+
+    /// Gmail and its `googlemail.com` alias, Outlook's consumer domains and
+    /// Fastmail
+    pub fn defaults() -> Vec<ProviderRule> {
+        let mut rules = vec![
+            ProviderRule::new("gmail.com")
+                .alias("googlemail.com")
+                .ignore_dots(true)
+                .tags('+'),
+        ];
+        for domain in ["outlook.com", "hotmail.com", "live.com", "msn.com"] {
+            rules.push(ProviderRule::new(domain).tags('+'));
+        }
+        rules.push(ProviderRule::new("fastmail.com").tags('+'));
+        rules.push(ProviderRule::new("fastmail.fm").tags('+'));
+        rules
+    }
+}
+
+/// An address as given, for sending to, with the key it is unique under
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CanonicalEmail {
+    pub address: EmailAddress,
+    pub key: String,
+}
+
+/// The provider rules canonical keys are built with
+#[derive(Clone, Debug)]
+pub struct CanonicalRules {
+    /// Searched from the end, so a rule added later overrides a default
+    pub providers: Vec<ProviderRule>,
+
+    /// Lowercase every local part, not only those of known providers.
+    /// RFC 5321 leaves case to the receiving server, but hardly any
+    /// server tells `John` from `john`.
+    pub fold_case: bool,
+}
+
+impl Default for CanonicalRules {
+    fn default() -> Self {
+        Self {
+            providers: ProviderRule::defaults(),
+            fold_case: true,
+        }
+    }
+}
+
+impl CanonicalRules {
+    /// The [`ProviderRule::defaults`], folding case everywhere
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// No provider rules: keys differ from addresses only in case
+    pub fn empty() -> Self {
+        Self {
+            providers: Vec::new(),
+            fold_case: true,
+        }
+    }
+
+    /// The rules behind [`canonicalize_email`](crate::validation::canonicalize_email)
+    pub fn global() -> &'static CanonicalRules {
+        &GLOBAL
+    }
+
+    pub fn rule(mut self, rule: ProviderRule) -> Self {
+        self.providers.push(rule);
+        self
+    }
+
+    pub fn fold_case(mut self, fold_case: bool) -> Self {
+        self.fold_case = fold_case;
+        self
+    }
+
+    /// Parse `value` and pair it with its key
+    pub fn canonicalize(&self, value: &str) -> Result<CanonicalEmail, ValidationError> {
+        let address = EmailAddress::parse(value)?;
+        let key = self.key(&address);
+        Ok(CanonicalEmail { address, key })
+    }
+
+    /// The key for `address`: its domain folded to the provider's main one,
+    /// and its local part stripped of what the provider ignores. Quoted
+    /// local parts are only case folded.
+    pub fn key(&self, address: &EmailAddress) -> String {
+        let rule = self
+            .providers
+            .iter()
+            .rev()
+            .find(|rule| rule.covers(address.domain()));
+
+        let mut local = address.local().to_string();
+        let mut domain = address.domain();
+        if let Some(rule) = rule {
+            domain = &rule.domain;
+            if !local.starts_with('"') {
+                // A tag needs something before it to be a tag
+                if let Some(separator) = rule.tag_separator
+                    && let Some((base, _)) = local.split_once(separator)
+                    && !base.is_empty()
+                {
+                    local.truncate(base.len());
+                }
+                if rule.ignore_dots {
+                    local.retain(|ch| ch != '.');
+                }
+            }
+        }
+
+        if self.fold_case || rule.is_some() {
+            local = local.to_lowercase();
+        }
+        format!("{local}@{domain}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonicalize() {
+        let rules = CanonicalRules::new();
+        let key = |value| rules.canonicalize(value).unwrap().key;
+
+        assert_eq!(key("john.doe+1@gmail.com"), "johndoe@gmail.com");
+        assert_eq!(key("J.O.H.N.DOE@GoogleMail.com"), "johndoe@gmail.com");
+        assert_eq!(key("john.doe+promo@outlook.com"), "john.doe@outlook.com");
+        assert_eq!(key("jane+shop@fastmail.com"), "jane@fastmail.com");
+        assert_eq!(key("+tag@gmail.com"), "+tag@gmail.com");
+        assert_eq!(key("john.doe+1@example.com"), "john.doe+1@example.com");
+        assert_eq!(key("John@Example.COM"), "john@example.com");
+        assert_eq!(key("\"j.doe\"@gmail.com"), "\"j.doe\"@gmail.com");
+
+        let original = rules.canonicalize("John.Doe+1@Gmail.com").unwrap();
+        assert_eq!(original.address.to_string(), "John.Doe+1@gmail.com");
+
+        let rules = CanonicalRules::empty().fold_case(false);
+        assert_eq!(
+            rules.canonicalize("John.Doe+1@gmail.com").unwrap().key,
+            "John.Doe+1@gmail.com"
+        );
+
+        // Later rules win over the defaults
+        let rules = CanonicalRules::new().rule(ProviderRule::new("gmail.com"));
+        assert_eq!(
+            rules.canonicalize("john.doe+1@gmail.com").unwrap().key,
+            "john.doe+1@gmail.com"
+        );
+    }
+}
@@ -0,0 +1,331 @@
+//! Whether an email domain can receive mail, from its MX, A and AAAA
+//! records (RFC 5321 5.1) and null MX records (RFC 7505)
+//!
+//! ```
+//! use toolbox::dns::{Record, RecordType};
+//! use toolbox::validation::{Deliverability, DeliverabilityCheck};
+//!
+//! let stub = |name: &str, rtype: RecordType| match (name, rtype) {
+//!     ("example.com", RecordType::Mx) => Ok(vec![Record::Mx {
+//!         preference: 10,
+//!         exchange: "mx.example.com".into(),
+//!     }]),
+//!     ("nomail.example", RecordType::Mx) => Ok(vec![Record::Mx {
+//!         preference: 0,
+//!         exchange: "".into(),
+//!     }]),
+//!     _ => Ok(vec![]),
+//! };
+//!
+//! let check = DeliverabilityCheck::new().resolver(stub);
+//! assert!(check.check("example.com").unwrap().is_deliverable());
+//! assert_eq!(check.check("nomail.example").unwrap(), Deliverability::NullMx);
+//! assert_eq!(check.check("doesnotexist.example").unwrap(), Deliverability::NoRecords);
+//! ```
+
+use super::EmailAddress;
+use crate::dns::{Record, RecordType, Resolver, UdpResolver};
+use std::collections::HashMap;
+use std::io;
+use std::net::IpAddr;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Where a domain's mail goes
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Deliverability {
+    /// Its mail exchangers, most preferred first
+    Mx(Vec<String>),
+
+    /// No MX records, so mail goes to the domain's own addresses
+    Implicit(Vec<IpAddr>),
+
+    /// A null MX: the domain takes no mail at all
+    NullMx,
+
+    /// No MX, A or AAAA records, e.g. a domain that does not exist
+    NoRecords,
+}
+
+impl Deliverability {
+    pub fn is_deliverable(&self) -> bool {
+        matches!(self, Deliverability::Mx(_) | Deliverability::Implicit(_))
+    }
+}
+
+/// Looks up email domains, remembering recent answers
+#[derive(Clone)]
+pub struct DeliverabilityCheck {
+    /// The system nameservers unless replaced
+    pub resolver: Option<Arc<dyn Resolver>>,
+
+    /// How long one domain's lookups may take in all
+    pub timeout: Duration,
+
+    /// How many answers are remembered; 0 turns the cache off
+    pub cache_size: usize,
+
+    /// How long an answer is remembered
+    pub cache_ttl: Duration,
+
+    cache: Arc<Mutex<HashMap<String, (Instant, Deliverability)>>>,
+}
+
+impl Default for DeliverabilityCheck {
+    fn default() -> Self {
+        Self {
+            resolver: UdpResolver::system()
+                .ok()
+                .map(|resolver| Arc::new(resolver) as Arc<dyn Resolver>),
+            timeout: Duration::from_secs(5),
+            cache_size: 256,
+            cache_ttl: Duration::from_secs(300),
+            cache: Arc::default(),
+        }
+    }
+}
+
+impl DeliverabilityCheck {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn resolver(mut self, resolver: impl Resolver + 'static) -> Self {
+        self.resolver = Some(Arc::new(resolver));
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn cache_size(mut self, cache_size: usize) -> Self {
+        self.cache_size = cache_size;
+        self
+    }
+
+    pub fn cache_ttl(mut self, cache_ttl: Duration) -> Self {
+        self.cache_ttl = cache_ttl;
+        self
+    }
+
+    /// Check the domain of `address`. An address literal needs no lookup.
+    pub fn check_address(&self, address: &EmailAddress) -> io::Result<Deliverability> {
+        let domain = address.domain();
+        let Some(literal) = domain.strip_prefix('[').and_then(|d| d.strip_suffix(']')) else {
+            return self.check(domain);
+        };
+
+        let ip = literal.get(5..).unwrap_or_default();
+        match literal.parse().or_else(|_| ip.parse()) {
+            Ok(ip) => Ok(Deliverability::Implicit(vec![ip])),
+            Err(_) => Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("invalid address literal {domain}"),
+            )),
+        }
+    }
+
+    /// Where mail for `domain` goes. Failed lookups are not cached, and
+    /// fail with [`io::ErrorKind::TimedOut`] once [`Self::timeout`] passes.
+    pub fn check(&self, domain: &str) -> io::Result<Deliverability> {
+        let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+        if let Some(found) = self.cached(&domain) {
+            return Ok(found);
+        }
+
+        let Some(resolver) = self.resolver.clone() else {
+            return Err(io::Error::new(
+                io::ErrorKind::NotFound,
+                "no DNS resolver configured",
+            ));
+        };
+
+        // On a thread of its own, so a slow resolver cannot hold us past
+        // the timeout; it finishes in the background
+        let (tx, rx) = mpsc::channel();
+        let name = domain.clone();
+        thread::spawn(move || {
+            let _ = tx.send(lookup(resolver.as_ref(), &name));
+        });
+        let found = match rx.recv_timeout(self.timeout) {
+            Ok(found) => found?,
+            Err(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::TimedOut,
+                    format!("looking up {domain} took over {:?}", self.timeout),
+                ));
+            }
+        };
+
+        self.remember(domain, found.clone());
+        Ok(found)
+    }
+
+    fn cached(&self, domain: &str) -> Option<Deliverability> {
+        let cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        cache
+            .get(domain)
+            .filter(|(at, _)| at.elapsed() < self.cache_ttl)
+            .map(|(_, found)| found.clone())
+    }
+
+    fn remember(&self, domain: String, found: Deliverability) {
+        if self.cache_size == 0 {
+            return;
+        }
+
+        let mut cache = self.cache.lock().unwrap_or_else(|e| e.into_inner());
+        cache.retain(|_, (at, _)| at.elapsed() < self.cache_ttl);
+        if cache.len() >= self.cache_size && !cache.contains_key(&domain) {
+            let oldest = cache
+                .iter()
+                .min_by_key(|(_, (at, _))| *at)
+                .map(|(domain, _)| domain.clone());
+            if let Some(oldest) = oldest {
+                cache.remove(&oldest);
+            }
+        }
+        cache.insert(domain, (Instant::now(), found));
+    }
+}
+
+/// MX records first; only without any do A and AAAA count
+fn lookup(resolver: &dyn Resolver, domain: &str) -> io::Result<Deliverability> {
+    let mut exchanges: Vec<(u16, String)> = resolver
+        .query(domain, RecordType::Mx)?
+        .into_iter()
+        .filter_map(|record| match record {
+            Record::Mx {
+                preference,
+                exchange,
+            } => Some((preference, exchange)),
+            _ => None,
+        })
+        .collect();
+
+    // A null MX names the root as its only exchange
+    let is_root = |exchange: &str| exchange.is_empty() || exchange == ".";
+    if let [(_, exchange)] = exchanges.as_slice()
+        && is_root(exchange)
+    {
+        return Ok(Deliverability::NullMx);
+    }
+    exchanges.retain(|(_, exchange)| !is_root(exchange));
+    if !exchanges.is_empty() {
+        exchanges.sort();
+        let hosts = exchanges.into_iter().map(|(_, host)| host).collect();
+        return Ok(Deliverability::Mx(hosts));
+    }
+
+    let ips = resolver.lookup_ip(domain)?;
+    match ips.is_empty() {
+        true => Ok(Deliverability::NoRecords),
+        false => Ok(Deliverability::Implicit(ips)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::{SocketAddr, UdpSocket};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    /// A nameserver on localhost answering from `zone`, counting queries
+    fn stub(zone: Vec<(&'static str, u16, Vec<u8>)>) -> (SocketAddr, Arc<AtomicUsize>) {
+        let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+        let addr = socket.local_addr().unwrap();
+        let queries = Arc::new(AtomicUsize::new(0));
+        let counter = queries.clone();
+
+        thread::spawn(move || {
+            let mut buf = [0u8; 512];
+            while let Ok((n, peer)) = socket.recv_from(&mut buf) {
+                counter.fetch_add(1, Ordering::SeqCst);
+                let query = &buf[..n];
+
+                // The question name, then its type
+                let mut at = 12;
+                let mut labels = Vec::new();
+                while query[at] != 0 {
+                    let len = query[at] as usize;
+                    labels.push(String::from_utf8_lossy(&query[at + 1..at + 1 + len]).into_owned());
+                    at += 1 + len;
+                }
+                let (name, rtype) = (
+                    labels.join("."),
+                    u16::from_be_bytes([query[at + 1], query[at + 2]]),
+                );
+                let answers: Vec<_> = zone
+                    .iter()
+                    .filter(|(owner, kind, _)| *owner == name && *kind == rtype)
+                    .collect();
+
+                let mut response = query[..2].to_vec();
+                response.extend_from_slice(&[0x81, 0x80, 0, 1]);
+                response.extend_from_slice(&(answers.len() as u16).to_be_bytes());
+                response.extend_from_slice(&[0, 0, 0, 0]);
+                response.extend_from_slice(&query[12..]);
+                for (_, rtype, rdata) in answers {
+                    response.extend_from_slice(&[0xc0, 12]);
+                    response.extend_from_slice(&rtype.to_be_bytes());
+                    response.extend_from_slice(&[0, 1, 0, 0, 1, 0]);
+                    response.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+                    response.extend_from_slice(rdata);
+                }
+                socket.send_to(&response, peer).unwrap();
+            }
+        });
+
+        (addr, queries)
+    }
+
+    #[test]
+    fn test_deliverability() {
+        let (server, queries) = stub(vec![
+            ("example.com", 15, b"\x00\x14\x03mx2\xc0\x0c".to_vec()),
+            ("example.com", 15, b"\x00\x0a\x03mx1\xc0\x0c".to_vec()),
+            ("nomail.example", 15, b"\x00\x00\x00".to_vec()),
+            ("bare.example", 1, vec![192, 0, 2, 25]),
+        ]);
+        let check = DeliverabilityCheck::new().resolver(UdpResolver::new(vec![server]));
+
+        assert_eq!(
+            check.check("Example.com").unwrap(),
+            Deliverability::Mx(vec!["mx1.example.com".into(), "mx2.example.com".into()])
+        );
+        assert_eq!(
+            check.check("nomail.example").unwrap(),
+            Deliverability::NullMx
+        );
+        assert_eq!(
+            check.check("bare.example").unwrap(),
+            Deliverability::Implicit(vec!["192.0.2.25".parse().unwrap()])
+        );
+        assert_eq!(
+            check.check("doesnotexist.example").unwrap(),
+            Deliverability::NoRecords
+        );
+
+        // Answered from the cache
+        let before = queries.load(Ordering::SeqCst);
+        assert!(check.check("example.com.").unwrap().is_deliverable());
+        assert_eq!(queries.load(Ordering::SeqCst), before);
+
+        let address = EmailAddress::parse("user@[192.0.2.1]").unwrap();
+        assert!(check.check_address(&address).unwrap().is_deliverable());
+
+        let slow = |_: &str, _: RecordType| {
+            thread::sleep(Duration::from_millis(500));
+            Ok(vec![])
+        };
+        let check = DeliverabilityCheck::new()
+            .resolver(slow)
+            .timeout(Duration::from_millis(50));
+        let e = check.check("example.com").unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::TimedOut);
+    }
+}
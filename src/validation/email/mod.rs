@@ -0,0 +1,499 @@
+//! Email addresses parsed per RFC 5321 and RFC 5322
+//!
+//! ```
+//! use toolbox::validation::{EmailAddress, EmailMode};
+//!
+//! let address: EmailAddress = "\"john doe\"@Example.COM".parse().unwrap();
+//! assert_eq!(address.local(), "\"john doe\"");
+//! assert_eq!(address.domain(), "example.com");
+//!
+//! assert!(EmailAddress::parse("john..doe@example.com").is_err());
+//! assert!(EmailAddress::parse_with("john..doe@example.com", EmailMode::Html5).is_ok());
+//! ```
+//!
+//! With the `idn` feature, local parts may hold UTF-8 (RFC 6531) and
+//! domains may be internationalized; the domain is kept in its ASCII
+//! (punycode) form, as DNS and SMTP see it.
+
+mod canonical;
+#[cfg(feature = "email_dns")]
+mod deliverability;
+mod risk;
+mod suggest;
+
+pub use canonical::{CanonicalEmail, CanonicalRules, ProviderRule};
+#[cfg(feature = "email_dns")]
+pub use deliverability::{Deliverability, DeliverabilityCheck};
+pub use risk::{DomainList, EmailRisk, ROLES, RiskRules};
+pub use suggest::{Suggester, Suggestion};
+
+use super::error::{Reason, ValidationError};
+use std::borrow::Cow;
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+/// Longest local part, in octets (RFC 5321 4.5.3.1.1)
+const MAX_LOCAL: usize = 64;
+
+/// Longest domain, in octets (RFC 5321 4.5.3.1.2)
+const MAX_DOMAIN: usize = 255;
+
+/// Longest address that fits a 256-octet SMTP path with its angle brackets
+const MAX_ADDRESS: usize = 254;
+
+/// Longest domain label, in octets (RFC 1035)
+const MAX_LABEL: usize = 63;
+
+/// Characters allowed in an unquoted local part besides letters and digits
+const ATEXT_SPECIALS: &str = "!#$%&'*+-/=?^_`{|}~";
+
+/// How strictly [`EmailAddress::parse_with`] reads an address
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EmailMode {
+    /// An SMTP mailbox: a dot-atom or quoted local part, and a host name
+    /// with a top-level domain or an `[address literal]`. Comments and
+    /// folding whitespace, which SMTP cannot carry, are rejected.
+    #[default]
+    Strict,
+
+    /// What a browser's `<input type="email">` accepts: no quoting or
+    /// address literals, but dots anywhere in the local part and
+    /// single-label domains such as `localhost`
+    Html5,
+}
+
+/// An email address split into its local part and domain
+///
+/// A host name domain is lowercased, and internationalized ones are kept
+/// in punycode; the local part is kept as written, since only the
+/// receiving server may decide whether its case matters.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct EmailAddress {
+    local: String,
+    domain: String,
+}
+
+impl EmailAddress {
+    /// Parse an address in [`EmailMode::Strict`]
+    pub fn parse(value: &str) -> Result<Self, ValidationError> {
+        Self::parse_with(value, EmailMode::Strict)
+    }
+
+    /// Parse an address, checking the length limits of RFC 5321 in every
+    /// mode: 64 octets before the `@`, 255 after and 254 in all
+    pub fn parse_with(value: &str, mode: EmailMode) -> Result<Self, ValidationError> {
+        let err = |reason| Err(ValidationError::new("email", reason));
+
+        let value = value.trim();
+        if value.is_empty() {
+            return err(Reason::Empty);
+        }
+        if !value.contains('@') {
+            return err(Reason::MissingAt);
+        }
+
+        let (local, domain) = match split(value, mode) {
+            Ok(parts) => parts,
+            Err(reason) => return err(reason),
+        };
+        let domain_start = local.chars().count() + 1;
+        if let Err(reason) = check_local(local, mode) {
+            return err(reason);
+        }
+        let ascii = match ascii_domain(domain) {
+            Ok(ascii) => ascii,
+            Err(reason) => return err(reason),
+        };
+        match check_domain(&ascii, mode, domain_start) {
+            // Positions in the punycode would not point into the input
+            Err(Reason::BadCharacter { .. }) if ascii != domain => {
+                return err(Reason::BadInternationalDomain);
+            }
+            Err(reason) => return err(reason),
+            Ok(()) => {}
+        }
+        if local.len() + 1 + ascii.len() > MAX_ADDRESS {
+            return err(Reason::TooLong { max: MAX_ADDRESS });
+        }
+
+        // Address literals keep their tag as written, e.g. `IPv6:`
+        let domain = match ascii.starts_with('[') {
+            true => ascii.into_owned(),
+            false => ascii.to_ascii_lowercase(),
+        };
+        Ok(Self {
+            local: local.to_string(),
+            domain,
+        })
+    }
+
+    /// The part before the `@`, with any quotes
+    pub fn local(&self) -> &str {
+        &self.local
+    }
+
+    /// The part after the `@`, lowercased unless it is an address literal,
+    /// and in punycode if internationalized
+    pub fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    /// The domain with any punycode labels decoded, e.g. `bücher.de` for
+    /// `xn--bcher-kva.de`
+    #[cfg(feature = "idn")]
+    pub fn unicode_domain(&self) -> String {
+        match self.domain.starts_with('[') {
+            true => self.domain.clone(),
+            false => idna::domain_to_unicode(&self.domain).0,
+        }
+    }
+
+    /// The address with its domain in Unicode, for showing to people
+    #[cfg(feature = "idn")]
+    pub fn to_unicode(&self) -> String {
+        format!("{}@{}", self.local, self.unicode_domain())
+    }
+
+    /// The address in ASCII, as a server without SMTPUTF8 takes it, or
+    /// `None` when the local part has UTF-8 that has no ASCII form
+    #[cfg(feature = "idn")]
+    pub fn to_ascii(&self) -> Option<String> {
+        match self.requires_smtputf8() {
+            true => None,
+            false => Some(self.to_string()),
+        }
+    }
+
+    /// Whether delivery needs a server that offers SMTPUTF8 (RFC 6531)
+    #[cfg(feature = "idn")]
+    pub fn requires_smtputf8(&self) -> bool {
+        !self.local.is_ascii()
+    }
+}
+
+/// Split at the `@` that ends the local part. A quoted local part may hold
+/// `@` itself, so its closing quote is found first.
+fn split(value: &str, mode: EmailMode) -> Result<(&str, &str), Reason> {
+    if mode == EmailMode::Strict && value.starts_with('"') {
+        let mut escaped = false;
+        let close = value
+            .char_indices()
+            .skip(1)
+            .find(|&(_, ch)| {
+                let close = ch == '"' && !escaped;
+                escaped = ch == '\\' && !escaped;
+                close
+            })
+            .map(|(i, _)| i);
+
+        let Some(close) = close else {
+            return Err(Reason::BadFormat);
+        };
+        return match value[close + 1..].chars().next() {
+            Some('@') => Ok((&value[..=close], &value[close + 2..])),
+            Some(ch) => {
+                let pos = value[..=close].chars().count();
+                Err(Reason::BadCharacter { pos, ch })
+            }
+            None => Err(Reason::MissingAt),
+        };
+    }
+
+    let at = value.find('@').expect("checked for @");
+    Ok((&value[..at], &value[at + 1..]))
+}
+
+/// Whether `ch` may appear in a local part as RFC 6531 `UTF8-non-ascii`
+fn is_utf8_text(ch: char, mode: EmailMode) -> bool {
+    cfg!(feature = "idn") && mode == EmailMode::Strict && !ch.is_ascii() && !ch.is_control()
+}
+
+fn check_local(local: &str, mode: EmailMode) -> Result<(), Reason> {
+    if local.is_empty() {
+        return Err(Reason::MissingLocalPart);
+    }
+
+    let quoted = local.strip_prefix('"').and_then(|l| l.strip_suffix('"'));
+    match quoted {
+        Some(quoted) if mode == EmailMode::Strict => check_quoted(quoted)?,
+        _ => check_dot_atom(local, mode)?,
+    }
+
+    match local.len() > MAX_LOCAL {
+        true => Err(Reason::LocalPartTooLong { max: MAX_LOCAL }),
+        false => Ok(()),
+    }
+}
+
+/// Printable ASCII, with `"` and `\` only after a `\`
+fn check_quoted(quoted: &str) -> Result<(), Reason> {
+    let mut escaped = false;
+    for (i, ch) in quoted.chars().enumerate() {
+        let printable = (' '..='~').contains(&ch) || is_utf8_text(ch, EmailMode::Strict);
+        if !printable || (!escaped && ch == '"') {
+            return Err(Reason::BadCharacter { pos: i + 1, ch });
+        }
+        escaped = ch == '\\' && !escaped;
+    }
+    Ok(())
+}
+
+/// `atext` separated by single dots; HTML5 puts dots anywhere
+fn check_dot_atom(local: &str, mode: EmailMode) -> Result<(), Reason> {
+    let last = local.chars().count() - 1;
+    let mut previous = None;
+
+    for (pos, ch) in local.chars().enumerate() {
+        let atext =
+            ch.is_ascii_alphanumeric() || ATEXT_SPECIALS.contains(ch) || is_utf8_text(ch, mode);
+        if !atext && ch != '.' {
+            return Err(Reason::BadCharacter { pos, ch });
+        }
+
+        let stray_dot = ch == '.' && (pos == 0 || pos == last || previous == Some('.'));
+        if stray_dot && mode == EmailMode::Strict {
+            return Err(Reason::BadCharacter { pos, ch });
+        }
+        previous = Some(ch);
+    }
+    Ok(())
+}
+
+/// The domain as DNS sees it: internationalized domains, and ASCII ones
+/// with punycode labels, go through IDNA (UTS 46) to check and normalize
+/// them. Address literals and plain host names pass through untouched.
+#[cfg(feature = "idn")]
+fn ascii_domain(domain: &str) -> Result<Cow<'_, str>, Reason> {
+    let punycode = domain.split('.').any(|label| {
+        label
+            .get(..4)
+            .is_some_and(|prefix| prefix.eq_ignore_ascii_case("xn--"))
+    });
+    if domain.starts_with('[') || (domain.is_ascii() && !punycode) {
+        return Ok(Cow::Borrowed(domain));
+    }
+    idna::domain_to_ascii_cow(domain.as_bytes(), idna::AsciiDenyList::STD3)
+        .map_err(|_| Reason::BadInternationalDomain)
+}
+
+#[cfg(not(feature = "idn"))]
+fn ascii_domain(domain: &str) -> Result<Cow<'_, str>, Reason> {
+    Ok(Cow::Borrowed(domain))
+}
+
+/// A host name, or in strict mode an `[IPv4]` or `[IPv6:…]` literal.
+/// Positions in errors count from the start of the address.
+fn check_domain(domain: &str, mode: EmailMode, start: usize) -> Result<(), Reason> {
+    if domain.is_empty() {
+        return Err(Reason::MissingDomain);
+    }
+
+    if mode == EmailMode::Strict && domain.starts_with('[') {
+        let literal = domain
+            .strip_prefix('[')
+            .and_then(|d| d.strip_suffix(']'))
+            .ok_or(Reason::BadAddressLiteral)?;
+        let valid = match literal.get(..5) {
+            Some(tag) if tag.eq_ignore_ascii_case("IPv6:") => {
+                literal[5..].parse::<Ipv6Addr>().is_ok()
+            }
+            _ => literal.parse::<Ipv4Addr>().is_ok(),
+        };
+        return match valid {
+            true => Ok(()),
+            false => Err(Reason::BadAddressLiteral),
+        };
+    }
+
+    for (i, ch) in domain.chars().enumerate() {
+        if !ch.is_ascii_alphanumeric() && ch != '-' && ch != '.' {
+            return Err(Reason::BadCharacter { pos: start + i, ch });
+        }
+    }
+
+    // Empty labels and labels starting or ending with a hyphen
+    let mut pos = start;
+    for label in domain.split('.') {
+        let len = label.len();
+        if label.is_empty() {
+            return Err(Reason::BadCharacter { pos, ch: '.' });
+        }
+        if label.starts_with('-') {
+            return Err(Reason::BadCharacter { pos, ch: '-' });
+        }
+        if label.ends_with('-') {
+            let pos = pos + len - 1;
+            return Err(Reason::BadCharacter { pos, ch: '-' });
+        }
+        if len > MAX_LABEL {
+            return Err(Reason::LabelTooLong);
+        }
+        pos += len + 1;
+    }
+
+    if domain.len() > MAX_DOMAIN {
+        return Err(Reason::DomainTooLong { max: MAX_DOMAIN });
+    }
+    if mode == EmailMode::Strict {
+        // A top-level domain is never all digits, so `user@1.2.3.4` is an
+        // address literal missing its brackets
+        match domain.rsplit_once('.') {
+            None => return Err(Reason::MissingTld),
+            Some((_, tld)) if tld.bytes().all(|b| b.is_ascii_digit()) => {
+                return Err(Reason::BadFormat);
+            }
+            Some(_) => {}
+        }
+    }
+    Ok(())
+}
+
+impl fmt::Display for EmailAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}@{}", self.local, self.domain)
+    }
+}
+
+impl FromStr for EmailAddress {
+    type Err = ValidationError;
+
+    /// Parse an address in [`EmailMode::Strict`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for EmailAddress {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for EmailAddress {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reason(value: &str, mode: EmailMode) -> Reason {
+        EmailAddress::parse_with(value, mode).unwrap_err().reason
+    }
+
+    #[test]
+    fn test_strict() {
+        for valid in [
+            "john.doe@example.com",
+            "x@example.co.uk",
+            "user+tag@sub.example.org",
+            "\"john doe\"@example.com",
+            "\"john@home\"@example.com",
+            "\"quote\\\"d\"@example.com",
+            "user@[192.0.2.1]",
+            "user@[IPv6:2001:db8::1]",
+            "!#$%&'*+-/=?^_`{|}~@example.com",
+        ] {
+            let address = EmailAddress::parse(valid).unwrap();
+            assert_eq!(address.to_string(), valid);
+        }
+
+        let strict = EmailMode::Strict;
+        assert_eq!(
+            reason("john..doe@example.com", strict),
+            Reason::BadCharacter { pos: 5, ch: '.' }
+        );
+        assert_eq!(
+            reason(".john@example.com", strict),
+            Reason::BadCharacter { pos: 0, ch: '.' }
+        );
+        assert_eq!(
+            reason(&format!("{}@example.com", "a".repeat(65)), strict),
+            Reason::LocalPartTooLong { max: 64 }
+        );
+        let long = format!("{}@{}example.com", "a".repeat(64), "b.".repeat(90));
+        assert_eq!(reason(&long, strict), Reason::TooLong { max: 254 });
+        assert_eq!(reason("\"open@example.com", strict), Reason::BadFormat);
+        assert_eq!(
+            reason("\"a\"b@example.com", strict),
+            Reason::BadCharacter { pos: 3, ch: 'b' }
+        );
+        assert_eq!(
+            reason("user@[192.0.2.256]", strict),
+            Reason::BadAddressLiteral
+        );
+        assert_eq!(reason("user@192.0.2.1", strict), Reason::BadFormat);
+        assert_eq!(reason("user@localhost", strict), Reason::MissingTld);
+    }
+
+    #[test]
+    fn test_html5() {
+        let html5 = EmailMode::Html5;
+        for valid in [
+            "john..doe@example.com",
+            ".john.@example.com",
+            "user@localhost",
+        ] {
+            assert!(EmailAddress::parse_with(valid, html5).is_ok(), "{valid}");
+        }
+
+        assert_eq!(
+            reason("\"john doe\"@example.com", html5),
+            Reason::BadCharacter { pos: 0, ch: '"' }
+        );
+        assert_eq!(
+            reason("user@[192.0.2.1]", html5),
+            Reason::BadCharacter { pos: 5, ch: '[' }
+        );
+        assert_eq!(
+            reason(&format!("{}@example.com", "a".repeat(65)), html5),
+            Reason::LocalPartTooLong { max: 64 }
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "idn")]
+    fn test_idn() {
+        let address = EmailAddress::parse("user@Bücher.de").unwrap();
+        assert_eq!(address.domain(), "xn--bcher-kva.de");
+        assert_eq!(address.unicode_domain(), "bücher.de");
+        assert_eq!(address.to_ascii().as_deref(), Some("user@xn--bcher-kva.de"));
+        assert!(!address.requires_smtputf8());
+
+        let address = EmailAddress::parse("用户@例子.广告").unwrap();
+        assert_eq!(address.local(), "用户");
+        assert_eq!(address.domain(), "xn--fsqu00a.xn--4rr70v");
+        assert_eq!(address.to_unicode(), "用户@例子.广告");
+        assert_eq!(address.to_ascii(), None);
+        assert!(address.requires_smtputf8());
+
+        let address = EmailAddress::parse("user@xn--bcher-kva.de").unwrap();
+        assert_eq!(address.unicode_domain(), "bücher.de");
+
+        let strict = EmailMode::Strict;
+        assert_eq!(
+            reason("user@xn--a.de", strict),
+            Reason::BadInternationalDomain
+        );
+        assert_eq!(
+            reason("user@bü_cher.de", strict),
+            Reason::BadInternationalDomain
+        );
+        // Each of these is three octets in UTF-8
+        assert_eq!(
+            reason(&format!("{}@example.com", "用".repeat(22)), strict),
+            Reason::LocalPartTooLong { max: 64 }
+        );
+        assert_eq!(
+            reason("用户@example.com", EmailMode::Html5),
+            Reason::BadCharacter { pos: 0, ch: '用' }
+        );
+        assert!(EmailAddress::parse_with("user@bücher.de", EmailMode::Html5).is_ok());
+    }
+}
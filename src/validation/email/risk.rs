@@ -0,0 +1,242 @@
+//! Throwaway and role-account addresses
+//!
+//! ```
+//! use toolbox::validation::{DomainList, RiskRules};
+//!
+//! let rules = RiskRules::new();
+//! let risk = rules.assess("x7@eu.mailinator.com").unwrap();
+//! assert_eq!(risk.disposable.as_deref(), Some("mailinator.com"));
+//! assert!(rules.assess("no-reply@example.com").unwrap().is_role());
+//!
+//! let rules = RiskRules::new().disposable(DomainList::parse("burner.example\n"));
+//! assert!(rules.assess("me@burner.example").unwrap().is_disposable());
+//! assert!(!rules.assess("me@mailinator.com").unwrap().is_disposable());
+//! ```
+
+use super::EmailAddress;
+use crate::validation::error::ValidationError;
+use std::collections::HashSet;
+use std::io;
+use std::path::Path;
+use std::sync::LazyLock;
+
+/// The disposable domains shipped with the crate
+const BUNDLED: &str = include_str!("disposable.txt");
+
+/// Local parts that name a function or a machine rather than a person
+pub const ROLES: [&str; 32] = [
+    "abuse",
+    "admin",
+    "administrator",
+    "billing",
+    "contact",
+    "do-not-reply",
+    "donotreply",
+    "enquiries",
+    "hello",
+    "help",
+    "hostmaster",
+    "hr",
+    "info",
+    "jobs",
+    "legal",
+    "mailer-daemon",
+    "marketing",
+    "no-reply",
+    "noc",
+    "noreply",
+    "office",
+    "postmaster",
+    "privacy",
+    "root",
+    "sales",
+    "security",
+    "spam",
+    "support",
+    "sysadmin",
+    "team",
+    "webmaster",
+    "www",
+];
+
+static GLOBAL: LazyLock<RiskRules> = LazyLock::new(RiskRules::new);
+
+/// A set of domains that also covers their subdomains
+#[derive(Clone, Debug, Default)]
+pub struct DomainList {
+    domains: HashSet<String>,
+}
+
+impl DomainList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The list shipped with the crate
+    pub fn bundled() -> Self {
+        Self::parse(BUNDLED)
+    }
+
+    pub fn insert(&mut self, domain: &str) {
+        let domain = domain.trim().trim_end_matches('.').to_ascii_lowercase();
+        if !domain.is_empty() {
+            self.domains.insert(domain);
+        }
+    }
+
+    /// Parse a list with one domain per line; `#` starts a comment
+    pub fn parse(text: &str) -> Self {
+        let mut list = Self::new();
+        for line in text.lines() {
+            let entry = line.split('#').next().unwrap_or_default();
+            list.insert(entry);
+        }
+        list
+    }
+
+    /// Read and [`parse`](Self::parse) a list from disk
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self::parse(&std::fs::read_to_string(path)?))
+    }
+
+    /// Add every domain of `other`, e.g. local additions to the bundled list
+    pub fn extend(&mut self, other: DomainList) {
+        self.domains.extend(other.domains);
+    }
+
+    /// The listed domain that is `domain` or one of its parents
+    pub fn find(&self, domain: &str) -> Option<&str> {
+        let domain = domain.trim_end_matches('.').to_ascii_lowercase();
+        let mut rest = domain.as_str();
+        loop {
+            if let Some(found) = self.domains.get(rest) {
+                return Some(found);
+            }
+            rest = rest.split_once('.')?.1;
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.domains.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.domains.is_empty()
+    }
+}
+
+/// What is risky about an address; each flow decides whether that means
+/// reject, warn or allow
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EmailRisk {
+    /// The listed disposable domain the address falls under, e.g.
+    /// `mailinator.com` for `x@eu.mailinator.com`
+    pub disposable: Option<String>,
+
+    /// The role its local part names, e.g. `postmaster`
+    pub role: Option<String>,
+}
+
+impl EmailRisk {
+    pub fn is_disposable(&self) -> bool {
+        self.disposable.is_some()
+    }
+
+    pub fn is_role(&self) -> bool {
+        self.role.is_some()
+    }
+
+    /// Neither disposable nor a role account
+    pub fn is_clean(&self) -> bool {
+        !self.is_disposable() && !self.is_role()
+    }
+}
+
+/// The lists addresses are assessed against
+#[derive(Clone, Debug)]
+pub struct RiskRules {
+    pub disposable: DomainList,
+
+    /// Matched against the lowercased local part, without any `+` tag
+    pub roles: HashSet<String>,
+}
+
+impl Default for RiskRules {
+    fn default() -> Self {
+        Self {
+            disposable: DomainList::bundled(),
+            roles: ROLES.iter().map(|role| role.to_string()).collect(),
+        }
+    }
+}
+
+impl RiskRules {
+    /// The bundled disposable list and [`ROLES`]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The rules behind [`email_risk`](crate::validation::email_risk)
+    pub fn global() -> &'static RiskRules {
+        &GLOBAL
+    }
+
+    pub fn disposable(mut self, list: DomainList) -> Self {
+        self.disposable = list;
+        self
+    }
+
+    pub fn role(mut self, local: &str) -> Self {
+        self.roles.insert(local.to_lowercase());
+        self
+    }
+
+    /// Parse `value` and [`check`](Self::check) it
+    pub fn assess(&self, value: &str) -> Result<EmailRisk, ValidationError> {
+        Ok(self.check(&EmailAddress::parse(value)?))
+    }
+
+    pub fn check(&self, address: &EmailAddress) -> EmailRisk {
+        let local = address.local().to_lowercase();
+        let base = local.split('+').next().unwrap_or_default();
+
+        EmailRisk {
+            disposable: self.disposable.find(address.domain()).map(str::to_string),
+            role: self.roles.get(base).cloned(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_risk() {
+        let rules = RiskRules::new();
+        let risk = |value| rules.assess(value).unwrap();
+
+        assert!(risk("jane@example.com").is_clean());
+        assert_eq!(
+            risk("jane@Mailinator.com").disposable.as_deref(),
+            Some("mailinator.com")
+        );
+        assert!(risk("jane@x.y.yopmail.com").is_disposable());
+        assert!(!risk("jane@notyopmail.com").is_disposable());
+        assert!(!risk("jane@yopmail.com.example").is_disposable());
+
+        assert_eq!(
+            risk("Postmaster@example.com").role.as_deref(),
+            Some("postmaster")
+        );
+        assert!(risk("noreply+billing@example.com").is_role());
+        assert!(!risk("administrator.jane@example.com").is_role());
+
+        let mut list = DomainList::parse("# local\nburner.example # ours\n\n");
+        assert_eq!(list.len(), 1);
+        list.extend(DomainList::bundled());
+        assert_eq!(list.find("a.burner.example."), Some("burner.example"));
+        assert!(list.find("mailinator.com").is_some());
+    }
+}
@@ -0,0 +1,295 @@
+//! "Did you mean …?" for mistyped email domains, after mailcheck.js
+//!
+//! ```
+//! use toolbox::validation::suggest_email;
+//!
+//! let suggestion = suggest_email("john@gmial.com").unwrap();
+//! assert_eq!(suggestion.address, "john@gmail.com");
+//! assert_eq!(suggestion.to_string(), "did you mean john@gmail.com?");
+//!
+//! assert!(suggest_email("john@gmail.com").is_none());
+//! ```
+
+use std::fmt;
+use std::sync::LazyLock;
+
+/// Mailbox providers most signups come from
+const DOMAINS: [&str; 29] = [
+    "aol.com",
+    "att.net",
+    "comcast.net",
+    "facebook.com",
+    "fastmail.com",
+    "gmail.com",
+    "gmx.com",
+    "gmx.de",
+    "googlemail.com",
+    "hey.com",
+    "hotmail.co.uk",
+    "hotmail.com",
+    "hotmail.fr",
+    "icloud.com",
+    "live.com",
+    "mac.com",
+    "mail.com",
+    "me.com",
+    "msn.com",
+    "outlook.com",
+    "proton.me",
+    "protonmail.com",
+    "qq.com",
+    "verizon.net",
+    "web.de",
+    "yahoo.co.uk",
+    "yahoo.com",
+    "yandex.ru",
+    "ymail.com",
+];
+
+/// The part of a domain before its top-level domain, for providers that
+/// serve many
+const SECOND_LEVEL: [&str; 8] = [
+    "gmail", "hotmail", "icloud", "live", "outlook", "proton", "yahoo", "yandex",
+];
+
+/// Common top-level domains, including the public suffixes under country
+/// codes that mail is often sent to
+const TLDS: [&str; 24] = [
+    "at", "be", "ca", "ch", "co.jp", "co.uk", "com", "com.au", "com.br", "de", "dk", "edu", "es",
+    "fr", "gov", "info", "io", "it", "me", "net", "nl", "org", "ru", "us",
+];
+
+static GLOBAL: LazyLock<Suggester> = LazyLock::new(Suggester::new);
+
+/// A likely intended address
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Suggestion {
+    /// The whole address, e.g. `john@gmail.com`
+    pub address: String,
+
+    /// Its domain, e.g. `gmail.com`
+    pub domain: String,
+}
+
+impl fmt::Display for Suggestion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "did you mean {}?", self.address)
+    }
+}
+
+/// Matches domains against popular ones by edit distance
+#[derive(Clone, Debug)]
+pub struct Suggester {
+    /// Whole domains, tried first
+    pub domains: Vec<String>,
+
+    /// Then the domain less its top-level domain, e.g. `yahoo`
+    pub second_level: Vec<String>,
+
+    /// And its top-level domain, e.g. `com`
+    pub tlds: Vec<String>,
+
+    /// Most edits, as insertions, deletions, substitutions or swaps of
+    /// neighbours, between a typo and its suggestion. The part before the
+    /// top-level domain and the top-level domain are compared separately,
+    /// each allowed one edit per three characters up to this many, so
+    /// `web.com` is not taken for `hey.com`.
+    pub threshold: usize,
+}
+
+impl Default for Suggester {
+    fn default() -> Self {
+        let owned = |list: &[&str]| list.iter().map(|s| s.to_string()).collect();
+        Self {
+            domains: owned(&DOMAINS),
+            second_level: owned(&SECOND_LEVEL),
+            tlds: owned(&TLDS),
+            threshold: 2,
+        }
+    }
+}
+
+impl Suggester {
+    /// Popular providers and common top-level domains
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The suggester behind [`suggest_email`](crate::validation::suggest_email)
+    pub fn global() -> &'static Suggester {
+        &GLOBAL
+    }
+
+    /// Add a domain people use, e.g. a company's own
+    pub fn domain(mut self, domain: &str) -> Self {
+        self.domains.push(domain.to_ascii_lowercase());
+        self
+    }
+
+    pub fn threshold(mut self, threshold: usize) -> Self {
+        self.threshold = threshold;
+        self
+    }
+
+    /// A correction for the domain of `value`, or `None` if it looks right
+    /// or is too far from anything known. `value` need not be a valid
+    /// address, since typos often make it invalid.
+    pub fn suggest(&self, value: &str) -> Option<Suggestion> {
+        let (local, domain) = value.trim().rsplit_once('@')?;
+        let domain = domain.to_ascii_lowercase();
+        if local.is_empty() || domain.is_empty() || self.domains.contains(&domain) {
+            return None;
+        }
+
+        let (name, tld) = self.split(&domain)?;
+        let domain = match self.closest_domain(name, tld) {
+            Some(found) => found.to_string(),
+            None => self.fix_parts(&domain, name, tld)?,
+        };
+        Some(Suggestion {
+            address: format!("{local}@{domain}"),
+            domain,
+        })
+    }
+
+    /// Split `domain` before its longest known top-level domain, so
+    /// `co.uk` wins over `uk`, or else before its last label
+    fn split<'a>(&self, domain: &'a str) -> Option<(&'a str, &'a str)> {
+        (1..domain.len())
+            .filter(|&i| domain.as_bytes()[i - 1] == b'.')
+            .map(|i| (&domain[..i - 1], &domain[i..]))
+            .find(|(_, tld)| self.is_tld(tld))
+            .or_else(|| domain.rsplit_once('.'))
+    }
+
+    fn is_tld(&self, tld: &str) -> bool {
+        self.tlds.iter().any(|known| known == tld)
+    }
+
+    /// The known domain nearest `name` and `tld`, both within their edits
+    fn closest_domain(&self, name: &str, tld: &str) -> Option<&str> {
+        self.domains
+            .iter()
+            .filter_map(|domain| {
+                let (known_name, known_tld) = self.split(domain)?;
+                let name_edits = self.edits(name, known_name)?;
+                // `hotmail.de` is another real domain, not a typo of `hotmail.fr`
+                if name_edits == 0 && self.is_tld(tld) {
+                    return None;
+                }
+                Some((name_edits + self.edits(tld, known_tld)?, domain))
+            })
+            .min_by_key(|&(edits, _)| edits)
+            .map(|(_, domain)| domain.as_str())
+    }
+
+    /// Correct the second-level and top-level parts separately, e.g.
+    /// `yahooo.de` to `yahoo.de`
+    fn fix_parts(&self, domain: &str, name: &str, tld: &str) -> Option<String> {
+        let sld = name.rsplit('.').next().unwrap_or(name);
+        let fixed_sld = match self.second_level.iter().any(|known| known == sld) {
+            true => sld,
+            false => self.closest(sld, &self.second_level).unwrap_or(sld),
+        };
+        let fixed_tld = match self.is_tld(tld) {
+            true => tld,
+            false => self.closest(tld, &self.tlds).unwrap_or(tld),
+        };
+
+        let fixed = format!("{}{fixed_sld}.{fixed_tld}", &name[..name.len() - sld.len()]);
+        (fixed != domain).then_some(fixed)
+    }
+
+    /// The entry nearest `word`, if within its edits; ties go to the
+    /// earlier entry
+    fn closest<'a>(&self, word: &str, list: &'a [String]) -> Option<&'a str> {
+        list.iter()
+            .filter_map(|entry| Some((self.edits(word, entry)?, entry)))
+            .min_by_key(|&(edits, _)| edits)
+            .map(|(_, entry)| entry.as_str())
+    }
+
+    /// The distance from `typo` to `known`, if few enough for their length:
+    /// one edit per three characters of the longer, up to [`Self::threshold`]
+    fn edits(&self, typo: &str, known: &str) -> Option<usize> {
+        let longer = typo.chars().count().max(known.chars().count());
+        let edits = distance(typo, known);
+        (edits <= (longer / 3).min(self.threshold)).then_some(edits)
+    }
+}
+
+/// Optimal string alignment distance: Levenshtein plus swaps of
+/// neighbouring characters, the commonest typo
+fn distance(a: &str, b: &str) -> usize {
+    let (a, b): (Vec<char>, Vec<char>) = (a.chars().collect(), b.chars().collect());
+    let width = b.len() + 1;
+    let mut d: Vec<usize> = vec![0; (a.len() + 1) * width];
+    for i in 0..=a.len() {
+        d[i * width] = i;
+    }
+    for (j, cell) in d.iter_mut().enumerate().take(width) {
+        *cell = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut best = (d[(i - 1) * width + j] + 1)
+                .min(d[i * width + j - 1] + 1)
+                .min(d[(i - 1) * width + j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                best = best.min(d[(i - 2) * width + j - 2] + 1);
+            }
+            d[i * width + j] = best;
+        }
+    }
+    d[a.len() * width + b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suggest() {
+        let suggester = Suggester::new();
+        let domain = |value| suggester.suggest(value).map(|s| s.domain);
+
+        assert_eq!(domain("john@gmial.com").as_deref(), Some("gmail.com"));
+        assert_eq!(domain("john@yaho.com").as_deref(), Some("yahoo.com"));
+        assert_eq!(domain("john@hotmail.con").as_deref(), Some("hotmail.com"));
+        assert_eq!(domain("john@HOTMAL.COM").as_deref(), Some("hotmail.com"));
+        assert_eq!(domain("john@yahooo.de").as_deref(), Some("yahoo.de"));
+        assert_eq!(domain("john@acme.cmo").as_deref(), Some("acme.com"));
+        assert_eq!(domain("john@mail.acme.co.uk"), None);
+        assert_eq!(domain("john@gmail.com"), None);
+        assert_eq!(domain("john@mail.com"), None);
+        assert_eq!(domain("john@example.org"), None);
+        assert_eq!(domain("gmial.com"), None);
+
+        // Real domains close to other real ones stay as they are
+        for real in [
+            "hotmail.de",
+            "hotmail.it",
+            "hotmail.es",
+            "ymail.com",
+            "gmx.net",
+            "gmx.at",
+            "gmx.ch",
+            "aim.com",
+            "web.com",
+        ] {
+            assert_eq!(suggester.suggest(&format!("john@{real}")), None, "{real}");
+        }
+        assert_eq!(domain("john@gmail.cm").as_deref(), Some("gmail.com"));
+        assert_eq!(domain("john@hotmial.it").as_deref(), Some("hotmail.it"));
+
+        let suggester = Suggester::new().domain("acme-corp.com");
+        let suggestion = suggester.suggest("Jane@acmecorp.com").unwrap();
+        assert_eq!(suggestion.address, "Jane@acme-corp.com");
+
+        assert_eq!(distance("gmial", "gmail"), 1);
+        assert_eq!(distance("", "abc"), 3);
+    }
+}
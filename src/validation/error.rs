@@ -0,0 +1,209 @@
+use std::fmt;
+
+/// Why a value was rejected
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Reason {
+    Empty,
+    TooShort {
+        min: usize,
+    },
+    TooLong {
+        max: usize,
+    },
+
+    /// `pos` counts characters from the start of the trimmed input
+    BadCharacter {
+        pos: usize,
+        ch: char,
+    },
+
+    /// A phone number's `+` prefix is not a known country calling code
+    UnknownCountryCode(String),
+
+    /// A national phone number given without a default region
+    MissingCountryCode,
+
+    /// A region code the phone metadata does not know
+    UnknownRegion(String),
+
+    /// A phone number outside the ranges its region hands out
+    InvalidForRegion(String),
+
+    /// A valid phone number of another type, e.g. `fixed-line`, where a
+    /// mobile is needed
+    NotMobile(String),
+
+    /// An email address without `@`
+    MissingAt,
+    MissingLocalPart,
+    MissingDomain,
+
+    /// An email domain without a dot, e.g. `user@localhost`
+    MissingTld,
+
+    /// An email local part over `max` octets
+    LocalPartTooLong {
+        max: usize,
+    },
+
+    /// An email domain over `max` octets
+    DomainTooLong {
+        max: usize,
+    },
+
+    /// A domain label longer than 63 characters
+    LabelTooLong,
+
+    /// An email domain in brackets that is not an IPv4 or `IPv6:` address
+    BadAddressLiteral,
+
+    /// An internationalized domain that IDNA cannot map to ASCII
+    BadInternationalDomain,
+
+    /// Well-formed pieces in an order the format does not allow
+    BadFormat,
+
+    /// A value below `min` or above `max`, written out for the message
+    OutOfRange {
+        min: Option<String>,
+        max: Option<String>,
+    },
+
+    /// A value outside a fixed set of choices
+    NotAllowed,
+
+    /// A rule's own message, e.g. `is already taken`
+    Custom(String),
+}
+
+impl Reason {
+    /// A stable machine-readable name, e.g. `bad_character`
+    pub fn code(&self) -> &'static str {
+        match self {
+            Reason::Empty => "empty",
+            Reason::TooShort { .. } => "too_short",
+            Reason::TooLong { .. } => "too_long",
+            Reason::BadCharacter { .. } => "bad_character",
+            Reason::UnknownCountryCode(_) => "unknown_country_code",
+            Reason::MissingCountryCode => "missing_country_code",
+            Reason::UnknownRegion(_) => "unknown_region",
+            Reason::InvalidForRegion(_) => "invalid_for_region",
+            Reason::NotMobile(_) => "not_mobile",
+            Reason::MissingAt => "missing_at",
+            Reason::MissingLocalPart => "missing_local_part",
+            Reason::MissingDomain => "missing_domain",
+            Reason::MissingTld => "missing_tld",
+            Reason::LocalPartTooLong { .. } => "local_part_too_long",
+            Reason::DomainTooLong { .. } => "domain_too_long",
+            Reason::LabelTooLong => "label_too_long",
+            Reason::BadAddressLiteral => "bad_address_literal",
+            Reason::BadInternationalDomain => "bad_international_domain",
+            Reason::BadFormat => "bad_format",
+            Reason::OutOfRange { .. } => "out_of_range",
+            Reason::NotAllowed => "not_allowed",
+            Reason::Custom(_) => "custom",
+        }
+    }
+}
+
+impl fmt::Display for Reason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Reason::Empty => write!(f, "is empty"),
+            Reason::TooShort { min } => write!(f, "is too short (at least {min})"),
+            Reason::TooLong { max } => write!(f, "is too long (at most {max})"),
+            Reason::BadCharacter { pos, ch } => {
+                write!(f, "has an unexpected {ch:?} at position {}", pos + 1)
+            }
+            Reason::UnknownCountryCode(code) => write!(f, "has an unknown country code +{code}"),
+            Reason::MissingCountryCode => write!(f, "needs a country code, e.g. +44"),
+            Reason::UnknownRegion(region) => write!(f, "is for an unknown region {region}"),
+            Reason::InvalidForRegion(region) => write!(f, "is not a valid number for {region}"),
+            Reason::NotMobile(kind) => write!(f, "is a {kind} number, not a mobile"),
+            Reason::MissingAt => write!(f, "is missing an @"),
+            Reason::MissingLocalPart => write!(f, "is missing the part before @"),
+            Reason::MissingDomain => write!(f, "is missing the domain after @"),
+            Reason::MissingTld => write!(f, "has a domain without a top-level domain"),
+            Reason::LocalPartTooLong { max } => {
+                write!(f, "has more than {max} characters before @")
+            }
+            Reason::DomainTooLong { max } => {
+                write!(f, "has a domain longer than {max} characters")
+            }
+            Reason::LabelTooLong => write!(f, "has a domain label longer than 63 characters"),
+            Reason::BadAddressLiteral => {
+                write!(f, "has a bracketed domain that is not an IP address")
+            }
+            Reason::BadInternationalDomain => {
+                write!(f, "has an international domain that cannot be encoded")
+            }
+            Reason::BadFormat => write!(f, "is not in a recognized format"),
+            Reason::OutOfRange { min, max } => match (min, max) {
+                (Some(min), Some(max)) => write!(f, "must be between {min} and {max}"),
+                (Some(min), None) => write!(f, "must be at least {min}"),
+                (None, Some(max)) => write!(f, "must be at most {max}"),
+                (None, None) => write!(f, "is out of range"),
+            },
+            Reason::NotAllowed => write!(f, "is not one of the allowed values"),
+            Reason::Custom(message) => f.write_str(message),
+        }
+    }
+}
+
+/// A rejected form value: which field, and why
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    pub field: String,
+    pub reason: Reason,
+}
+
+impl ValidationError {
+    pub fn new(field: &str, reason: Reason) -> Self {
+        Self {
+            field: field.to_string(),
+            reason,
+        }
+    }
+
+    /// Report the error against another field name, e.g. `login`
+    pub fn with_field(mut self, field: &str) -> Self {
+        self.field = field.to_string();
+        self
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "The {} {}", self.field.replace('_', " "), self.reason)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let e = ValidationError::new("phone", Reason::BadCharacter { pos: 3, ch: 'x' });
+        assert_eq!(
+            e.to_string(),
+            "The phone has an unexpected 'x' at position 4"
+        );
+        assert_eq!(e.reason.code(), "bad_character");
+
+        let e = e.with_field("login_id");
+        assert_eq!(
+            e.to_string(),
+            "The login id has an unexpected 'x' at position 4"
+        );
+
+        #[cfg(feature = "result")]
+        {
+            let err = crate::result::Error::from(e);
+            assert!(err.has_meta());
+            assert!(format!("{err:?}").contains("User"));
+        }
+    }
+}
@@ -0,0 +1,125 @@
+mod auth;
+mod email;
+mod error;
+mod phone;
+pub mod rules;
+mod validator;
+
+pub use auth::AuthIdentifier;
+pub use email::{
+    CanonicalEmail, CanonicalRules, DomainList, EmailAddress, EmailMode, EmailRisk, ProviderRule,
+    ROLES, RiskRules, Suggester, Suggestion,
+};
+#[cfg(feature = "email_dns")]
+pub use email::{Deliverability, DeliverabilityCheck};
+pub use error::{Reason, ValidationError};
+#[cfg(feature = "phone_metadata")]
+pub use phone::{AsYouType, PhoneFormat, PhoneType};
+pub use phone::{PhoneNumber, Region};
+pub use rules::{Checks, Rule, Validate};
+pub use validator::{Kind, Validator};
+
+/// Check if a string is a valid email address or phone number
+pub fn is_means_of_auth(value: &str) -> bool {
+    Validator::global().is_means_of_auth(value)
+}
+
+/// Check if a string is a valid phone number
+pub fn is_phone(value: &str) -> bool {
+    Validator::global().is_phone(value)
+}
+
+/// Check if a string is a valid email address
+pub fn is_email(value: &str) -> bool {
+    Validator::global().is_email(value)
+}
+
+/// Like [`is_means_of_auth`], but says what is wrong
+pub fn validate_means_of_auth(value: &str) -> Result<String, ValidationError> {
+    Validator::global().validate_means_of_auth(value)
+}
+
+/// Like [`is_phone`], but returns the normalized number or what is wrong
+pub fn validate_phone(value: &str) -> Result<String, ValidationError> {
+    Validator::global().validate_phone(value)
+}
+
+/// Like [`is_email`], but returns the normalized address or what is wrong
+pub fn validate_email(value: &str) -> Result<String, ValidationError> {
+    Validator::global().validate_email(value)
+}
+
+/// Parse an email address into its local part and domain, per RFC 5321
+pub fn parse_email(value: &str) -> Result<EmailAddress, ValidationError> {
+    EmailAddress::parse(value)
+}
+
+/// Parse an email address and pair it with a key that is the same for
+/// every spelling of one mailbox, e.g. `John.Doe+1@gmail.com` and
+/// `johndoe@googlemail.com`. Send to the address; check uniqueness on the key.
+pub fn canonicalize_email(value: &str) -> Result<CanonicalEmail, ValidationError> {
+    CanonicalRules::global().canonicalize(value)
+}
+
+/// Parse an email address and flag it if it is disposable or a role
+/// account, against the bundled lists
+pub fn email_risk(value: &str) -> Result<EmailRisk, ValidationError> {
+    RiskRules::global().assess(value)
+}
+
+/// A correction for a mistyped domain, e.g. `john@gmail.com` for
+/// `john@gmial.com`, to show next to an email field
+pub fn suggest_email(value: &str) -> Option<Suggestion> {
+    Suggester::global().suggest(value)
+}
+
+/// Parse a login string into the email address or phone number it is;
+/// phone numbers must be international. See [`AuthIdentifier::parse`] to
+/// give a default region.
+pub fn parse_auth_identifier(value: &str) -> Result<AuthIdentifier, ValidationError> {
+    AuthIdentifier::parse(value, None)
+}
+
+/// Parse a phone number into its E.164 parts; national numbers need
+/// `default_region`, e.g. `Some("GB")`
+pub fn parse_phone(
+    value: &str,
+    default_region: Option<&str>,
+) -> Result<PhoneNumber, ValidationError> {
+    PhoneNumber::parse(value, default_region)
+}
+
+/// Parse a phone number and check it is valid for `region`, e.g. `GB`,
+/// which is also the default region for national numbers
+#[cfg(feature = "phone_metadata")]
+pub fn validate_phone_for_region(
+    value: &str,
+    region: &str,
+) -> Result<PhoneNumber, ValidationError> {
+    let number = PhoneNumber::parse(value, Some(region))?;
+    match number.is_valid_for_region(region) {
+        true => Ok(number),
+        false => Err(ValidationError::new(
+            "phone",
+            Reason::InvalidForRegion(region.trim().to_uppercase()),
+        )),
+    }
+}
+
+/// Parse a phone number and check it can take an SMS, e.g. before sending
+/// a one-time code; national numbers need `default_region`
+#[cfg(feature = "phone_metadata")]
+pub fn validate_mobile(
+    value: &str,
+    default_region: Option<&str>,
+) -> Result<PhoneNumber, ValidationError> {
+    let number = PhoneNumber::parse(value, default_region)?;
+    let reason = match number.number_type() {
+        kind if kind.is_mobile() => return Ok(number),
+        PhoneType::Unknown => {
+            Reason::InvalidForRegion(number.region().unwrap_or_default().to_string())
+        }
+        kind => Reason::NotMobile(kind.to_string()),
+    };
+    Err(ValidationError::new("phone", reason))
+}
@@ -0,0 +1,495 @@
+//! Writing phone numbers the way their region groups them
+
+use super::{INTERNATIONAL_PREFIXES, NumberFormat, PhoneNumber, Region};
+use super::{metadata, split_country_code};
+use regex::Regex;
+use std::sync::{LazyLock, OnceLock};
+
+/// Stands for a digit still to be typed in an as-you-type template
+const PLACEHOLDER: char = '\u{2008}';
+
+/// Digits typed before a grouping is picked; fewer rarely tell formats apart
+const MIN_LEADING_DIGITS: usize = 3;
+
+/// Longer than any national number, for building templates
+const NINES: &str = "999999999999999";
+
+/// Each region's formats, compiled on first use
+static FORMATS: LazyLock<Vec<OnceLock<Vec<Compiled>>>> =
+    LazyLock::new(|| metadata::METADATA.iter().map(|_| OnceLock::new()).collect());
+
+/// How [`PhoneNumber::format`] writes a number
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PhoneFormat {
+    /// `+442079460958`
+    E164,
+
+    /// `+44 20 7946 0958`
+    International,
+
+    /// `020 7946 0958`, as dialed from within the region
+    National,
+
+    /// `tel:+44-20-7946-0958`, a URI per RFC 3966
+    Rfc3966,
+}
+
+/// Which replacement of a [`NumberFormat`] to group digits with
+#[derive(Clone, Copy)]
+enum Style {
+    Plain,
+    National,
+
+    /// The national format, for formats that write the trunk prefix
+    Trunk,
+    International,
+}
+
+/// A [`NumberFormat`] with its patterns compiled and its as-you-type
+/// templates worked out
+struct Compiled {
+    pattern: Regex,
+    leading_digits: Vec<Regex>,
+    format: &'static str,
+    national_format: &'static str,
+    intl_format: Option<&'static str>,
+    templates: [Option<String>; 3],
+}
+
+impl Compiled {
+    fn new(format: &NumberFormat) -> Self {
+        // The generator checks every pattern compiles
+        let anchored = |pattern: &str, end: &str| {
+            Regex::new(&format!("^(?:{pattern}){end}")).expect("generated phone pattern")
+        };
+
+        Self {
+            pattern: anchored(format.pattern, "$"),
+            leading_digits: format
+                .leading_digits
+                .iter()
+                .map(|p| anchored(p, ""))
+                .collect(),
+            format: format.format,
+            national_format: format.national_format,
+            intl_format: format.intl_format,
+            templates: [
+                Some(format.format),
+                Some(format.national_format),
+                format.intl_format,
+            ]
+            .map(|replacement| template(format.pattern, replacement?)),
+        }
+    }
+
+    fn replacement(&self, style: Style) -> Option<&'static str> {
+        match style {
+            Style::Plain => Some(self.format),
+            Style::National => Some(self.national_format),
+            Style::Trunk => (self.national_format != self.format).then_some(self.national_format),
+            Style::International => self.intl_format,
+        }
+    }
+
+    fn template(&self, style: Style) -> Option<&str> {
+        self.replacement(style)?;
+        let index = match style {
+            Style::Plain => 0,
+            Style::National | Style::Trunk => 1,
+            Style::International => 2,
+        };
+        self.templates[index].as_deref()
+    }
+
+    /// Whether a complete national number is one this format is for
+    fn fits(&self, national: &str) -> bool {
+        self.leading_digits
+            .last()
+            .is_none_or(|leading| leading.is_match(national))
+            && self.pattern.is_match(national)
+    }
+
+    /// Whether the start of a national number could be one this format is
+    /// for. The leading digit patterns sharpen as more digits come in.
+    fn could_fit(&self, partial: &str) -> bool {
+        let Some(last) = self.leading_digits.len().checked_sub(1) else {
+            return true;
+        };
+        let index = partial.len().saturating_sub(MIN_LEADING_DIGITS).min(last);
+        self.leading_digits[index].is_match(partial)
+    }
+}
+
+fn formats(region: &Region) -> &'static [Compiled] {
+    let index = region.index();
+    FORMATS[index].get_or_init(|| {
+        metadata::METADATA[index]
+            .formats
+            .iter()
+            .map(Compiled::new)
+            .collect()
+    })
+}
+
+/// Compile `region`'s formats ahead of their first use
+pub(super) fn preload(region: &Region) {
+    formats(region);
+}
+
+/// Group a national number with the first format that fits it
+fn group(code: u16, national: &str, style: Style) -> Option<String> {
+    let region = Region::main_for_code(code)?;
+    formats(region).iter().find_map(|format| {
+        let replacement = format.replacement(style)?;
+        format.fits(national).then(|| {
+            let grouped = format.pattern.replace(national, replacement);
+            grouped.into_owned()
+        })
+    })
+}
+
+/// Group the start of a national number: in full if it already fits a
+/// format, else along the first template with room for it
+fn group_partial(code: u16, national: &str, style: Style) -> Option<String> {
+    if national.len() < MIN_LEADING_DIGITS {
+        return None;
+    }
+    if let Some(grouped) = group(code, national, style) {
+        return Some(grouped);
+    }
+
+    let region = Region::main_for_code(code)?;
+    formats(region)
+        .iter()
+        .filter(|format| format.could_fit(national))
+        .find_map(|format| fill(format.template(style)?, national))
+}
+
+/// The shape `replacement` gives the longest number `pattern` matches, with
+/// [`PLACEHOLDER`] for each digit: `(\d{3})(\d{4})` and `${1}-${2}` give
+/// `   -    `. Literal digits, such as a trunk prefix, are kept.
+fn template(pattern: &str, replacement: &str) -> Option<String> {
+    let re = Regex::new(&generalize(pattern)).ok()?;
+    let nines = re.find(NINES)?.as_str();
+    let eights = "8".repeat(nines.len());
+
+    // Characters that differ between the two came from the number
+    let a = re.replace(nines, replacement);
+    let b = re.replace(&eights, replacement);
+    let template = a
+        .chars()
+        .zip(b.chars())
+        .map(|(a, b)| if a == b { a } else { PLACEHOLDER })
+        .collect();
+    Some(template)
+}
+
+/// `pattern` with character classes and literal digits widened to `\d`, so
+/// it matches a run of nines as long as the numbers it is for
+fn generalize(pattern: &str) -> String {
+    let mut out = String::new();
+    let mut chars = pattern.chars();
+    let mut quantifier = false;
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            '[' => {
+                chars.by_ref().find(|&c| c == ']');
+                out.push_str(r"\d");
+            }
+            '\\' => {
+                out.push(ch);
+                out.extend(chars.next());
+            }
+            '{' | '}' => {
+                quantifier = ch == '{';
+                out.push(ch);
+            }
+            '0'..='9' if !quantifier => out.push_str(r"\d"),
+            _ => out.push(ch),
+        }
+    }
+    out
+}
+
+/// Put digits into a template's placeholders, up to the last digit.
+/// `None` when there are more digits than placeholders.
+fn fill(template: &str, digits: &str) -> Option<String> {
+    let mut out = String::new();
+    let mut pending = String::new();
+    let mut digits = digits.chars();
+
+    for ch in template.chars() {
+        if ch != PLACEHOLDER {
+            pending.push(ch);
+            continue;
+        }
+        let Some(digit) = digits.next() else {
+            break;
+        };
+        out.push_str(&pending);
+        out.push(digit);
+        pending.clear();
+    }
+
+    match digits.next() {
+        Some(_) => None,
+        None => Some(out),
+    }
+}
+
+impl PhoneNumber {
+    /// Write the number in `style`, grouped the way its country does. An
+    /// extension follows as ` ext. 12`, or `;ext=12` in a `tel:` URI.
+    ///
+    /// ```
+    /// use toolbox::validation::{PhoneFormat, PhoneNumber};
+    ///
+    /// let number: PhoneNumber = "+442079460958".parse().unwrap();
+    /// assert_eq!(number.format(PhoneFormat::International), "+44 20 7946 0958");
+    /// assert_eq!(number.format(PhoneFormat::National), "020 7946 0958");
+    /// assert_eq!(number.format(PhoneFormat::Rfc3966), "tel:+44-20-7946-0958");
+    /// ```
+    pub fn format(&self, style: PhoneFormat) -> String {
+        let (code, national) = (self.country_code, self.national.as_str());
+        let grouped = |style| group(code, national, style).unwrap_or_else(|| national.to_string());
+
+        let mut out = match style {
+            PhoneFormat::E164 => return self.e164(),
+            PhoneFormat::International => {
+                format!("+{code} {}", grouped(Style::International))
+            }
+            PhoneFormat::National => grouped(Style::National),
+            PhoneFormat::Rfc3966 => {
+                // Every separator becomes a hyphen
+                let grouped = grouped(Style::International);
+                let blocks = grouped
+                    .split(|c: char| !c.is_ascii_digit())
+                    .filter(|block| !block.is_empty())
+                    .collect::<Vec<_>>();
+                format!("tel:+{code}-{}", blocks.join("-"))
+            }
+        };
+
+        if let Some(extension) = &self.extension {
+            match style {
+                PhoneFormat::Rfc3966 => out.push_str(";ext="),
+                _ => out.push_str(" ext. "),
+            }
+            out.push_str(extension);
+        }
+        out
+    }
+}
+
+/// Formats a phone number while it is typed
+///
+/// Feed it keystrokes with [`push`](Self::push) and [`pop`](Self::pop), or
+/// the whole field with [`set`](Self::set) from an `oninput` handler; each
+/// returns the text to show. Anything but digits and a leading `+` is
+/// dropped, so separators the user types do not pile up.
+///
+/// ```
+/// use toolbox::validation::AsYouType;
+///
+/// let mut typer = AsYouType::new(Some("GB"));
+/// for ch in "0207946".chars() {
+///     typer.push(ch);
+/// }
+/// assert_eq!(typer.as_str(), "020 7946");
+/// assert_eq!(typer.set("+1 650 253 0000"), "+1 650-253-0000");
+/// ```
+#[derive(Clone, Debug)]
+pub struct AsYouType {
+    region: Option<&'static Region>,
+    typed: String,
+    formatted: String,
+}
+
+impl AsYouType {
+    /// A formatter for numbers dialed from `default_region`, such as `GB`.
+    /// Without a known region only international numbers are grouped.
+    pub fn new(default_region: Option<&str>) -> Self {
+        Self {
+            region: default_region.and_then(Region::get),
+            typed: String::new(),
+            formatted: String::new(),
+        }
+    }
+
+    /// Add a typed character
+    pub fn push(&mut self, ch: char) -> &str {
+        if ch.is_ascii_digit() || (ch == '+' && self.typed.is_empty()) {
+            self.typed.push(ch);
+            self.formatted = self.compose();
+        }
+        &self.formatted
+    }
+
+    /// Remove the last digit, as on backspace
+    pub fn pop(&mut self) -> &str {
+        self.typed.pop();
+        self.formatted = self.compose();
+        &self.formatted
+    }
+
+    /// Replace everything typed so far with `value`
+    pub fn set(&mut self, value: &str) -> &str {
+        self.typed.clear();
+        for ch in value.chars() {
+            if ch.is_ascii_digit() || (ch == '+' && self.typed.is_empty()) {
+                self.typed.push(ch);
+            }
+        }
+        self.formatted = self.compose();
+        &self.formatted
+    }
+
+    /// Forget everything typed, as when the field is emptied
+    pub fn clear(&mut self) {
+        self.typed.clear();
+        self.formatted.clear();
+    }
+
+    /// The text to show
+    pub fn as_str(&self) -> &str {
+        &self.formatted
+    }
+
+    /// The number typed so far, once it parses
+    pub fn number(&self) -> Option<PhoneNumber> {
+        PhoneNumber::parse(&self.typed, self.region.map(|region| region.id)).ok()
+    }
+
+    fn compose(&self) -> String {
+        if let Some(digits) = self.typed.strip_prefix('+') {
+            return match split_country_code(digits) {
+                Some((code, national)) => international(&format!("+{code}"), code, national),
+                None => self.typed.clone(),
+            };
+        }
+        let digits = self.typed.as_str();
+        let Some(region) = self.region else {
+            return digits.to_string();
+        };
+
+        // `011 44 …` from the US, `00 44 …` from most elsewhere
+        let idd = INTERNATIONAL_PREFIXES[region.index()]
+            .as_ref()
+            .and_then(|idd| idd.find(digits));
+        if let Some(idd) = idd {
+            let (idd, rest) = digits.split_at(idd.end());
+            return match split_country_code(rest) {
+                Some((code, national)) => international(&format!("{idd} {code}"), code, national),
+                None if rest.is_empty() => idd.to_string(),
+                None => format!("{idd} {rest}"),
+            };
+        }
+
+        let trunk = region
+            .national_prefix
+            .filter(|trunk| digits.len() > trunk.len() && digits.starts_with(trunk));
+        let Some(trunk) = trunk else {
+            return group_partial(region.code, digits, Style::Plain)
+                .unwrap_or_else(|| digits.to_string());
+        };
+
+        // The trunk prefix goes where the region's national format puts it,
+        // or ahead of the number when the format leaves it out
+        let national = &digits[trunk.len()..];
+        group_partial(region.code, national, Style::Trunk)
+            .or_else(|| {
+                group_partial(region.code, national, Style::Plain)
+                    .map(|grouped| format!("{trunk} {grouped}"))
+            })
+            .unwrap_or_else(|| digits.to_string())
+    }
+}
+
+fn international(prefix: &str, code: u16, national: &str) -> String {
+    match national {
+        "" => prefix.to_string(),
+        _ => {
+            let grouped = group_partial(code, national, Style::International);
+            format!("{prefix} {}", grouped.as_deref().unwrap_or(national))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed(region: Option<&str>, keys: &str) -> Vec<String> {
+        let mut typer = AsYouType::new(region);
+        keys.chars().map(|ch| typer.push(ch).to_string()).collect()
+    }
+
+    #[test]
+    fn test_format() {
+        let cases = [
+            ("+16502530000", "+1 650-253-0000", "(650) 253-0000"),
+            ("+18765551234", "+1 876-555-1234", "(876) 555-1234"),
+            ("+79123456789", "+7 912 345-67-89", "8 (912) 345-67-89"),
+            ("+390612345678", "+39 06 1234 5678", "06 1234 5678"),
+            ("+33123456789", "+33 1 23 45 67 89", "01 23 45 67 89"),
+        ];
+        for (e164, international, national) in cases {
+            let number: PhoneNumber = e164.parse().unwrap();
+            assert_eq!(number.format(PhoneFormat::E164), e164);
+            assert_eq!(number.format(PhoneFormat::International), international);
+            assert_eq!(number.format(PhoneFormat::National), national);
+        }
+
+        let number: PhoneNumber = "+44 20 7946 0958 ext. 12".parse().unwrap();
+        assert_eq!(
+            number.format(PhoneFormat::National),
+            "020 7946 0958 ext. 12"
+        );
+        assert_eq!(
+            number.format(PhoneFormat::Rfc3966),
+            "tel:+44-20-7946-0958;ext=12"
+        );
+    }
+
+    #[test]
+    fn test_as_you_type() {
+        assert_eq!(
+            typed(Some("GB"), "02079460958"),
+            [
+                "0",
+                "02",
+                "020",
+                "020 7",
+                "020 79",
+                "020 794",
+                "020 7946",
+                "020 7946 0",
+                "020 7946 09",
+                "020 7946 095",
+                "020 7946 0958"
+            ]
+        );
+        assert_eq!(
+            typed(Some("US"), "16502530000").last().unwrap(),
+            "1 (650) 253-0000"
+        );
+        assert_eq!(
+            typed(Some("US"), "011442079460958").last().unwrap(),
+            "011 44 20 7946 0958"
+        );
+        assert_eq!(
+            typed(Some("RU"), "89123456789").last().unwrap(),
+            "8 (912) 345-67-89"
+        );
+        assert_eq!(typed(None, "0207").last().unwrap(), "0207");
+
+        let mut typer = AsYouType::new(Some("GB"));
+        assert_eq!(typer.set("+44 (20) 79"), "+44 20 79");
+        assert_eq!(typer.pop(), "+44 20 7");
+        typer.push('x');
+        assert_eq!(typer.as_str(), "+44 20 7");
+        assert_eq!(typer.set("020 7946 0958"), "020 7946 0958");
+        assert_eq!(typer.number().unwrap().e164(), "+442079460958");
+    }
+}
@@ -0,0 +1,2923 @@
+// @generated by examples/phone_metadata.rs from libphonenumber 9.0.21
+// PhoneNumberMetadata.xml (Apache License 2.0). Do not edit by hand.
+
+use super::{Metadata, NumberDesc, NumberFormat, PhoneType};
+
+pub(crate) static METADATA: [Metadata; 254] = [
+    // AC
+    Metadata { general: "(?:[01589]\\d|[46])\\d{4}", lengths: &[5, 6], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "6[2-467]\\d{3}", lengths: &[5] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "4\\d{4}", lengths: &[5] }),
+        (PhoneType::Uan, NumberDesc { pattern: "(?:0[1-9]|[1589]\\d)\\d{4}", lengths: &[6] }),
+    ] },
+    // AD
+    Metadata { general: "(?:1|6\\d)\\d{7}|[135-9]\\d{5}", lengths: &[6, 8, 9], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3})", leading_digits: &["[135-9]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["1"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["6"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "[78]\\d{5}", lengths: &[6] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "690\\d{6}|[356]\\d{5}", lengths: &[6, 9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "180[02]\\d{4}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "[19]\\d{5}", lengths: &[6] }),
+    ] },
+    // AE
+    Metadata { general: "(?:[4-7]\\d|9[0-689])\\d{7}|800\\d{2,9}|[2-4679]\\d{7}", lengths: &[5, 6, 7, 8, 9, 10, 11, 12], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{2,9})", leading_digits: &["60|8"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{4})", leading_digits: &["[236]|[479][2-8]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d)(\\d{5})", leading_digits: &["[479]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["5"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "[2-4679][2-8]\\d{6}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "5[024-68]\\d{7}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "400\\d{6}|800\\d{2,9}", lengths: &[5, 6, 7, 8, 9, 10, 11, 12] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[02]\\d{5}", lengths: &[9] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "700[05]\\d{5}", lengths: &[9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "600[25]\\d{5}", lengths: &[9] }),
+    ] },
+    // AF
+    Metadata { general: "[2-7]\\d{8}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[1-9]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["[2-7]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:[25][0-8]|[34][0-4]|6[0-5])[2-9]\\d{6}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "7\\d{8}", lengths: &[9] }),
+    ] },
+    // AG
+    Metadata { general: "(?:268|[58]\\d\\d|900)\\d{7}", lengths: &[10], leading_digits: Some("268"), national_prefix_for_parsing: Some("([457]\\d{6})$|1"), national_prefix_transform_rule: Some("268$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "268(?:4(?:6[0-38]|84)|56[0-2])\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "268(?:464|7(?:1[3-9]|[28]\\d|3[0246]|64|7[0-689]))\\d{4}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "26848[01]\\d{4}", lengths: &[10] }),
+        (PhoneType::Pager, NumberDesc { pattern: "26840[69]\\d{4}", lengths: &[10] }),
+    ] },
+    // AI
+    Metadata { general: "(?:264|[58]\\d\\d|900)\\d{7}", lengths: &[10], leading_digits: Some("264"), national_prefix_for_parsing: Some("([2457]\\d{6})$|1"), national_prefix_transform_rule: Some("264$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "264(?:292|4(?:6[12]|9[78]))\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "264(?:235|4(?:69|76)|5(?:3[6-9]|8[1-4])|7(?:29|72))\\d{4}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::Pager, NumberDesc { pattern: "264724\\d{4}", lengths: &[10] }),
+    ] },
+    // AL
+    Metadata { general: "(?:700\\d\\d|900)\\d{3}|8\\d{5,7}|(?:[2-5]|6\\d)\\d{7}", lengths: &[6, 7, 8, 9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3,4})", leading_digits: &["80|9"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{4})", leading_digits: &["4[2-6]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["[2358][2-5]|4"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{5})", leading_digits: &["[23578]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["6"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "4505[0-2]\\d{3}|(?:[2358][16-9]\\d[2-9]|4410)\\d{4}|(?:[2358][2-5][2-9]|4(?:[2-57-9][2-9]|6\\d))\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "6(?:[78][2-9]|9\\d)\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{4}", lengths: &[7] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[1-9]\\d\\d", lengths: &[6] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "808[1-9]\\d\\d", lengths: &[6] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "700[2-9]\\d{4}", lengths: &[8] }),
+    ] },
+    // AM
+    Metadata { general: "(?:[1-489]\\d|55|60|77)\\d{6}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{3})", leading_digits: &["[89]0"], format: "${1} ${2} ${3}", national_format: "0 ${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{5})", leading_digits: &["2|3[12]"], format: "${1} ${2}", national_format: "(0${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{6})", leading_digits: &["1|47"], format: "${1} ${2}", national_format: "(0${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{6})", leading_digits: &["[3-9]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:(?:1[0-25]|47)\\d|2(?:2[2-46]|3[1-8]|4[2-69]|5[2-7]|6[1-9]|8[1-7])|3[12]2)\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:33|4[1349]|55|77|88|9[13-9])\\d{6}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{5}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90[016]\\d{5}", lengths: &[8] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "80[1-4]\\d{5}", lengths: &[8] }),
+        (PhoneType::Voip, NumberDesc { pattern: "60(?:2[78]|3[5-9]|4[02-9]|5[0-46-9]|[6-8]\\d|9[0-2])\\d{4}", lengths: &[8] }),
+    ] },
+    // AO
+    Metadata { general: "[29]\\d{8}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[29]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2\\d(?:[0134][25-9]|[25-9]\\d)\\d{5}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "9[1-79]\\d{7}", lengths: &[9] }),
+    ] },
+    // AR
+    Metadata { general: "(?:11|[89]\\d\\d)\\d{8}|[2368]\\d{9}", lengths: &[10, 11], leading_digits: None, national_prefix_for_parsing: Some("0?(?:(11|2(?:2(?:02?|[13]|2[13-79]|4[1-6]|5[2457]|6[124-8]|7[1-4]|8[13-6]|9[1267])|3(?:02?|1[467]|2[03-6]|3[13-8]|[49][2-6]|5[2-8]|[67])|4(?:7[3-578]|9)|6(?:[0136]|2[24-6]|4[6-8]?|5[15-8])|80|9(?:0[1-3]|[19]|2\\d|3[1-6]|4[02568]?|5[2-4]|6[2-46]|72?|8[23]?))|3(?:3(?:2[79]|6|8[2578])|4(?:0[0-24-9]|[12]|3[5-8]?|4[24-7]|5[4-68]?|6[02-9]|7[126]|8[2379]?|9[1-36-8])|5(?:1|2[1245]|3[237]?|4[1-46-9]|6[2-4]|7[1-6]|8[2-5]?)|6[24]|7(?:[069]|1[1568]|2[15]|3[145]|4[13]|5[14-8]|7[2-57]|8[126])|8(?:[01]|2[15-7]|3[2578]?|4[13-6]|5[4-8]?|6[1-357-9]|7[36-8]?|8[5-8]?|9[124])))15)?"), national_prefix_transform_rule: Some("9$1"), formats: &[
+        NumberFormat { pattern: "(\\d{3})", leading_digits: &["0|1(?:0[0-35-7]|1[02-5]|2[015]|3[47]|4[478])|911"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{2})(\\d{4})", leading_digits: &["[1-9]"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[2-9]"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[1-8]"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d{4})(\\d{2})(\\d{4})", leading_digits: &["2(?:2[024-9]|3[0-59]|47|6[245]|9[02-8])|3(?:3[28]|4[03-9]|5[2-46-8]|7[1-578]|8[2-9])", "2(?:[23]02|6(?:[25]|4[6-8])|9(?:[02356]|4[02568]|72|8[23]))|3(?:3[28]|4(?:[04679]|3[5-8]|5[4-68]|8[2379])|5(?:[2467]|3[237]|8[2-5])|7[1-578]|8(?:[2469]|3[2578]|5[4-8]|7[36-8]|8[5-8]))|2(?:2[24-9]|3[1-59]|47)", "2(?:[23]02|6(?:[25]|4(?:64|[78]))|9(?:[02356]|4(?:[0268]|5[2-6])|72|8[23]))|3(?:3[28]|4(?:[04679]|3[78]|5(?:4[46]|8)|8[2379])|5(?:[2467]|3[237]|8[23])|7[1-578]|8(?:[2469]|3[278]|5[56][46]|86[3-6]))|2(?:2[24-9]|3[1-59]|47)|38(?:[58][78]|7[378])|3(?:4[35][56]|58[45]|8(?:[38]5|54|76))[4-6]", "2(?:[23]02|6(?:[25]|4(?:64|[78]))|9(?:[02356]|4(?:[0268]|5[2-6])|72|8[23]))|3(?:3[28]|4(?:[04679]|3(?:5(?:4[0-25689]|[56])|[78])|58|8[2379])|5(?:[2467]|3[237]|8(?:[23]|4(?:[45]|60)|5(?:4[0-39]|5|64)))|7[1-578]|8(?:[2469]|3[278]|54(?:4|5[13-7]|6[89])|86[3-6]))|2(?:2[24-9]|3[1-59]|47)|38(?:[58][78]|7[378])|3(?:454|85[56])[46]|3(?:4(?:36|5[56])|8(?:[38]5|76))[4-6]"], format: "${1} ${2}-${3}", national_format: "0${1} ${2}-${3}", intl_format: Some("${1} ${2}-${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{4})(\\d{4})", leading_digits: &["1"], format: "${1} ${2}-${3}", national_format: "0${1} ${2}-${3}", intl_format: Some("${1} ${2}-${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["[68]"], format: "${1}-${2}-${3}", national_format: "0${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["[23]"], format: "${1} ${2}-${3}", national_format: "0${1} ${2}-${3}", intl_format: Some("${1} ${2}-${3}") },
+        NumberFormat { pattern: "(\\d)(\\d{4})(\\d{2})(\\d{4})", leading_digits: &["9(?:2[2-469]|3[3-578])", "9(?:2(?:2[024-9]|3[0-59]|47|6[245]|9[02-8])|3(?:3[28]|4[03-9]|5[2-46-8]|7[1-578]|8[2-9]))", "9(?:2(?:[23]02|6(?:[25]|4[6-8])|9(?:[02356]|4[02568]|72|8[23]))|3(?:3[28]|4(?:[04679]|3[5-8]|5[4-68]|8[2379])|5(?:[2467]|3[237]|8[2-5])|7[1-578]|8(?:[2469]|3[2578]|5[4-8]|7[36-8]|8[5-8])))|92(?:2[24-9]|3[1-59]|47)", "9(?:2(?:[23]02|6(?:[25]|4(?:64|[78]))|9(?:[02356]|4(?:[0268]|5[2-6])|72|8[23]))|3(?:3[28]|4(?:[04679]|3[78]|5(?:4[46]|8)|8[2379])|5(?:[2467]|3[237]|8[23])|7[1-578]|8(?:[2469]|3[278]|5(?:[56][46]|[78])|7[378]|8(?:6[3-6]|[78]))))|92(?:2[24-9]|3[1-59]|47)|93(?:4[35][56]|58[45]|8(?:[38]5|54|76))[4-6]", "9(?:2(?:[23]02|6(?:[25]|4(?:64|[78]))|9(?:[02356]|4(?:[0268]|5[2-6])|72|8[23]))|3(?:3[28]|4(?:[04679]|3(?:5(?:4[0-25689]|[56])|[78])|5(?:4[46]|8)|8[2379])|5(?:[2467]|3[237]|8(?:[23]|4(?:[45]|60)|5(?:4[0-39]|5|64)))|7[1-578]|8(?:[2469]|3[278]|5(?:4(?:4|5[13-7]|6[89])|[56][46]|[78])|7[378]|8(?:6[3-6]|[78]))))|92(?:2[24-9]|3[1-59]|47)|93(?:4(?:36|5[56])|8(?:[38]5|76))[4-6]"], format: "${2} 15-${3}-${4}", national_format: "0${2} 15-${3}-${4}", intl_format: Some("${1} ${2} ${3}-${4}") },
+        NumberFormat { pattern: "(\\d)(\\d{2})(\\d{4})(\\d{4})", leading_digits: &["91"], format: "${2} 15-${3}-${4}", national_format: "0${2} 15-${3}-${4}", intl_format: Some("${1} ${2} ${3}-${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{5})", leading_digits: &["8"], format: "${1}-${2}-${3}", national_format: "0${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["9"], format: "${2} 15-${3}-${4}", national_format: "0${2} 15-${3}-${4}", intl_format: Some("${1} ${2} ${3}-${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "3(?:7(?:1[15]|81)|8(?:21|4[16]|69|9[12]))[46]\\d{5}|(?:2(?:2(?:2[59]|44|52)|3(?:26|44)|47[35]|9(?:[07]2|2[26]|34|46))|3327)[45]\\d{5}|(?:2(?:657|9(?:54|66))|3(?:48[27]|7(?:55|77)|8(?:65|78)))[2-8]\\d{5}|(?:2(?:284|3(?:02|23)|477|622|920)|3(?:4(?:46|89|92)|541))[2-7]\\d{5}|(?:(?:11[1-8]|670)\\d|2(?:2(?:0[45]|1[2-6]|3[3-6])|3(?:[06]4|7[45])|494|6(?:04|1[2-8]|[36][45]|4[3-6])|80[45]|9(?:[17][4-6]|[48][45]|9[3-6]))|3(?:364|4(?:1[2-8]|[25][4-6]|3[3-6]|84)|5(?:1[2-9]|[38][4-6])|6(?:2[45]|44)|7[069][45]|8(?:0[45]|1[2-7]|3[4-6]|5[3-6]|7[2-6]|8[3-68])))\\d{6}|(?:2(?:2(?:62|81)|320|9(?:42|83))|3(?:329|4(?:62|7[16])|5(?:43|64)|7(?:18|5[17])))[2-6]\\d{5}|2(?:2(?:21|4[23]|6[145]|7[1-4]|8[356]|9[267])|3(?:16|3[13-8]|43|5[346-8]|9[3-5])|6(?:2[46]|4[78]|5[1568])|9(?:03|2[1457-9]|3[1356]|4[08]|[56][23]|82))4\\d{5}|(?:2(?:257|3(?:24|46|92)|9(?:01|23|64))|3(?:4(?:42|64)|5(?:25|37|4[47]|71)|7(?:35|72)|825))[3-6]\\d{5}|(?:2(?:2(?:02|2[3467]|4[156]|5[45]|6[6-8]|91)|3(?:1[47]|25|[45][25]|96)|47[48]|625|932)|3(?:38[2578]|4(?:0[0-24-9]|3[78]|4[457]|58|6[035-9]|72|83|9[136-8])|5(?:2[124]|[368][23]|4[2689]|7[2-6])|7(?:16|2[15]|3[14]|4[13]|5[468]|7[3-5]|8[26])|8(?:2[67]|3[278]|4[3-5]|5[78]|6[1-378]|[78]7|94)))[4-6]\\d{5}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "93(?:7(?:1[15]|81)|8(?:21|4[16]|69|9[12]))[46]\\d{5}|9(?:2(?:2(?:2[59]|44|52)|3(?:26|44)|47[35]|9(?:[07]2|2[26]|34|46))|3327)[45]\\d{5}|9(?:2(?:657|9(?:54|66))|3(?:48[27]|7(?:55|77)|8(?:65|78)))[2-8]\\d{5}|9(?:2(?:284|3(?:02|23)|477|622|920)|3(?:4(?:46|89|92)|541))[2-7]\\d{5}|(?:675\\d|9(?:11[1-8]\\d|2(?:2(?:0[45]|1[2-6]|3[3-6])|3(?:[06]4|7[45])|494|6(?:04|1[2-8]|[36][45]|4[3-6])|80[45]|9(?:[17][4-6]|[48][45]|9[3-6]))|3(?:364|4(?:1[2-8]|[25][4-6]|3[3-6]|84)|5(?:1[2-9]|[38][4-6])|6(?:2[45]|44)|7[069][45]|8(?:0[45]|1[2-7]|3[4-6]|5[3-6]|7[2-6]|8[3-68]))))\\d{6}|9(?:2(?:2(?:62|81)|320|9(?:42|83))|3(?:329|4(?:62|7[16])|5(?:43|64)|7(?:18|5[17])))[2-6]\\d{5}|92(?:2(?:21|4[23]|6[145]|7[1-4]|8[356]|9[267])|3(?:16|3[13-8]|43|5[346-8]|9[3-5])|6(?:2[46]|4[78]|5[1568])|9(?:03|2[1457-9]|3[1356]|4[08]|[56][23]|82))4\\d{5}|9(?:2(?:257|3(?:24|46|92)|9(?:01|23|64))|3(?:4(?:42|64)|5(?:25|37|4[47]|71)|7(?:35|72)|825))[3-6]\\d{5}|9(?:2(?:2(?:02|2[3467]|4[156]|5[45]|6[6-8]|91)|3(?:1[47]|25|[45][25]|96)|47[48]|625|932)|3(?:38[2578]|4(?:0[0-24-9]|3[78]|4[457]|58|6[035-9]|72|83|9[136-8])|5(?:2[124]|[368][23]|4[2689]|7[2-6])|7(?:16|2[15]|3[14]|4[13]|5[468]|7[3-5]|8[26])|8(?:2[67]|3[278]|4[3-5]|5[78]|6[1-378]|[78]7|94)))[4-6]\\d{5}", lengths: &[10, 11] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{7,8}", lengths: &[10, 11] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "60[04579]\\d{7}", lengths: &[10] }),
+        (PhoneType::Uan, NumberDesc { pattern: "810\\d{7}", lengths: &[10] }),
+    ] },
+    // AS
+    Metadata { general: "(?:[58]\\d\\d|684|900)\\d{7}", lengths: &[10], leading_digits: Some("684"), national_prefix_for_parsing: Some("([267]\\d{6})$|1"), national_prefix_transform_rule: Some("684$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "6846(?:22|33|44|55|77|88|9[19])\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "684(?:2(?:48|5[2468]|7[26])|7(?:3[13]|70|82))\\d{4}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+    ] },
+    // AT
+    Metadata { general: "1\\d{3,12}|2\\d{6,12}|43(?:(?:0\\d|5[02-9])\\d{3,9}|2\\d{4,5}|[3467]\\d{4}|8\\d{4,6}|9\\d{4,7})|5\\d{4,12}|8\\d{7,12}|9\\d{8,12}|(?:[367]\\d|4[0-24-9])\\d{4,11}", lengths: &[4, 5, 6, 7, 8, 9, 10, 11, 12, 13], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})", leading_digits: &["14"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d)(\\d{3,12})", leading_digits: &["1(?:11|[2-9])"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})", leading_digits: &["517"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3,5})", leading_digits: &["5[079]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{6})", leading_digits: &["[18]"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{3})(\\d{3,10})", leading_digits: &["(?:31|4)6|51|6(?:48|5[0-3579]|[6-9])|7(?:20|32|8)|[89]", "(?:31|4)6|51|6(?:485|5[0-3579]|[6-9])|7(?:20|32|8)|[89]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3,9})", leading_digits: &["[2-467]|5[2-6]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3,4})", leading_digits: &["5"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{4})(\\d{4,7})", leading_digits: &["5"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "1(?:11\\d|[2-9]\\d{3,11})|(?:316|463)\\d{3,10}|648[34]\\d{3,9}|(?:51|66|73)2\\d{3,10}|(?:2(?:1[467]|2[13-8]|5[2357]|6[1-46-8]|7[1-8]|8[124-7]|9[1458])|3(?:1[1-578]|3[23568]|4[5-7]|5[1378]|6[1-38]|8[3-68])|4(?:2[1-8]|35|7[1368]|8[2457])|5(?:2[1-8]|3[357]|4[147]|5[12578]|6[37])|6(?:13|2[1-47]|4[135-7]|5[468])|7(?:2[1-8]|35|4[13478]|5[68]|6[16-8]|7[1-6]|9[45]))\\d{4,10}", lengths: &[4, 5, 6, 7, 8, 9, 10, 11, 12, 13] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "6(?:485|(?:5[0-3579]|6[013-9]|[7-9]\\d)\\d)\\d{3,9}", lengths: &[7, 8, 9, 10, 11, 12, 13] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{6,10}", lengths: &[9, 10, 11, 12, 13] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "(?:8[69][2-68]|9(?:0[01]|3[019]))\\d{6,10}", lengths: &[9, 10, 11, 12, 13] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "8(?:10|2[018])\\d{6,10}|828\\d{5}", lengths: &[8, 9, 10, 11, 12, 13] }),
+        (PhoneType::Voip, NumberDesc { pattern: "5(?:0[1-9]|17|[79]\\d)\\d{2,10}|7[28]0\\d{6,10}", lengths: &[5, 6, 7, 8, 9, 10, 11, 12, 13] }),
+    ] },
+    // AU
+    Metadata { general: "1(?:[0-79]\\d{7}(?:\\d(?:\\d{2})?)?|8[0-24-9]\\d{7})|[2-478]\\d{8}|1\\d{4,7}", lengths: &[5, 6, 7, 8, 9, 10, 12], leading_digits: None, national_prefix_for_parsing: Some("(183[12])|0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3,4})", leading_digits: &["16"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["13"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: None },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})", leading_digits: &["19"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["180", "1802"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d{4})(\\d{3,4})", leading_digits: &["19"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{2,4})", leading_digits: &["16"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["14|4"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d)(\\d{4})(\\d{4})", leading_digits: &["[2378]"], format: "${1} ${2} ${3}", national_format: "(0${1}) ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})(\\d{3})", leading_digits: &["1(?:30|[89])"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})(\\d{4})", leading_digits: &["130"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: None },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:(?:241|349)0\\d\\d|8(?:51(?:0(?:0[03-9]|[12479]\\d|3[2-9]|5[0-8]|6[1-9]|8[0-7])|1(?:[0235689]\\d|1[0-69]|4[0-589]|7[0-47-9])|2(?:0[0-79]|[18][13579]|2[14-9]|3[0-46-9]|[4-6]\\d|7[89]|9[0-4])|[34]\\d\\d)|91(?:(?:[0-58]\\d|6[0135-9])\\d|7(?:0[0-24-9]|[1-9]\\d)|9(?:[0-46-9]\\d|5[0-79]))))\\d{3}|(?:2(?:[0-26-9]\\d|3[0-8]|4[02-9]|5[0135-9])|3(?:[0-3589]\\d|4[0-578]|6[1-9]|7[0-35-9])|7(?:[013-57-9]\\d|2[0-8])|8(?:55|6[0-8]|[78]\\d|9[02-9]))\\d{6}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "4(?:79[01]|83[0-36-9]|95[0-3])\\d{5}|4(?:[0-36]\\d|4[047-9]|[58][0-24-9]|7[02-8]|9[0-47-9])\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "180(?:0\\d{3}|2)\\d{3}", lengths: &[7, 10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "190[0-26]\\d{6}", lengths: &[10] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "13(?:00\\d{6}(?:\\d{2})?|45[0-4]\\d{3})|13\\d{4}", lengths: &[6, 8, 10, 12] }),
+        (PhoneType::Voip, NumberDesc { pattern: "14(?:5(?:1[0458]|[23][458])|71\\d)\\d{4}", lengths: &[9] }),
+        (PhoneType::Pager, NumberDesc { pattern: "163\\d{2,6}", lengths: &[5, 6, 7, 8, 9] }),
+    ] },
+    // AW
+    Metadata { general: "(?:[25-79]\\d\\d|800)\\d{4}", lengths: &[7], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[25-9]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "5(?:2\\d|8[1-9])\\d{4}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:290|5[69]\\d|6(?:[03]0|22|4[0-2]|[69]\\d)|7(?:[34]\\d|7[07])|9(?:6[45]|9[4-8]))\\d{4}", lengths: &[7] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{4}", lengths: &[7] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900\\d{4}", lengths: &[7] }),
+        (PhoneType::Voip, NumberDesc { pattern: "(?:28\\d|501)\\d{4}", lengths: &[7] }),
+    ] },
+    // AX
+    Metadata { general: "2\\d{4,9}|35\\d{4,5}|(?:60\\d\\d|800)\\d{4,6}|7\\d{5,11}|(?:[14]\\d|3[0-46-9]|50)\\d{4,8}", lengths: &[5, 6, 7, 8, 9, 10, 11, 12], leading_digits: Some("18"), national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "18[1-8]\\d{3,6}", lengths: &[6, 7, 8, 9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "4946\\d{2,6}|(?:4[0-8]|50)\\d{4,8}", lengths: &[6, 7, 8, 9, 10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{4,6}", lengths: &[7, 8, 9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "[67]00\\d{5,6}", lengths: &[8, 9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "20\\d{4,8}|60[12]\\d{5,6}|7(?:099\\d{4,5}|5[03-9]\\d{3,7})|20[2-59]\\d\\d|(?:606|7(?:0[78]|1|3\\d))\\d{7}|(?:10|29|3[09]|70[1-5]\\d)\\d{4,8}", lengths: &[5, 6, 7, 8, 9, 10, 11, 12] }),
+    ] },
+    // AZ
+    Metadata { general: "365\\d{6}|(?:[124579]\\d|60|88)\\d{7}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["[1-9]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: None },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["90"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["1[28]|2|365|46", "1[28]|2|365[45]|46", "1[28]|2|365(?:4|5[02])|46"], format: "${1} ${2} ${3} ${4}", national_format: "(0${1}) ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["[13-9]"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2[12]428|3655[02])\\d{4}|(?:2(?:22[0-79]|63[0-28])|3654)\\d{5}|(?:(?:1[28]|46)\\d|2(?:[014-6]2|[23]3))\\d{6}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "36554\\d{4}|(?:[16]0|4[04]|5[015]|7[07]|99)\\d{7}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "88\\d{7}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900200\\d{3}", lengths: &[9] }),
+    ] },
+    // BA
+    Metadata { general: "6\\d{8}|(?:[35689]\\d|49|70)\\d{6}", lengths: &[8, 9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3})", leading_digits: &["[2-9]"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["6[1-3]|[7-9]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["[3-5]|6[56]"], format: "${1} ${2}-${3}", national_format: "0${1} ${2}-${3}", intl_format: Some("${1} ${2}-${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{3})", leading_digits: &["6"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:3(?:[05-79][2-9]|1[4579]|[23][24-9]|4[2-4689]|8[2457-9])|49[2-579]|5(?:0[2-49]|[13][2-9]|[268][2-4679]|4[4689]|5[2-79]|7[2-69]|9[2-4689]))\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "6040\\d{5}|6(?:03|[1-356]|44|7\\d)\\d{6}", lengths: &[8, 9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8[08]\\d{6}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "9[0246]\\d{6}", lengths: &[8] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "8[12]\\d{6}", lengths: &[8] }),
+        (PhoneType::Uan, NumberDesc { pattern: "703[235]0\\d{3}|70(?:2[0-5]|3[0146]|[56]0)\\d{4}", lengths: &[8] }),
+    ] },
+    // BB
+    Metadata { general: "(?:246|[58]\\d\\d|900)\\d{7}", lengths: &[10], leading_digits: Some("246"), national_prefix_for_parsing: Some("([2-9]\\d{6})$|1"), national_prefix_transform_rule: Some("246$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "246521[0369]\\d{3}|246(?:2(?:2[78]|7[0-4])|4(?:1[024-6]|2\\d|3[2-9])|5(?:20|[34]\\d|54|7[1-3])|6(?:2\\d|38)|7[35]7|9(?:1[89]|63))\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "246(?:(?:2(?:[3568]\\d|4[0-57-9])|3(?:5[2-9]|6[0-6])|4(?:46|5\\d)|69[5-7]|8(?:[2-5]\\d|83))\\d|52(?:1[147]|20))\\d{3}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "(?:246976|900[2-9]\\d\\d)\\d{4}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "24631\\d{5}", lengths: &[10] }),
+        (PhoneType::Uan, NumberDesc { pattern: "246(?:292|367|4(?:1[7-9]|3[01]|4[47-9]|67)|7(?:1[2-9]|2\\d|3[016]|53))\\d{4}", lengths: &[10] }),
+    ] },
+    // BD
+    Metadata { general: "[1-469]\\d{9}|8[0-79]\\d{7,8}|[2-79]\\d{8}|[2-9]\\d{7}|[3-9]\\d{6}|[57-9]\\d{5}", lengths: &[6, 7, 8, 9, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{4,6})", leading_digits: &["31[5-8]|[459]1"], format: "${1}-${2}", national_format: "0${1}-${2}", intl_format: Some("${1}-${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3,7})", leading_digits: &["3(?:[67]|8[013-9])|4(?:6[168]|7|[89][18])|5(?:6[128]|9)|6(?:[15]|28|4[14])|7[2-589]|8(?:0[014-9]|[12])|9[358]|(?:3[2-5]|4[235]|5[2-578]|6[0389]|76|8[3-7]|9[24])1|(?:44|66)[01346-9]"], format: "${1}-${2}", national_format: "0${1}-${2}", intl_format: Some("${1}-${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3,6})", leading_digits: &["[13-9]|2[23]"], format: "${1}-${2}", national_format: "0${1}-${2}", intl_format: Some("${1}-${2}") },
+        NumberFormat { pattern: "(\\d)(\\d{7,8})", leading_digits: &["2"], format: "${1}-${2}", national_format: "0${1}-${2}", intl_format: Some("${1}-${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:4(?:31\\d\\d|423)|5222)\\d{3}(?:\\d{2})?|8332[6-9]\\d\\d|(?:3(?:03[56]|224)|4(?:22[25]|653))\\d{3,4}|(?:3(?:42[47]|529|823)|4(?:027|525|65(?:28|8))|562|6257|7(?:1(?:5[3-5]|6[12]|7[156]|89)|22[589]56|32|42675|52(?:[25689](?:56|8)|[347]8)|71(?:6[1267]|75|89)|92374)|82(?:2[59]|32)56|9(?:03[23]56|23(?:256|373)|31|5(?:1|2[4589]56)))\\d{3}|(?:3(?:02[348]|22[35]|324|422)|4(?:22[67]|32[236-9]|6(?:2[46]|5[57])|953)|5526|6(?:024|6655)|81)\\d{4,5}|(?:2(?:7(?:1[0-267]|2[0-289]|3[0-29]|4[01]|5[1-3]|6[013]|7[0178]|91)|8(?:0[125]|1[1-6]|2[0157-9]|3[1-69]|41|6[1-35]|7[1-5]|8[1-8]|9[0-6])|9(?:0[0-2]|1[0-4]|2[568]|3[3-6]|5[5-7]|6[0136-9]|7[0-7]|8[014-9]))|3(?:0(?:2[025-79]|3[2-4])|181|22[12]|32[2356]|824)|4(?:02[09]|22[348]|32[045]|523|6(?:27|54))|666(?:22|53)|7(?:22[57-9]|42[56]|82[35])8|8(?:0[124-9]|2(?:181|2[02-4679]8)|4[12]|[5-7]2)|9(?:[04]2|2(?:2|328)|81))\\d{4}|(?:2(?:[23]\\d|[45])\\d\\d|3(?:1(?:2[5-7]|[5-7])|425|822)|4(?:033|1\\d|[257]1|332|4(?:2[246]|5[25])|6(?:2[35]|56|62)|8(?:23|54)|92[2-5])|5(?:02[03489]|22[457]|32[35-79]|42[46]|6(?:[18]|53)|724|826)|6(?:023|2(?:2[2-5]|5[3-5]|8)|32[3478]|42[34]|52[47]|6(?:[18]|6(?:2[34]|5[24]))|[78]2[2-5]|92[2-6])|7(?:02|21\\d|[3-589]1|6[12]|72[24])|8(?:217|3[12]|[5-7]1)|9[24]1)\\d{5}|(?:(?:3[2-8]|5[2-57-9]|6[03-589])1|4[4689][18])\\d{5}|[59]1\\d{5}", lengths: &[6, 7, 8, 9, 10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:1[13-9]\\d|644)\\d{7}|(?:3[78]|44|66)[02-9]\\d{7}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[03]\\d{7}", lengths: &[10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "96(?:0[469]|1[0-47]|3[389]|43|6[69]|7[78])\\d{6}", lengths: &[10] }),
+    ] },
+    // BE
+    Metadata { general: "4\\d{8}|[1-9]\\d{7}", lengths: &[8, 9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{3})", leading_digits: &["(?:80|9)0"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["[239]|4[23]"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[15-8]"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["4"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "80[2-8]\\d{5}|(?:1[0-69]|[23][2-8]|4[23]|5\\d|6[013-57-9]|71|8[1-79]|9[2-4])\\d{6}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "4[5-9]\\d{7}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800[1-9]\\d{4}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "(?:70(?:2[0-57]|3[04-7]|44|6[04-69]|7[0579])|90\\d\\d)\\d{4}", lengths: &[8] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "7879\\d{4}", lengths: &[8] }),
+        (PhoneType::Uan, NumberDesc { pattern: "78(?:0[578]|1[014-8]|2[25]|3[15-8]|48|5[05]|60|7[06-8]|9\\d)\\d{4}", lengths: &[8] }),
+    ] },
+    // BF
+    Metadata { general: "(?:[025-7]\\d|44)\\d{6}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[024-7]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2(?:0(?:49|5[23]|6[5-7]|9[016-9])|4(?:4[569]|5[4-6]|6[5-7]|7[0179])|5(?:[34]\\d|50|6[5-7]))\\d{4}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:0[1-7]|44|5[0-8]|[67]\\d)\\d{6}", lengths: &[8] }),
+    ] },
+    // BG
+    Metadata { general: "00800\\d{7}|[2-7]\\d{6,7}|[89]\\d{6,8}|2\\d{5}", lengths: &[6, 7, 8, 9, 12], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{6})", leading_digits: &["1"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d)(\\d)(\\d{2})(\\d{2})", leading_digits: &["2"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["43[1-6]|70[1-9]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{3,4})", leading_digits: &["2"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{2,3})", leading_digits: &["[356]|4[124-7]|7[1-9]|8[1-6]|9[1-7]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{3})", leading_digits: &["(?:70|8)0"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{2})", leading_digits: &["43[1-7]|7"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3,4})", leading_digits: &["[48]|9[08]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["9"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2\\d{5,7}|(?:43[1-6]|70[1-9])\\d{4,5}|(?:[36]\\d|4[124-7]|[57][1-9]|8[1-6]|9[1-7])\\d{5,6}", lengths: &[6, 7, 8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:43[07-9]|99[69]\\d)\\d{5}|(?:8[7-9]|98)\\d{7}", lengths: &[8, 9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "(?:00800\\d\\d|800)\\d{5}", lengths: &[8, 12] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90\\d{6}", lengths: &[8] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "700\\d{5}", lengths: &[8] }),
+    ] },
+    // BH
+    Metadata { general: "[136-9]\\d{7}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[13679]|8[02-4679]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:1(?:3[1356]|6[0156]|7\\d)\\d|6(?:1[16]\\d|500|6(?:0\\d|3[12]|44|55|7[7-9]|88)|9[69][69])|7(?:[07]\\d\\d|1(?:11|78)))\\d{4}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:3(?:[0-79]\\d|8[0-57-9])\\d|6(?:3(?:00|33|6[16])|441|6(?:3[03-9]|[69]\\d|7[0-689])))\\d{4}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8[02369]\\d{6}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "(?:87|9[0-8])\\d{6}", lengths: &[8] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "84\\d{6}", lengths: &[8] }),
+    ] },
+    // BI
+    Metadata { general: "(?:[267]\\d|31)\\d{6}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[2367]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:22|31)\\d{6}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:29|6[124-9]|7[125-9])\\d{6}", lengths: &[8] }),
+    ] },
+    // BJ
+    Metadata { general: "(?:01\\d|8)\\d{7}", lengths: &[8, 10], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["8"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["0"], format: "${1} ${2} ${3} ${4} ${5}", national_format: "${1} ${2} ${3} ${4} ${5}", intl_format: Some("${1} ${2} ${3} ${4} ${5}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "012\\d{7}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "01(?:2[5-9]|[4-69]\\d)\\d{6}", lengths: &[10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "857[58]\\d{4}", lengths: &[8] }),
+        (PhoneType::Uan, NumberDesc { pattern: "81\\d{6}", lengths: &[8] }),
+    ] },
+    // BL
+    Metadata { general: "(?:590\\d|7090)\\d{5}|(?:69|80|9\\d)\\d{7}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "590(?:2[7-9]|3[3-7]|5[12]|87)\\d{4}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:69(?:0\\d\\d|1(?:2[2-9]|3[0-5])|4(?:0[89]|1[2-6]|9\\d)|6(?:1[016-9]|5[0-4]|[67]\\d))|7090[0-4])\\d{4}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[0-5]\\d{6}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "9(?:(?:39[5-7]|76[018])\\d|475[0-6])\\d{4}", lengths: &[9] }),
+    ] },
+    // BM
+    Metadata { general: "(?:441|[58]\\d\\d|900)\\d{7}", lengths: &[10], leading_digits: Some("441"), national_prefix_for_parsing: Some("([2-9]\\d{6})$|1"), national_prefix_transform_rule: Some("441$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "441(?:[46]\\d\\d|5(?:4\\d|60|89))\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "441(?:[2378]\\d|5[0-39]|9[02])\\d{5}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+    ] },
+    // BN
+    Metadata { general: "[2-578]\\d{6}", lengths: &[7], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[2-578]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "22[0-7]\\d{4}|(?:2[013-9]|[34]\\d|5[0-25-9])\\d{5}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:22[89]|[78]\\d\\d)\\d{4}", lengths: &[7] }),
+        (PhoneType::Voip, NumberDesc { pattern: "5[34]\\d{5}", lengths: &[7] }),
+    ] },
+    // BO
+    Metadata { general: "8001\\d{5}|(?:[2-467]\\d|50)\\d{6}", lengths: &[8, 9], leading_digits: None, national_prefix_for_parsing: Some("0(1\\d)?"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{7})", leading_digits: &["[235]|4[46]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{8})", leading_digits: &["[67]"], format: "${1}", national_format: "${1}", intl_format: Some("${1}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{4})", leading_digits: &["8"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2(?:2\\d\\d|5(?:11|[258]\\d|9[67])|6(?:12|2\\d|9[34])|8(?:2[34]|39|62))|3(?:3\\d\\d|4(?:6\\d|8[24])|8(?:25|42|5[257]|86|9[25])|9(?:[27]\\d|3[2-4]|4[248]|5[24]|6[2-6]))|4(?:4\\d\\d|6(?:11|[24689]\\d|72)))\\d{4}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "[67]\\d{7}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8001[07]\\d{4}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "50\\d{6}", lengths: &[8] }),
+    ] },
+    // BQ
+    Metadata { general: "(?:[34]1|7\\d)\\d{5}", lengths: &[7], leading_digits: Some("[347]"), national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:318[023]|41(?:6[023]|70)|7(?:1[578]|2[05]|50)\\d)\\d{3}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:31(?:8[14-8]|9[14578])|416[14-9]|7(?:0[01]|7[07]|8\\d|9[056])\\d)\\d{3}", lengths: &[7] }),
+    ] },
+    // BR
+    Metadata { general: "[1-467]\\d{9,10}|55[0-46-9]\\d{8}|[34]\\d{7}|55\\d{7,8}|(?:5[0-46-9]|[89]\\d)\\d{7,9}", lengths: &[8, 9, 10, 11], leading_digits: None, national_prefix_for_parsing: Some("(?:0|90)(?:(1[245]|2[1-35]|31|4[13]|[56]5|99)(\\d{10,11}))?"), national_prefix_transform_rule: Some("$2"), formats: &[
+        NumberFormat { pattern: "(\\d{3,6})", leading_digits: &["1(?:1[25-8]|2[357-9]|3[02-68]|4[12568]|5|6[0-8]|8[015]|9[0-47-9])|321|610"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["300|4(?:0[02]|37|86)", "300|4(?:0(?:0|20)|370|864)"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: Some("${1}-${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[2-57]", "[2357]|4(?:[0-24-9]|3(?:[0-689]|7[1-9]))"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d{3})(\\d{2,3})(\\d{4})", leading_digits: &["(?:[358]|90)0"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{5})(\\d{4})", leading_digits: &["9"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d{2})(\\d{4})(\\d{4})", leading_digits: &["(?:[14689][1-9]|2[12478]|3[1-578]|5[13-5]|7[13-579])[2-57]"], format: "${1} ${2}-${3}", national_format: "(${1}) ${2}-${3}", intl_format: Some("${1} ${2}-${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{5})(\\d{4})", leading_digits: &["[16][1-9]|[2-57-9]"], format: "${1} ${2}-${3}", national_format: "(${1}) ${2}-${3}", intl_format: Some("${1} ${2}-${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:[14689][1-9]|2[12478]|3[1-578]|5[13-5]|7[13-579])[2-5]\\d{7}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:[14689][1-9]|2[12478]|3[1-578]|5[13-5]|7[13-579])(?:7|9\\d)\\d{7}", lengths: &[10, 11] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{6,7}", lengths: &[9, 10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "[59]00\\d{6,7}", lengths: &[9, 10] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "(?:30[03]\\d{3}|4(?:0(?:0\\d|20)|370|864))\\d{4}|300\\d{5}", lengths: &[8, 10] }),
+    ] },
+    // BS
+    Metadata { general: "(?:242|[58]\\d\\d|900)\\d{7}", lengths: &[10], leading_digits: Some("242"), national_prefix_for_parsing: Some("([3-8]\\d{6})$|1"), national_prefix_transform_rule: Some("242$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "242(?:3(?:02|[236][1-9]|4[0-24-9]|5[0-68]|7[347]|8[0-4]|9[2-467])|461|502|6(?:0[1-5]|12|2[013]|[45]0|7[67]|8[78]|9[89])|7(?:02|88))\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "242(?:3(?:5[79]|7[56]|95)|4(?:[23][1-9]|4[1-35-9]|5[1-8]|6[2-8]|7\\d|81)|5(?:2[45]|3[35]|44|5[1-46-9]|65|77)|6[34]6|7(?:27|38)|8(?:0[1-9]|1[02-9]|2\\d|3[0-4]|[89]9))\\d{4}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "242300\\d{4}|8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::Uan, NumberDesc { pattern: "242225\\d{4}", lengths: &[10] }),
+    ] },
+    // BT
+    Metadata { general: "[178]\\d{7}|[2-8]\\d{6}", lengths: &[7, 8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3})", leading_digits: &["[2-7]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{3})", leading_digits: &["[2-6]|7[246]|8[2-4]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["1[67]|[78]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2[3-6]|[34][5-7]|5[236]|6[2-46]|7[246]|8[2-4])\\d{5}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:1[67]|[78]7)\\d{6}", lengths: &[8] }),
+    ] },
+    // BW
+    Metadata { general: "(?:0800|(?:[37]|800)\\d)\\d{6}|(?:[2-6]\\d|90)\\d{5}", lengths: &[7, 8, 10], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{5})", leading_digits: &["90"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[24-6]|3[15-9]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["[37]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})(\\d{3})", leading_digits: &["0"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})(\\d{3})", leading_digits: &["8"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2(?:4[0-48]|6[0-24]|9[0578])|3(?:1[0-35-9]|55|[69]\\d|7[013]|81)|4(?:6[03]|7[1267]|9[0-5])|5(?:3[03489]|4[0489]|7[1-47]|88|9[0-49])|6(?:2[1-35]|5[149]|8[013467]))\\d{4}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:321|7[1-8]\\d)\\d{5}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "(?:0800|800\\d)\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90\\d{5}", lengths: &[7] }),
+        (PhoneType::Voip, NumberDesc { pattern: "79(?:1(?:[0-2]\\d|3[0-8])|2[0-7]\\d)\\d{3}", lengths: &[8] }),
+    ] },
+    // BY
+    Metadata { general: "(?:[12]\\d|33|44|902)\\d{7}|8(?:0[0-79]\\d{5,7}|[1-7]\\d{9})|8(?:1[0-489]|[5-79]\\d)\\d{7}|8[1-79]\\d{6,7}|8[0-79]\\d{5}|8\\d{5}", lengths: &[6, 7, 8, 9, 10, 11], leading_digits: None, national_prefix_for_parsing: Some("0|80?"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3})", leading_digits: &["800"], format: "${1} ${2}", national_format: "8 ${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2,4})", leading_digits: &["800"], format: "${1} ${2} ${3}", national_format: "8 ${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{2})(\\d{3})", leading_digits: &["1(?:5[169]|6[3-5]|7[179])|2(?:1[35]|2[34]|3[3-5])", "1(?:5[169]|6(?:3[1-3]|4|5[125])|7(?:1[3-9]|7[0-24-6]|9[2-7]))|2(?:1[35]|2[34]|3[3-5])"], format: "${1} ${2}-${3}", national_format: "8 0${1} ${2}-${3}", intl_format: Some("${1} ${2}-${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["1(?:[56]|7[467])|2[1-3]"], format: "${1} ${2}-${3}-${4}", national_format: "8 0${1} ${2}-${3}-${4}", intl_format: Some("${1} ${2}-${3}-${4}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["[1-4]"], format: "${1} ${2}-${3}-${4}", national_format: "8 0${1} ${2}-${3}-${4}", intl_format: Some("${1} ${2}-${3}-${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3,4})(\\d{4})", leading_digits: &["[89]"], format: "${1} ${2} ${3}", national_format: "8 ${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:1(?:5(?:1[1-5]|[24]\\d|6[2-4]|9[1-7])|6(?:[235]\\d|4[1-7])|7\\d\\d)|2(?:1(?:[246]\\d|3[0-35-9]|5[1-9])|2(?:[235]\\d|4[0-8])|3(?:[26]\\d|3[02-79]|4[024-7]|5[03-7])))\\d{5}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:2(?:5[5-79]|9[1-9])|(?:33|44)\\d)\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{3,7}|8(?:0[13]|20\\d)\\d{7}", lengths: &[6, 7, 8, 9, 10, 11] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "(?:810|902)\\d{7}", lengths: &[10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "249\\d{6}", lengths: &[9] }),
+    ] },
+    // BZ
+    Metadata { general: "(?:0800\\d|[2-8])\\d{6}", lengths: &[7, 11], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[2-8]"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: Some("${1}-${2}") },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{4})(\\d{3})", leading_digits: &["0"], format: "${1}-${2}-${3}-${4}", national_format: "${1}-${2}-${3}-${4}", intl_format: Some("${1}-${2}-${3}-${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2(?:[02]\\d|36|[68]0)|[3-58](?:[02]\\d|[68]0)|7(?:[02]\\d|32|[68]0))\\d{4}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "6[0-35-7]\\d{5}", lengths: &[7] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "0800\\d{7}", lengths: &[11] }),
+    ] },
+    // CA
+    Metadata { general: "[2-9]\\d{9}|3\\d{6}", lengths: &[7, 10], leading_digits: None, national_prefix_for_parsing: Some("1"), national_prefix_transform_rule: None, formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2(?:04|[23]6|[48]9|5[07]|63)|3(?:06|43|54|6[578]|82)|4(?:03|1[68]|[26]8|3[178]|50|74)|5(?:06|1[49]|48|79|8[147])|6(?:04|[18]3|39|47|72)|7(?:0[59]|42|53|78|8[02])|8(?:[06]7|19|25|7[39])|9(?:0[25]|42))[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:2(?:04|[23]6|[48]9|5[07]|63)|3(?:06|43|54|6[578]|82)|4(?:03|1[68]|[26]8|3[178]|50|74)|5(?:06|1[49]|48|79|8[147])|6(?:04|[18]3|39|47|72)|7(?:0[59]|42|53|78|8[02])|8(?:[06]7|19|25|7[39])|9(?:0[25]|42))[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|(?:5(?:2[125-9]|33|44|66|77|88)|6(?:22|33))[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "600[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::Uan, NumberDesc { pattern: "310\\d{4}", lengths: &[7] }),
+    ] },
+    // CC
+    Metadata { general: "1(?:[0-79]\\d{8}(?:\\d{2})?|8[0-24-9]\\d{7})|[148]\\d{8}|1\\d{5,7}", lengths: &[6, 7, 8, 9, 10, 12], leading_digits: None, national_prefix_for_parsing: Some("([59]\\d{7})$|0"), national_prefix_transform_rule: Some("8$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "8(?:51(?:0(?:02|31|60|89)|1(?:18|76)|223)|91(?:0(?:1[0-2]|29)|1(?:[28]2|50|79)|2(?:10|64)|3(?:[06]8|22)|4[29]8|62\\d|70[23]|959))\\d{3}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "4(?:79[01]|83[0-36-9]|95[0-3])\\d{5}|4(?:[0-36]\\d|4[047-9]|[58][0-24-9]|7[02-8]|9[0-47-9])\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "180(?:0\\d{3}|2)\\d{3}", lengths: &[7, 10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "190[0-26]\\d{6}", lengths: &[10] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "13(?:00\\d{6}(?:\\d{2})?|45[0-4]\\d{3})|13\\d{4}", lengths: &[6, 8, 10, 12] }),
+        (PhoneType::Voip, NumberDesc { pattern: "14(?:5(?:1[0458]|[23][458])|71\\d)\\d{4}", lengths: &[9] }),
+    ] },
+    // CD
+    Metadata { general: "(?:(?:[189]|5\\d)\\d|2)\\d{7}|[1-68]\\d{6}", lengths: &[7, 8, 9, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{3})", leading_digits: &["88"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{5})", leading_digits: &["[1-6]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{4})", leading_digits: &["2"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["1"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[89]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["5"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:(?:12|573)\\d\\d|276)\\d{5}|[1-6]\\d{6}", lengths: &[7, 8, 9, 10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "88\\d{5}|(?:8[0-69]|9[017-9])\\d{7}", lengths: &[7, 9] }),
+    ] },
+    // CF
+    Metadata { general: "(?:[27]\\d{3}|8776)\\d{4}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[278]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2[12]\\d{6}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "7[02-7]\\d{6}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "8776\\d{4}", lengths: &[8] }),
+    ] },
+    // CG
+    Metadata { general: "222\\d{6}|(?:0\\d|80)\\d{7}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{4})(\\d{4})", leading_digits: &["8"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["[02]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "222[1-589]\\d{5}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "026(?:1[0-5]|6[6-9])\\d{4}|0(?:[14-6]\\d\\d|2(?:40|5[5-8]|6[07-9]))\\d{5}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "80[0-2]\\d{6}", lengths: &[9] }),
+    ] },
+    // CH
+    Metadata { general: "8\\d{11}|[2-9]\\d{8}", lengths: &[9, 12], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["8[047]|90"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["[2-79]|81"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["8"], format: "${1} ${2} ${3} ${4} ${5}", national_format: "0${1} ${2} ${3} ${4} ${5}", intl_format: Some("${1} ${2} ${3} ${4} ${5}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2[12467]|3[1-4]|4[134]|5[256]|6[12]|[7-9]1)\\d{7}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:6[89]|7[235-9])\\d{7}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{6}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90[016]\\d{6}", lengths: &[9] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "84[0248]\\d{6}", lengths: &[9] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "878\\d{6}", lengths: &[9] }),
+        (PhoneType::Pager, NumberDesc { pattern: "74[0248]\\d{6}", lengths: &[9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "5[18]\\d{7}", lengths: &[9] }),
+        (PhoneType::Voicemail, NumberDesc { pattern: "860\\d{9}", lengths: &[12] }),
+    ] },
+    // CI
+    Metadata { general: "[02]\\d{9}", lengths: &[10], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d)(\\d{5})", leading_digits: &["2"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{4})", leading_digits: &["0"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2(?:[15]\\d{3}|7(?:2(?:0[23]|1[2357]|2[245]|3[45]|4[3-5])|3(?:06|1[69]|[2-6]7)))\\d{5}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "0[157]\\d{8}", lengths: &[10] }),
+    ] },
+    // CK
+    Metadata { general: "[2-578]\\d{4}", lengths: &[5], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3})", leading_digits: &["[2-578]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2\\d|3[13-7]|4[1-5])\\d{3}", lengths: &[5] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "[578]\\d{4}", lengths: &[5] }),
+    ] },
+    // CL
+    Metadata { general: "12300\\d{6}|6\\d{9,10}|[2-9]\\d{8}", lengths: &[9, 10, 11], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})", leading_digits: &["1(?:[03-589]|21)|[29]0|78"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{5})(\\d{4})", leading_digits: &["219", "2196"], format: "${1} ${2}", national_format: "(${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["60|809"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["44"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d)(\\d{4})(\\d{4})", leading_digits: &["2[1-36]"], format: "${1} ${2} ${3}", national_format: "(${1}) ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d)(\\d{4})(\\d{4})", leading_digits: &["9(?:10|[2-9])"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["3[2-5]|[47]|5[1-3578]|6[13-57]|8(?:0[1-8]|[1-9])"], format: "${1} ${2} ${3}", national_format: "(${1}) ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3,4})", leading_digits: &["60|8"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})(\\d{4})", leading_digits: &["1"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{2})(\\d{3})", leading_digits: &["60"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2(?:1982[0-6]|3314[05-9])\\d{3}|(?:2(?:1(?:160|962)|3(?:(?:2\\d|50)\\d|3(?:[034679]\\d|1[0-35-9]|2[1-9]|5[0-24-9]|8[0-389])|600)|646[59])|(?:600|80[1-9])\\d\\d|9(?:(?:10[0-2]|7[1-9]\\d)\\d|3(?:[0-57-9]\\d\\d|6(?:0[02-9]|[1-9]\\d))|6(?:[0-8]\\d\\d|9(?:[02-79]\\d|1[05-9]))|9(?:[03-9]\\d\\d|1(?:[0235-9]\\d|4[0-24-9])|2(?:[0-79]\\d|8[0-46-9]))))\\d{4}|(?:22|3[2-5]|[47][1-35]|5[1-3578]|6[13-57]|8[1-9]|9[2458])\\d{7}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "2(?:1982[0-6]|3314[05-9])\\d{3}|(?:2(?:1(?:160|962)|3(?:(?:2\\d|50)\\d|3(?:[034679]\\d|1[0-35-9]|2[1-9]|5[0-24-9]|8[0-389])|600)|646[59])|80[1-8]\\d\\d|9(?:(?:10[0-2]|7[1-9]\\d)\\d|3(?:[0-57-9]\\d\\d|6(?:0[02-9]|[1-9]\\d))|6(?:[0-8]\\d\\d|9(?:[02-79]\\d|1[05-9]))|9(?:[03-9]\\d\\d|1(?:[0235-9]\\d|4[0-24-9])|2(?:[0-79]\\d|8[0-46-9]))))\\d{4}|(?:22|3[2-5]|[47][1-35]|5[1-3578]|6[13-57]|8[1-9]|9[2458])\\d{7}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "(?:123|8)00\\d{6}", lengths: &[9, 11] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "600\\d{7,8}", lengths: &[10, 11] }),
+        (PhoneType::Voip, NumberDesc { pattern: "44\\d{7}", lengths: &[9] }),
+    ] },
+    // CM
+    Metadata { general: "[26]\\d{8}|88\\d{6,7}", lengths: &[8, 9], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["88"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d)(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[26]|88"], format: "${1} ${2} ${3} ${4} ${5}", national_format: "${1} ${2} ${3} ${4} ${5}", intl_format: Some("${1} ${2} ${3} ${4} ${5}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2(?:22|33)\\d{6}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:24[23]|6(?:[25-9]\\d|40))\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "88\\d{6,7}", lengths: &[8, 9] }),
+    ] },
+    // CN
+    Metadata { general: "(?:(?:1[03-689]|2\\d)\\d\\d|6)\\d{8}|1\\d{10}|[126]\\d{6}(?:\\d(?:\\d{2})?)?|86\\d{5,6}|(?:[3-579]\\d|8[0-57-9])\\d{5,9}", lengths: &[7, 8, 9, 10, 11, 12], leading_digits: None, national_prefix_for_parsing: Some("(1(?:[12]\\d|79)\\d\\d)|0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{5,6})", leading_digits: &["1(?:00|2[13])|9[56]", "1(?:00|2(?:1|39))|9[56]", "1(?:00|2(?:1|395))|9[56]"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{5,6})", leading_digits: &["1(?:0|23)|781|[1-9]12", "1(?:0|23)|7812|[1-9]123", "1(?:0|23(?:[0-8]|9[0-46-9]))|78123|[1-9]123"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{2})(\\d{5,6})", leading_digits: &["(?:10|2[0-57-9])[19]|3(?:[157]|35|49|9[1-68])|4(?:1[124-9]|2[179]|6[47-9]|7|8[23])|5(?:[1357]|2[37]|4[36]|6[1-46]|80)|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:07|1[236-8]|2[5-7]|[37]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|3|4[13]|5[1-5]|7[0-79]|9[0-35-9])|(?:4[35]|59|85)[1-9]", "(?:10|2[0-57-9])(?:1[02]|9[56])|8078|(?:3(?:[157]\\d|35|49|9[1-68])|4(?:1[124-9]|2[179]|[35][1-9]|6[47-9]|7\\d|8[23])|5(?:[1357]\\d|2[37]|4[36]|6[1-46]|80|9[1-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]\\d|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:1[236-8]|2[5-7]|[37]\\d|5[1-9]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|3\\d|4[13]|5[1-5]|7[0-79]|9[0-35-9]))1", "10(?:1(?:0|23)|9[56])|2[0-57-9](?:1(?:00|23)|9[56])|80781|(?:3(?:[157]\\d|35|49|9[1-68])|4(?:1[124-9]|2[179]|[35][1-9]|6[47-9]|7\\d|8[23])|5(?:[1357]\\d|2[37]|4[36]|6[1-46]|80|9[1-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]\\d|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:1[236-8]|2[5-7]|[37]\\d|5[1-9]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|3\\d|4[13]|5[1-5]|7[0-79]|9[0-35-9]))12", "10(?:1(?:0|23)|9[56])|2[0-57-9](?:1(?:00|23)|9[56])|807812|(?:3(?:[157]\\d|35|49|9[1-68])|4(?:1[124-9]|2[179]|[35][1-9]|6[47-9]|7\\d|8[23])|5(?:[1357]\\d|2[37]|4[36]|6[1-46]|80|9[1-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]\\d|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:1[236-8]|2[5-7]|[37]\\d|5[1-9]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|3\\d|4[13]|5[1-5]|7[0-79]|9[0-35-9]))123", "10(?:1(?:0|23)|9[56])|2[0-57-9](?:1(?:00|23)|9[56])|(?:3(?:[157]\\d|35|49|9[1-68])|4(?:1[124-9]|2[179]|[35][1-9]|6[47-9]|7\\d|8[23])|5(?:[1357]\\d|2[37]|4[36]|6[1-46]|80|9[1-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]\\d|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:078|1[236-8]|2[5-7]|[37]\\d|5[1-9]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|3\\d|4[13]|5[1-5]|7[0-79]|9[0-35-9]))123"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[1-9]", "1[1-9]|26|[3-9]|(?:10|2[0-57-9])(?:[0-8]|9[0-47-9])", "1(?:0(?:[02-8]|1(?:[013-9]|2[0-24-9])|9[0-47-9])|[1-9])|2(?:[0-57-9](?:[02-8]|1(?:0[1-9]|[13-9]|2[0-24-9])|9[0-47-9])|6)|[3-9]", "1(?:0(?:[02-8]|1(?:[013-9]|2[0-24-9])|9[0-47-9])|[1-9])|2(?:[0-57-9](?:[02-8]|1(?:0[1-9]|[13-9]|2[0-24-9])|9[0-47-9])|6)|3(?:[0268]|3[0-46-9]|4[0-8]|9[079])|4(?:[049]|1[03]|2[02-68]|[35]0|6[0-356]|8[014-9])|5(?:0|2[0-24-689]|4[0-2457-9]|6[057-9]|8[1-9]|90)|6(?:[0-24578]|3[06-9]|6[14-79]|9[03-9])|7(?:0[02-9]|2[0135-79]|3[23]|4[0-27-9]|6[1457]|8)|8(?:[046]|1[01459]|2[0-489]|50|8[0-2459]|9[09])|9(?:0[0457]|1[08]|[268]|4[024-9]|5[06-9]|78|94)|(?:3(?:[157]\\d|35|49|9[1-68])|4(?:1[124-9]|2[179]|[35][1-9]|6[47-9]|7\\d|8[23])|5(?:[1357]\\d|2[37]|4[36]|6[1-46]|80|9[1-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]\\d|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:1[236-8]|2[5-7]|[37]\\d|5[1-9]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|3\\d|4[13]|5[1-5]|7[0-79]|9[0-35-9]))(?:[02-9]|1(?:[013-9]|2[0-24-9]))", "1(?:0(?:[02-8]|1(?:[013-9]|2[0-24-9])|9[0-47-9])|[1-9])|2(?:[0-57-9](?:[02-8]|1(?:0[1-9]|[13-9]|2[0-24-9])|9[0-47-9])|6)|3(?:[0268]|3[0-46-9]|4[0-8]|9[079])|4(?:[049]|1[03]|2[02-68]|[35]0|6[0-356]|8[014-9])|5(?:0|2[0-24-689]|4[0-2457-9]|6[057-9]|8[1-9]|90)|6(?:[0-24578]|3[06-9]|6[14-79]|9[03-9])|7(?:0[02-9]|2[0135-79]|3[23]|4[0-27-9]|6[1457]|8)|8(?:0(?:[0-689]|7[0-79])|1[01459]|2[0-489]|[46]|50|8[0-2459]|9[09])|9(?:0[0457]|1[08]|[268]|4[024-9]|5[06-9]|78|94)|(?:3(?:[157]\\d|35|49|9[1-68])|4(?:1[124-9]|2[179]|[35][1-9]|6[47-9]|7\\d|8[23])|5(?:[1357]\\d|2[37]|4[36]|6[1-46]|80|9[1-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]\\d|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:078|1[236-8]|2[5-7]|[37]\\d|5[1-9]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|3\\d|4[13]|5[1-5]|7[0-79]|9[0-35-9]))(?:[02-9]|1(?:[013-9]|2[0-24-9]))"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["16[08]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d{3})(\\d{5,6})", leading_digits: &["3(?:[157]|35|49|9[1-68])|4(?:[17]|2[179]|6[47-9]|8[23])|5(?:[1357]|2[37]|4[36]|6[1-46]|80)|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:1[236-8]|2[5-7]|[37]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|[379]|4[13]|5[1-5])|(?:4[35]|59|85)[1-9]", "(?:3(?:[157]\\d|35|49|9[1-68])|4(?:[17]\\d|2[179]|[35][1-9]|6[47-9]|8[23])|5(?:[1357]\\d|2[37]|4[36]|6[1-46]|80|9[1-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]\\d|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:1[236-8]|2[5-7]|[37]\\d|5[1-9]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|[379]\\d|4[13]|5[1-5]))[19]", "85[23](?:10|95)|(?:3(?:[157]\\d|35|49|9[1-68])|4(?:[17]\\d|2[179]|[35][1-9]|6[47-9]|8[23])|5(?:[1357]\\d|2[37]|4[36]|6[1-46]|80|9[1-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]\\d|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:1[236-8]|2[5-7]|[37]\\d|5[14-9]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|[379]\\d|4[13]|5[1-5]))(?:10|9[56])", "85[23](?:100|95)|(?:3(?:[157]\\d|35|49|9[1-68])|4(?:[17]\\d|2[179]|[35][1-9]|6[47-9]|8[23])|5(?:[1357]\\d|2[37]|4[36]|6[1-46]|80|9[1-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]\\d|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:1[236-8]|2[5-7]|[37]\\d|5[14-9]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|[379]\\d|4[13]|5[1-5]))(?:100|9[56])"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[1-9]", "1(?:0(?:[02-8]|1[1-9]|9[0-47-9])|[1-9])|2(?:[0-57-9](?:[0-8]|9[0-47-9])|6)|[3-9]", "1(?:0(?:[02-8]|1[1-9]|9[0-47-9])|[1-9])|26|3(?:[0268]|4[0-8]|9[079])|4(?:[049]|2[02-68]|[35]0|6[0-356]|8[014-9])|5(?:0|2[0-24-689]|4[0-2457-9]|6[057-9]|8[1-9]|90)|6(?:[0-24578]|3[06-9]|6[14-79]|9[03-9])|7(?:0[02-9]|2[0135-79]|3[23]|4[0-27-9]|6[1457]|8)|8(?:[046]|1[01459]|2[0-489]|5(?:0|[23][0-8])|8[0-2459]|9[09])|9(?:0[0457]|1[08]|[268]|4[024-9]|5[06-9])|(?:33|85[23]9)[0-46-9]|(?:2[0-57-9]|3(?:[157]\\d|35|49|9[1-68])|4(?:[17]\\d|2[179]|[35][1-9]|6[47-9]|8[23])|5(?:[1357]\\d|2[37]|4[36]|6[1-46]|80|9[1-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]\\d|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:1[236-8]|2[5-7]|[37]\\d|5[14-9]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|[379]\\d|4[13]|5[1-5]))(?:[0-8]|9[0-47-9])", "1(?:0[02-8]|[1-9])|2(?:[0-57-9][0-8]|6)|3(?:[0268]|3[0-46-9]|4[0-8]|9[079])|4(?:[049]|2[02-68]|[35]0|6[0-356]|8[014-9])|5(?:0|2[0-24-689]|4[0-2457-9]|6[057-9]|90)|6(?:[0-24578]|3[06-9]|6[14-79]|9[03-9])|7(?:0[02-9]|2[0135-79]|3[23]|4[0-27-9]|6[1457]|8)|8(?:[046]|1[01459]|2[0-489]|5(?:0|[23](?:[02-8]|1[1-9]|9[0-46-9]))|8[0-2459]|9[09])|9(?:0[0457]|1[08]|[268]|4[024-9]|5[06-9])|(?:10|2[0-57-9])9[0-47-9]|(?:101|58|85[23]10)[1-9]|(?:3(?:[157]\\d|35|49|9[1-68])|4(?:[17]\\d|2[179]|[35][1-9]|6[47-9]|8[23])|5(?:[1357]\\d|2[37]|4[36]|6[1-46]|80|9[1-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]\\d|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:1[236-8]|2[5-7]|[37]\\d|5[14-9]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|[379]\\d|4[13]|5[1-5]))(?:[02-8]|1(?:0[1-9]|[1-9])|9[0-47-9])"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["(?:4|80)0"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{4})(\\d{4})", leading_digits: &["10|2(?:[02-57-9]|1[1-9])", "10|2(?:[02-57-9]|1[1-9])", "10[0-79]|2(?:[02-57-9]|1[1-79])|(?:10|21)8(?:0[1-9]|[1-9])"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["3(?:[3-59]|7[02-68])|4(?:[26-8]|3[3-9]|5[2-9])|5(?:3[03-9]|[468]|7[028]|9[2-46-9])|6|7(?:[0-247]|3[04-9]|5[0-4689]|6[2368])|8(?:[1-358]|9[1-7])|9(?:[013479]|5[1-5])|(?:[34]1|55|79|87)[02-9]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{7,8})", leading_digits: &["9"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})(\\d{4})", leading_digits: &["80"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})(\\d{4})", leading_digits: &["[3-578]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})(\\d{4})", leading_digits: &["1[3-9]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["[12]"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:10(?:[02-79]\\d\\d|[18](?:0[1-9]|[1-9]\\d))|2(?:[02-57-9]\\d{3}|1(?:[18](?:0[1-9]|[1-9]\\d)|[2-79]\\d\\d))|(?:41[03]|8078|9(?:78|94))\\d\\d)\\d{5}|(?:10|2[0-57-9])(?:1(?:00|23)\\d\\d|95\\d{3,4})|(?:41[03]|9(?:78|94))(?:100\\d\\d|95\\d{3,4})|8078123|(?:43[35]|754|851)\\d{7,8}|(?:43[35]|754|851)(?:1(?:00\\d|23)\\d|95\\d{3,4})|(?:3(?:11|7[179])|4(?:[15]1|3[12])|5(?:1\\d|2[37]|3[12]|51|7[13-79]|9[15])|7(?:[39]1|5[57]|6[09])|8(?:71|98))(?:[02-8]\\d{7}|1(?:0(?:0\\d\\d(?:\\d{3})?|[1-9]\\d{5})|[13-9]\\d{6}|2(?:[0-24-9]\\d{5}|3\\d(?:\\d{4})?))|9(?:[0-46-9]\\d{6}|5\\d{3}(?:\\d(?:\\d{2})?)?))|(?:3(?:1[02-9]|35|49|5\\d|7[02-68]|9[1-68])|4(?:1[24-9]|2[179]|3[46-9]|5[2-9]|6[47-9]|7\\d|8[23])|5(?:3[03-9]|4[36]|5[02-9]|6[1-46]|7[028]|80|9[2-46-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[17]\\d|2[248]|3[04-9]|4[3-6]|5[0-3689]|6[2368]|9[02-9])|8(?:1[236-8]|2[5-7]|3\\d|5[2-9]|7[02-9]|8[36-8]|9[1-7])|9(?:0[1-3689]|1[1-79]|3\\d|4[13]|5[1-5]|7[0-79]|9[0-35-9]))(?:[02-8]\\d{6}|1(?:0(?:0\\d\\d(?:\\d{2})?|[1-9]\\d{4})|[13-9]\\d{5}|2(?:[0-24-9]\\d{4}|3\\d(?:\\d{3})?))|9(?:[0-46-9]\\d{5}|5\\d{3,5}))", lengths: &[7, 8, 9, 10, 11] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "1740[0-5]\\d{6}|1(?:[38]\\d|4[57]|[59][0-35-9]|6[25-7]|7[0-35-8])\\d{8}", lengths: &[11] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "(?:(?:10|21)8|8)00\\d{7}", lengths: &[10, 12] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "16[08]\\d{5}", lengths: &[8] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "10(?:10\\d{4}|96\\d{3,4})|400\\d{7}|950\\d{7,8}|(?:2[0-57-9]|3(?:[157]\\d|35|49|9[1-68])|4(?:[17]\\d|2[179]|[35][1-9]|6[47-9]|8[23])|5(?:[1357]\\d|2[37]|4[36]|6[1-46]|80|9[1-9])|6(?:3[1-5]|6[0238]|9[12])|7(?:01|[1579]\\d|2[248]|3[014-9]|4[3-6]|6[023689])|8(?:1[236-8]|2[5-7]|[37]\\d|5[14-9]|8[36-8]|9[1-8])|9(?:0[1-3689]|1[1-79]|[379]\\d|4[13]|5[1-5]))96\\d{3,4}", lengths: &[7, 8, 9, 10, 11] }),
+    ] },
+    // CO
+    Metadata { general: "(?:46|60\\d\\d)\\d{6}|(?:1\\d|[39])\\d{9}", lengths: &[8, 10, 11], leading_digits: None, national_prefix_for_parsing: Some("0([3579]|4(?:[14]4|56))?"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["46"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{7})", leading_digits: &["6|90"], format: "${1} ${2}", national_format: "(${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{7})", leading_digits: &["3[0-357]|9[14]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{7})", leading_digits: &["1"], format: "${1}-${2}-${3}", national_format: "0${1}-${2}-${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "601055(?:[0-4]\\d|50)\\d\\d|6010(?:[0-4]\\d|5[0-4])\\d{4}|(?:46|60(?:[18][1-9]|[24-7][2-9]))\\d{6}", lengths: &[8, 10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "333301[0-5]\\d{3}|3333(?:00|2[5-9]|[3-9]\\d)\\d{4}|(?:3(?:(?:0[0-5]|1\\d|5[01]|70)\\d|2(?:[0-3]\\d|4[1-9])|3(?:00|3[0-24-9]))|9(?:101|408))\\d{6}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "1800\\d{7}", lengths: &[11] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "(?:19(?:0[01]|4[78])|901)\\d{7}", lengths: &[10, 11] }),
+    ] },
+    // CR
+    Metadata { general: "(?:8\\d|90)\\d{8}|(?:[24-8]\\d{3}|3005)\\d{4}", lengths: &[8, 10], leading_digits: None, national_prefix_for_parsing: Some("(19(?:0[0-2468]|1[09]|20|66|77|99))"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[2-7]|8[3-9]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["[89]"], format: "${1}-${2}-${3}", national_format: "${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "210[7-9]\\d{4}|2(?:[024-7]\\d|1[1-9])\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:3005\\d|6500[01])\\d{3}|(?:5[07]|6[0-4]|7[0-3]|8[3-9])\\d{6}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{7}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90[059]\\d{7}", lengths: &[10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "(?:210[0-6]|4\\d{3}|5100)\\d{4}", lengths: &[8] }),
+    ] },
+    // CU
+    Metadata { general: "(?:[2-7]|8\\d\\d)\\d{7}|[2-47]\\d{6}|[34]\\d{5}", lengths: &[6, 7, 8, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{4,6})", leading_digits: &["2[1-4]|[34]"], format: "${1} ${2}", national_format: "(0${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d)(\\d{6,7})", leading_digits: &["7"], format: "${1} ${2}", national_format: "(0${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d)(\\d{7})", leading_digits: &["[56]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{7})", leading_digits: &["8"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:3[23]|4[89])\\d{4,6}|(?:31|4[36]|8(?:0[25]|78)\\d)\\d{6}|(?:2[1-4]|4[1257]|7\\d)\\d{5,6}", lengths: &[6, 7, 8, 10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:5\\d|6[2-4])\\d{6}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{7}", lengths: &[10] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "807\\d{7}", lengths: &[10] }),
+    ] },
+    // CV
+    Metadata { general: "(?:[2-59]\\d\\d|800)\\d{4}", lengths: &[7], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["[2-589]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2(?:2[1-7]|3[0-8]|4[12]|5[1256]|6\\d|7[1-3]|8[1-5])\\d{4}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:36|5[1-389]|9\\d)\\d{5}", lengths: &[7] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{4}", lengths: &[7] }),
+        (PhoneType::Voip, NumberDesc { pattern: "(?:3[3-5]|4[356])\\d{5}", lengths: &[7] }),
+    ] },
+    // CW
+    Metadata { general: "(?:[34]1|60|(?:7|9\\d)\\d)\\d{5}", lengths: &[7, 8], leading_digits: Some("[69]"), national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[3467]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{4})", leading_digits: &["9[4-8]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "9(?:4(?:3[0-5]|4[14]|6\\d)|50\\d|7(?:2[014]|3[02-9]|4[4-9]|6[357]|77|8[7-9])|8(?:3[39]|[46]\\d|7[01]|8[57-9]))\\d{4}", lengths: &[7, 8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "953[01]\\d{4}|9(?:5[12467]|6[5-9])\\d{5}", lengths: &[7, 8] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "60[0-2]\\d{4}", lengths: &[7] }),
+        (PhoneType::Pager, NumberDesc { pattern: "955\\d{5}", lengths: &[8] }),
+    ] },
+    // CX
+    Metadata { general: "1(?:[0-79]\\d{8}(?:\\d{2})?|8[0-24-9]\\d{7})|[148]\\d{8}|1\\d{5,7}", lengths: &[6, 7, 8, 9, 10, 12], leading_digits: None, national_prefix_for_parsing: Some("([59]\\d{7})$|0"), national_prefix_transform_rule: Some("8$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "8(?:51(?:0(?:01|30|59|88)|1(?:17|46|75)|2(?:22|35))|91(?:00[6-9]|1(?:[28]1|49|78)|2(?:09|63)|3(?:12|26|75)|4(?:56|97)|64\\d|7(?:0[01]|1[0-2])|958))\\d{3}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "4(?:79[01]|83[0-36-9]|95[0-3])\\d{5}|4(?:[0-36]\\d|4[047-9]|[58][0-24-9]|7[02-8]|9[0-47-9])\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "180(?:0\\d{3}|2)\\d{3}", lengths: &[7, 10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "190[0-26]\\d{6}", lengths: &[10] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "13(?:00\\d{6}(?:\\d{2})?|45[0-4]\\d{3})|13\\d{4}", lengths: &[6, 8, 10, 12] }),
+        (PhoneType::Voip, NumberDesc { pattern: "14(?:5(?:1[0458]|[23][458])|71\\d)\\d{4}", lengths: &[9] }),
+    ] },
+    // CY
+    Metadata { general: "(?:[279]\\d|[58]0)\\d{6}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{6})", leading_digits: &["[257-9]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2[2-6]\\d{6}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "9(?:10|[4-79]\\d)\\d{5}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{5}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90[09]\\d{5}", lengths: &[8] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "80[1-9]\\d{5}", lengths: &[8] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "700\\d{5}", lengths: &[8] }),
+        (PhoneType::Uan, NumberDesc { pattern: "(?:50|77)\\d{6}", lengths: &[8] }),
+    ] },
+    // CZ
+    Metadata { general: "(?:[2-578]\\d|60)\\d{7}|9\\d{8,11}", lengths: &[9, 10, 11, 12], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[2-8]|9[015-7]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})(\\d{2})", leading_digits: &["96"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["9"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["9"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2\\d|3[1257-9]|4[16-9]|5[13-9])\\d{7}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "7(?:060\\d|19(?:[0-5]\\d|6[0-6]))\\d{4}|(?:60[1-8]|7(?:0[2-5]|[2379]\\d))\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{6}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "9(?:0[05689]|76)\\d{6}", lengths: &[9] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "8[134]\\d{7}", lengths: &[9] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "70[01]\\d{6}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "9[17]0\\d{6}", lengths: &[9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "9(?:5\\d|7[2-4])\\d{6}", lengths: &[9] }),
+        (PhoneType::Voicemail, NumberDesc { pattern: "9(?:3\\d{9}|6\\d{7,10})", lengths: &[9, 10, 11, 12] }),
+    ] },
+    // DE
+    Metadata { general: "[2579]\\d{5,14}|49(?:[34]0|69|8\\d)\\d\\d?|49(?:37|49|60|7[089]|9\\d)\\d{1,3}|49(?:2[024-9]|3[2-689]|7[1-7])\\d{1,8}|(?:1|[368]\\d|4[0-8])\\d{3,13}|49(?:[015]\\d|2[13]|31|[46][1-8])\\d{1,9}", lengths: &[4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3,13})", leading_digits: &["3[02]|40|[68]9"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{6})", leading_digits: &["227", "2277"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{3})(\\d{3,12})", leading_digits: &["2(?:0[1-389]|1[124]|2[18]|3[14])|3(?:[35-9][15]|4[015])|906|(?:2[4-9]|4[2-9]|[579][1-9]|[68][1-8])1", "2(?:0[1-389]|12[0-8])|3(?:[35-9][15]|4[015])|906|2(?:[13][14]|2[18])|(?:2[4-9]|4[2-9]|[579][1-9]|[68][1-8])1"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{2,11})", leading_digits: &["[24-6]|3(?:[3569][02-46-9]|4[2-4679]|7[2-467]|8[2-46-8])|70[2-8]|8(?:0[2-9]|[1-8])|90[7-9]|[79][1-9]", "[24-6]|3(?:3(?:0[1-467]|2[127-9]|3[124578]|7[1257-9]|8[1256]|9[145])|4(?:2[135]|4[13578]|9[1346])|5(?:0[14]|2[1-3589]|6[1-4]|7[13468]|8[13568])|6(?:2[1-489]|3[124-6]|6[13]|7[12579]|8[1-356]|9[135])|7(?:2[1-7]|4[145]|6[1-5]|7[1-4])|8(?:21|3[1468]|6|7[1467]|8[136])|9(?:0[12479]|2[1358]|4[134679]|6[1-9]|7[136]|8[147]|9[1468]))|70[2-8]|8(?:0[2-9]|[1-8])|90[7-9]|[79][1-9]|3[68]4[1347]|3(?:47|60)[1356]|3(?:3[46]|46|5[49])[1246]|3[4579]3[1357]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["138"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{5})(\\d{2,10})", leading_digits: &["3"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{5,11})", leading_digits: &["181"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d)(\\d{4,10})", leading_digits: &["1(?:3|80)|9"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{7,8})", leading_digits: &["1[67]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{7,12})", leading_digits: &["8"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{5})(\\d{6})", leading_digits: &["185", "1850", "18500"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})(\\d{4})", leading_digits: &["7"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{7})", leading_digits: &["18[68]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{7})", leading_digits: &["15[1279]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{5})(\\d{6})", leading_digits: &["15[03568]", "15(?:[0568]|3[13])"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{8})", leading_digits: &["18"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{7,8})", leading_digits: &["1(?:6[023]|7)"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{2})(\\d{7})", leading_digits: &["15[279]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{8})", leading_digits: &["15"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "32\\d{9,11}|49[1-6]\\d{10}|322\\d{6}|49[0-7]\\d{3,9}|(?:[34]0|[68]9)\\d{3,13}|(?:2(?:0[1-689]|[1-3569]\\d|4[0-8]|7[1-7]|8[0-7])|3(?:[3569]\\d|4[0-79]|7[1-7]|8[1-8])|4(?:1[02-9]|[2-48]\\d|5[0-6]|6[0-8]|7[0-79])|5(?:0[2-8]|[124-6]\\d|[38][0-8]|[79][0-7])|6(?:0[02-9]|[1-358]\\d|[47][0-8]|6[1-9])|7(?:0[2-8]|1[1-9]|[27][0-7]|3\\d|[4-6][0-8]|8[0-5]|9[013-7])|8(?:0[2-9]|1[0-79]|2\\d|3[0-46-9]|4[0-6]|5[013-9]|6[1-8]|7[0-8]|8[0-24-6])|9(?:0[6-9]|[1-4]\\d|[589][0-7]|6[0-8]|7[0-467]))\\d{3,12}", lengths: &[5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "1(?:(?:5(?:[0-25-9]\\d\\d|3(?:10|33))|7[26-9]\\d\\d)\\d{6}|6[023]\\d{7,8})|17\\d{8}", lengths: &[10, 11] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{7,12}", lengths: &[10, 11, 12, 13, 14, 15] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "(?:137[7-9]|900(?:[135]|9\\d))\\d{6}", lengths: &[10, 11] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "180\\d{5,11}|13(?:7[1-6]\\d\\d|8)\\d{4}", lengths: &[7, 8, 9, 10, 11, 12, 13, 14] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "700\\d{8}", lengths: &[11] }),
+        (PhoneType::Pager, NumberDesc { pattern: "16(?:4\\d{1,10}|[89]\\d{1,11})", lengths: &[4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14] }),
+        (PhoneType::Uan, NumberDesc { pattern: "18(?:1\\d{5,11}|[2-9]\\d{8})", lengths: &[8, 9, 10, 11, 12, 13, 14] }),
+        (PhoneType::Voicemail, NumberDesc { pattern: "1(?:6(?:013|255|399)|7(?:(?:[015]1|[69]3)3|[2-4]55|[78]99))\\d{7,8}|15(?:(?:[03-68]00|113)\\d|2\\d55|7\\d99|9\\d33)\\d{7}", lengths: &[12, 13] }),
+    ] },
+    // DJ
+    Metadata { general: "(?:2\\d|77)\\d{6}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[27]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2(?:1[2-5]|7[45])\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "77\\d{6}", lengths: &[8] }),
+    ] },
+    // DK
+    Metadata { general: "[2-9]\\d{7}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[2-9]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2(?:[0-59][1-9]|[6-8]\\d)|3(?:[0-3][1-9]|4[13]|5[1-58]|6[1347-9]|7\\d|8[1-8]|9[1-79])|4(?:[0-25][1-9]|[34][2-9]|6[13-579]|7[13579]|8[1-47]|9[127])|5(?:[0-36][1-9]|4[146-9]|5[3-57-9]|7[568]|8[1-358]|9[1-69])|6(?:[0135][1-9]|2[1-68]|4[2-8]|6[1689]|[78]\\d|9[15689])|7(?:[0-69][1-9]|7[3-9]|8[147])|8(?:[16-9][1-9]|2[1-58])|9(?:[1-47-9][1-9]|6\\d))\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:2[6-8]|37|6[78]|96)\\d{6}|(?:2[0-59]|3[0-689]|[457]\\d|6[0-69]|8[126-9]|9[1-47-9])[1-9]\\d{5}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80\\d{6}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90\\d{6}", lengths: &[8] }),
+    ] },
+    // DM
+    Metadata { general: "(?:[58]\\d\\d|767|900)\\d{7}", lengths: &[10], leading_digits: Some("767"), national_prefix_for_parsing: Some("([2-7]\\d{6})$|1"), national_prefix_transform_rule: Some("767$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "767(?:2(?:55|66)|4(?:2[01]|4[0-25-9])|50[0-4])\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "767(?:2(?:[2-4689]5|7[5-7])|31[5-7]|61[1-8]|70[1-6])\\d{4}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+    ] },
+    // DO
+    Metadata { general: "(?:[58]\\d\\d|900)\\d{7}", lengths: &[10], leading_digits: Some("8001|8[024]9"), national_prefix_for_parsing: Some("1"), national_prefix_transform_rule: None, formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "8(?:[04]9[2-9]\\d\\d|29(?:2(?:[0-59]\\d|6[04-9]|7[0-27]|8[0237-9])|3(?:[0-35-9]\\d|4[7-9])|[45]\\d\\d|6(?:[0-27-9]\\d|[3-5][1-9]|6[0135-8])|7(?:0[013-9]|[1-37]\\d|4[1-35689]|5[1-4689]|6[1-57-9]|8[1-79]|9[1-8])|8(?:0[146-9]|1[0-48]|[248]\\d|3[1-79]|5[01589]|6[013-68]|7[124-8]|9[0-8])|9(?:[0-24]\\d|3[02-46-9]|5[0-79]|60|7[0169]|8[57-9]|9[02-9])))\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "8[024]9[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800(?:14|[2-9]\\d)\\d{5}|8[024]9[01]\\d{6}|8(?:33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+    ] },
+    // DZ
+    Metadata { general: "(?:[1-4]|[5-79]\\d|80)\\d{7}", lengths: &[8, 9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[1-4]"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["9"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[5-8]"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "9619\\d{5}|(?:1\\d|2[013-79]|3[0-8]|4[013-689])\\d{6}", lengths: &[8, 9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:5(?:4[0-29]|5\\d|6[0-3])|6(?:[569]\\d|7[0-6])|7[7-9]\\d)\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{6}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "80[3-689]1\\d{5}", lengths: &[9] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "80[12]1\\d{5}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "98[23]\\d{6}", lengths: &[9] }),
+    ] },
+    // EC
+    Metadata { general: "1\\d{9,10}|(?:[2-7]|9\\d)\\d{7}", lengths: &[8, 9, 10, 11], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[2-7]"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{4})", leading_digits: &["[2-7]"], format: "${1} ${2}-${3}", national_format: "(0${1}) ${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["9"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})(\\d{3,4})", leading_digits: &["1"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "[2-7][2-7]\\d{6}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "964[0-2]\\d{5}|9(?:39|[57][89]|6[0-36-9]|[89]\\d)\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "1800\\d{7}|1[78]00\\d{6}", lengths: &[10, 11] }),
+        (PhoneType::Voip, NumberDesc { pattern: "[2-7]890\\d{4}", lengths: &[8] }),
+    ] },
+    // EE
+    Metadata { general: "8\\d{9}|[4578]\\d{7}|(?:[3-8]\\d|90)\\d{5}", lengths: &[7, 8, 10], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[369]|4[3-8]|5(?:[0-2]|5[0-478]|6[45])|7[1-9]|88", "[369]|4[3-8]|5(?:[02]|1(?:[0-8]|95)|5[0-478]|6(?:4[0-4]|5[1-589]))|7[1-9]|88"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3,4})", leading_digits: &["[45]|8(?:00|[1-49])", "[45]|8(?:00[1-9]|[1-49])"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{4})", leading_digits: &["7"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})(\\d{3})", leading_digits: &["8"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:3[23589]|4[3-8]|6\\d|7[1-9]|88)\\d{5}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:5\\d{5}|8(?:1(?:0(?:0(?:00|[178]\\d)|[3-9]\\d\\d)|(?:1(?:0[2-6]|1\\d)|[2-79]\\d\\d)\\d)|2(?:0(?:0(?:00|4\\d)|(?:19|[2-7]\\d)\\d)|(?:(?:[124-69]\\d|3[5-9])\\d|7(?:[0-79]\\d|8[013-9])|8(?:[2-6]\\d|7[01]))\\d)|[349]\\d{4}))\\d\\d|5(?:(?:[02]\\d|5[0-478])\\d|1(?:[0-8]\\d|95)|6(?:4[0-4]|5[1-589]))\\d{3}", lengths: &[7, 8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800(?:(?:0\\d\\d|1)\\d|[2-9])\\d{3}", lengths: &[7, 8, 10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "(?:40\\d\\d|900)\\d{4}", lengths: &[7, 8] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "70[0-2]\\d{5}", lengths: &[8] }),
+    ] },
+    // EG
+    Metadata { general: "[189]\\d{8,9}|[24-6]\\d{8}|[135]\\d{7}", lengths: &[8, 9, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{7,8})", leading_digits: &["[23]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{6,7})", leading_digits: &["1[35]|[4-6]|8[2468]|9[235-7]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["[89]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{8})", leading_digits: &["1"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "13[23]\\d{6}|(?:15|57)\\d{6,7}|(?:2\\d|3|4[05-8]|5[05]|6[24-689]|8[2468]|9[235-7])\\d{7}", lengths: &[8, 9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "1[0-25]\\d{8}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{7}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900\\d{7}", lengths: &[10] }),
+    ] },
+    // EH
+    Metadata { general: "[5-8]\\d{8}", lengths: &[9], leading_digits: Some("528[89]"), national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "528[89]\\d{5}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:6(?:[0-79]\\d|8[0-247-9])|7(?:[016-8]\\d|2[0-8]|5[0-5]))\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[0-7]\\d{6}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "89\\d{7}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "(?:592(?:4[0-2]|93)|80[89]\\d\\d)\\d{4}", lengths: &[9] }),
+    ] },
+    // ER
+    Metadata { general: "[178]\\d{6}", lengths: &[7], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{3})", leading_digits: &["[178]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:1(?:1[12568]|[24]0|55|6[146])|8\\d\\d)\\d{4}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:17[1-3]|7\\d\\d)\\d{4}", lengths: &[7] }),
+    ] },
+    // ES
+    Metadata { general: "[5-9]\\d{8}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})", leading_digits: &["905"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{6})", leading_digits: &["[79]9"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[89]00"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[5-9]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "96906(?:0[0-8]|1[1-9]|[2-9]\\d)\\d\\d|9(?:69(?:0[0-57-9]|[1-9]\\d)|73(?:[0-8]\\d|9[1-9]))\\d{4}|(?:8(?:[1356]\\d|[28][0-8]|[47][1-9])|9(?:[135]\\d|[268][0-8]|4[1-9]|7[124-9]))\\d{6}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "96906(?:09|10)\\d\\d|(?:590(?:10[0-2]|600)|97390\\d)\\d{3}|(?:6\\d|7[1-48])\\d{7}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "[89]00\\d{6}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "80[367]\\d{6}", lengths: &[9] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "90[12]\\d{6}", lengths: &[9] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "70\\d{7}", lengths: &[9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "51\\d{7}", lengths: &[9] }),
+    ] },
+    // ET
+    Metadata { general: "(?:11|[2-579]\\d)\\d{7}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["[1-579]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:11(?:[124]\\d\\d|3(?:[0-79]\\d|8[0-7])|5(?:[02-9]\\d|1[0-57-9])|6(?:[02-79]\\d|1[0-57-9]|8[0-8]))|2(?:2(?:11[1-9]|22[0-7]|33\\d|44[1467]|66[1-68])|5(?:11[124-6]|33[2-8]|44[1467]|55[14]|66[1-3679]|77[124-79]|880))|3(?:3(?:11[0-46-8]|(?:22|55)[0-6]|33[0134689]|44[04]|66[01467])|4(?:44[0-8]|55[0-69]|66[0-3]|77[1-5]))|4(?:6(?:119|22[0-24-7]|33[1-5]|44[13-69]|55[14-689]|660|88[1-4])|7(?:(?:11|22)[1-9]|33[13-7]|44[13-6]|55[1-689]))|5(?:7(?:227|55[05]|(?:66|77)[14-8])|8(?:11[149]|22[013-79]|33[0-68]|44[013-8]|550|66[1-5]|77\\d)))\\d{4}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "700[1-9]\\d{5}|(?:7(?:0[1-9]|1[0-8]|2[1-35-79]|3\\d|77|86|99)|9\\d\\d)\\d{6}", lengths: &[9] }),
+    ] },
+    // FI
+    Metadata { general: "[1-35689]\\d{4}|7\\d{10,11}|(?:[124-7]\\d|3[0-46-9])\\d{8}|[1-9]\\d{5,8}", lengths: &[5, 6, 7, 8, 9, 10, 11, 12], leading_digits: Some("1[03-79]|[2-9]"), national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{5})", leading_digits: &["75[12]"], format: "${1}", national_format: "0${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{5})", leading_digits: &["20[2-59]"], format: "${1}", national_format: "0${1}", intl_format: Some("${1}") },
+        NumberFormat { pattern: "(\\d{6})", leading_digits: &["11"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{3})(\\d{3,7})", leading_digits: &["(?:[1-3]0|[68])0|70[07-9]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{4,8})", leading_digits: &["[14]|2[09]|50|7[135]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{6,10})", leading_digits: &["7"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d)(\\d{4,9})", leading_digits: &["(?:19|[2568])[1-8]|3(?:0[1-9]|[1-9])|9"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "1[3-7][1-8]\\d{3,6}|(?:19[1-8]|[23568][1-8]\\d|9(?:00|[1-8]\\d))\\d{2,6}", lengths: &[5, 6, 7, 8, 9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "4946\\d{2,6}|(?:4[0-8]|50)\\d{4,8}", lengths: &[6, 7, 8, 9, 10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{4,6}", lengths: &[7, 8, 9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "[67]00\\d{5,6}", lengths: &[8, 9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "20\\d{4,8}|60[12]\\d{5,6}|7(?:099\\d{4,5}|5[03-9]\\d{3,7})|20[2-59]\\d\\d|(?:606|7(?:0[78]|1|3\\d))\\d{7}|(?:10|29|3[09]|70[1-5]\\d)\\d{4,8}", lengths: &[5, 6, 7, 8, 9, 10, 11, 12] }),
+    ] },
+    // FJ
+    Metadata { general: "45\\d{5}|(?:0800\\d|[235-9])\\d{6}", lengths: &[7, 11], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[235-9]|45"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})(\\d{4})", leading_digits: &["0"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "603\\d{4}|(?:3[0-5]|6[25-7]|8[58])\\d{5}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:[279]\\d|45|5[01568]|8[034679])\\d{5}", lengths: &[7] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "0800\\d{7}", lengths: &[11] }),
+    ] },
+    // FK
+    Metadata { general: "[2-7]\\d{4}", lengths: &[5], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "[2-47]\\d{4}", lengths: &[5] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "[56]\\d{4}", lengths: &[5] }),
+    ] },
+    // FM
+    Metadata { general: "(?:[39]\\d\\d|820)\\d{4}", lengths: &[7], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[389]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "31(?:00[67]|208|309)\\d\\d|(?:3(?:[2357]0[1-9]|602|804|905)|(?:820|9[2-6]\\d)\\d)\\d{3}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "31(?:00[67]|208|309)\\d\\d|(?:3(?:[2357]0[1-9]|602|804|905)|(?:820|9[2-7]\\d)\\d)\\d{3}", lengths: &[7] }),
+    ] },
+    // FO
+    Metadata { general: "[2-9]\\d{5}", lengths: &[6], leading_digits: None, national_prefix_for_parsing: Some("(10(?:01|[12]0|88))"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{6})", leading_digits: &["[2-9]"], format: "${1}", national_format: "${1}", intl_format: Some("${1}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:20|[34]\\d|8[19])\\d{4}", lengths: &[6] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:[27][1-9]|5\\d|9[16])\\d{4}", lengths: &[6] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[257-9]\\d{3}", lengths: &[6] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90(?:[13-5][15-7]|2[125-7]|9\\d)\\d\\d", lengths: &[6] }),
+        (PhoneType::Voip, NumberDesc { pattern: "(?:6[0-36]|88)\\d{4}", lengths: &[6] }),
+    ] },
+    // FR
+    Metadata { general: "[1-9]\\d{8}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})", leading_digits: &["10"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})", leading_digits: &["1"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["8"], format: "${1} ${2} ${3} ${4}", national_format: "0 ${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d)(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[1-79]"], format: "${1} ${2} ${3} ${4} ${5}", national_format: "0${1} ${2} ${3} ${4} ${5}", intl_format: Some("${1} ${2} ${3} ${4} ${5}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:26[013-9]|59[1-35-9])\\d{6}|(?:[13]\\d|2[0-57-9]|4[1-9]|5[0-8])\\d{7}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:6(?:[0-24-8]\\d|3[0-8]|9[589])|7[3-9]\\d)\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[0-5]\\d{6}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "836(?:0[0-36-9]|[1-9]\\d)\\d{4}|8(?:1[2-9]|2[2-47-9]|3[0-57-9]|[569]\\d|8[0-35-9])\\d{6}", lengths: &[9] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "8(?:1[01]|2[0156]|4[024]|84)\\d{6}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "9\\d{8}", lengths: &[9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "80[6-9]\\d{6}", lengths: &[9] }),
+    ] },
+    // GA
+    Metadata { general: "(?:[067]\\d|11)\\d{6}|[2-7]\\d{6}", lengths: &[7, 8], leading_digits: None, national_prefix_for_parsing: Some("0(11\\d{6}|60\\d{6}|61\\d{6}|6[256]\\d{6}|7[467]\\d{6})"), national_prefix_transform_rule: Some("$1"), formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[2-7]"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["0"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["11|[67]"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "[01]1\\d{6}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:(?:0[2-7]|7[467])\\d|6(?:0[0-4]|10|[256]\\d))\\d{5}|[2-7]\\d{6}", lengths: &[7, 8] }),
+    ] },
+    // GB
+    Metadata { general: "[1-357-9]\\d{9}|[18]\\d{8}|8\\d{6}", lengths: &[7, 9, 10], leading_digits: None, national_prefix_for_parsing: Some("0|180020"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["800", "8001", "80011", "800111", "8001111"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["845", "8454", "84546", "845464"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{6})", leading_digits: &["800"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{5})(\\d{4,5})", leading_digits: &["1(?:38|5[23]|69|76|94)", "1(?:(?:38|69)7|5(?:24|39)|768|946)", "1(?:3873|5(?:242|39[4-6])|(?:697|768)[347]|9467)"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{5,6})", leading_digits: &["1(?:[2-69][02-9]|[78])"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{4})(\\d{4})", leading_digits: &["[25]|7(?:0|6[02-9])", "[25]|7(?:0|6(?:[03-9]|2[356]))"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{6})", leading_digits: &["7"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["[1389]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:1(?:1(?:3(?:[0-58]\\d\\d|73[0-5])|4(?:(?:[0-5]\\d|70)\\d|69[7-9])|(?:(?:5[0-26-9]|[78][0-49])\\d|6(?:[0-4]\\d|5[01]))\\d)|(?:2(?:(?:0[024-9]|2[3-9]|3[3-79]|4[1-689]|[58][02-9]|6[0-47-9]|7[013-9]|9\\d)\\d|1(?:[0-7]\\d|8[0-3]))|(?:3(?:0\\d|1[0-8]|[25][02-9]|3[02-579]|[468][0-46-9]|7[1-35-79]|9[2-578])|4(?:0[03-9]|[137]\\d|[28][02-57-9]|4[02-69]|5[0-8]|[69][0-79])|5(?:0[1-35-9]|[16]\\d|2[024-9]|3[015689]|4[02-9]|5[03-9]|7[0-35-9]|8[0-468]|9[0-57-9])|6(?:0[034689]|1\\d|2[0-35689]|[38][013-9]|4[1-467]|5[0-69]|6[13-9]|7[0-8]|9[0-24578])|7(?:0[0246-9]|2\\d|3[0236-8]|4[03-9]|5[0-46-9]|6[013-9]|7[0-35-9]|8[024-9]|9[02-9])|8(?:0[35-9]|2[1-57-9]|3[02-578]|4[0-578]|5[124-9]|6[2-69]|7\\d|8[02-9]|9[02569])|9(?:0[02-589]|[18]\\d|2[02-689]|3[1-57-9]|4[2-9]|5[0-579]|6[2-47-9]|7[0-24578]|9[2-57]))\\d)\\d)|2(?:0[013478]|3[0189]|4[017]|8[0-46-9]|9[0-2])\\d{3})\\d{4}|1(?:2(?:0(?:46[1-4]|87[2-9])|545[1-79]|76(?:2\\d|3[1-8]|6[1-6])|9(?:7(?:2[0-4]|3[2-5])|8(?:2[2-8]|7[0-47-9]|8[3-5])))|3(?:6(?:38[2-5]|47[23])|8(?:47[04-9]|64[0157-9]))|4(?:044[1-7]|20(?:2[23]|8\\d)|6(?:0(?:30|5[2-57]|6[1-8]|7[2-8])|140)|8(?:052|87[1-3]))|5(?:2(?:4(?:3[2-79]|6\\d)|76\\d)|6(?:26[06-9]|686))|6(?:06(?:4\\d|7[4-79])|295[5-7]|35[34]\\d|47(?:24|61)|59(?:5[08]|6[67]|74)|9(?:55[0-4]|77[23]))|7(?:26(?:6[13-9]|7[0-7])|(?:442|688)\\d|50(?:2[0-3]|[3-68]2|76))|8(?:27[56]\\d|37(?:5[2-5]|8[239])|843[2-58])|9(?:0(?:0(?:6[1-8]|85)|52\\d)|3583|4(?:66[1-8]|9(?:2[01]|81))|63(?:23|3[1-4])|9561))\\d{3}", lengths: &[9, 10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "7(?:457[0-57-9]|700[01]|911[028])\\d{5}|7(?:[1-3]\\d\\d|4(?:[0-46-9]\\d|5[0-689])|5(?:0[0-8]|[13-9]\\d|2[0-35-9])|7(?:0[1-9]|[1-7]\\d|8[02-9]|9[0-689])|8(?:[014-9]\\d|[23][0-8])|9(?:[024-9]\\d|1[02-9]|3[0-689]))\\d{6}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[08]\\d{7}|800\\d{6}|8001111", lengths: &[7, 9, 10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "(?:8(?:4[2-5]|7[0-3])|9(?:[01]\\d|8[2-49]))\\d{7}|845464\\d", lengths: &[7, 10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "70\\d{8}", lengths: &[10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "56\\d{8}", lengths: &[10] }),
+        (PhoneType::Pager, NumberDesc { pattern: "76(?:464|652)\\d{5}|76(?:0[0-28]|2[356]|34|4[01347]|5[49]|6[0-369]|77|8[14]|9[139])\\d{6}", lengths: &[10] }),
+        (PhoneType::Uan, NumberDesc { pattern: "(?:3[0347]|55)\\d{8}", lengths: &[10] }),
+    ] },
+    // GD
+    Metadata { general: "(?:473|[58]\\d\\d|900)\\d{7}", lengths: &[10], leading_digits: Some("473"), national_prefix_for_parsing: Some("([2-9]\\d{6})$|1"), national_prefix_transform_rule: Some("473$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "473(?:2(?:3[0-2]|69)|3(?:2[89]|86)|4(?:[06]8|3[5-9]|4[0-4]|5[59]|73|90)|63[68]|7(?:58|84)|800|938)\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "473(?:4(?:0[2-79]|1[04-9]|2[0-5]|49|5[6-8])|5(?:2[01]|3[3-8])|901)\\d{4}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+    ] },
+    // GE
+    Metadata { general: "(?:[3-57]\\d\\d|800)\\d{6}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["70"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["32"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[57]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[348]"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:3(?:[256]\\d|4[124-9]|7[0-4])|4(?:1\\d|2[2-7]|3[1-79]|4[2-8]|7[239]|9[1-7]))\\d{6}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "5(?:(?:(?:0555|1(?:[17]77|555))[5-9]|757(?:7[7-9]|8[01]))\\d|22252[0-4])\\d\\d|5(?:0(?:0(?:1[09]|70)|505)|1(?:0[01]0|1(?:07|33|51))|2(?:0[02]0|2[25]2)|3(?:0[03]0|3[35]3)|(?:40[04]|900)0|5222)[0-4]\\d{3}|(?:5(?:0(?:0(?:0\\d|1[12]|22|3[0-6]|44|5[05]|77|88|9[09])|(?:[14]\\d|77)\\d|22[02])|1(?:1(?:[03][01]|[124]\\d|5[2-6]|7[0-6])|4\\d\\d)|[23]555|4(?:4\\d\\d|555)|5(?:[0157-9]\\d\\d|200|333|444)|6[89]\\d\\d|7(?:(?:[0147-9]\\d|22)\\d|5(?:00|[57]5))|8(?:0(?:[018]\\d|2[0-4])|5(?:55|8[89])|8(?:55|88))|9(?:090|[1-35-9]\\d\\d))|790\\d\\d)\\d{4}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{6}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "70[67]\\d{6}", lengths: &[9] }),
+    ] },
+    // GF
+    Metadata { general: "(?:[56]94\\d|7093)\\d{5}|(?:80|9\\d)\\d{7}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[5-7]|9[47]"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[89]"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "594(?:[02-49]\\d|1[0-5]|5[6-9]|6[0-3]|80)\\d{4}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:694(?:[0-249]\\d|3[0-8])|7093[0-3])\\d{4}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[0-5]\\d{6}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "9(?:(?:396|76\\d)\\d|476[0-6])\\d{4}", lengths: &[9] }),
+    ] },
+    // GG
+    Metadata { general: "(?:1481|[357-9]\\d{3})\\d{6}|8\\d{6}(?:\\d{2})?", lengths: &[7, 9, 10], leading_digits: None, national_prefix_for_parsing: Some("([25-9]\\d{5})$|0|180020"), national_prefix_transform_rule: Some("1481$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "1481[25-9]\\d{5}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "7(?:(?:781|839)\\d|911[17])\\d{5}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[08]\\d{7}|800\\d{6}|8001111", lengths: &[7, 9, 10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "(?:8(?:4[2-5]|7[0-3])|9(?:[01]\\d|8[0-3]))\\d{7}|845464\\d", lengths: &[7, 10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "70\\d{8}", lengths: &[10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "56\\d{8}", lengths: &[10] }),
+        (PhoneType::Pager, NumberDesc { pattern: "76(?:464|652)\\d{5}|76(?:0[0-28]|2[356]|34|4[01347]|5[49]|6[0-369]|77|8[14]|9[139])\\d{6}", lengths: &[10] }),
+        (PhoneType::Uan, NumberDesc { pattern: "(?:3[0347]|55)\\d{8}", lengths: &[10] }),
+    ] },
+    // GH
+    Metadata { general: "(?:[235]\\d{3}|800)\\d{5}", lengths: &[8, 9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[237]|8[0-2]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d{3})(\\d{5})", leading_digits: &["8"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["[235]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "3082[0-5]\\d{4}|3(?:0(?:[237]\\d|8[01])|[167](?:2[0-6]|7\\d|80)|2(?:2[0-5]|7\\d|80)|3(?:2[0-3]|7\\d|80)|4(?:2[013-9]|3[01]|7\\d|80)|5(?:2[0-7]|7\\d|80)|8(?:2[0-2]|7\\d|80)|9(?:[28]0|7\\d))\\d{5}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:2(?:[0346-9]\\d|5[67])|5(?:[03-7]\\d|9[1-9]))\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{5}", lengths: &[8] }),
+    ] },
+    // GI
+    Metadata { general: "(?:[25]\\d|60)\\d{6}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{5})", leading_digits: &["2"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2190[0-2]\\d{3}|2(?:0(?:[02]\\d|3[01])|16[24-9]|2[2-5]\\d)\\d{4}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "5251[0-4]\\d{3}|(?:5(?:[146-8]\\d\\d|250)|60(?:1[01]|6\\d))\\d{4}", lengths: &[8] }),
+    ] },
+    // GL
+    Metadata { general: "(?:19|[2-689]\\d|70)\\d{4}", lengths: &[6], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["19|[2-9]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:19|3[1-7]|[68][1-9]|70|9\\d)\\d{4}", lengths: &[6] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "[245]\\d{5}", lengths: &[6] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80\\d{4}", lengths: &[6] }),
+        (PhoneType::Voip, NumberDesc { pattern: "3[89]\\d{4}", lengths: &[6] }),
+    ] },
+    // GM
+    Metadata { general: "[2-9]\\d{6}", lengths: &[7], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[2-9]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:4(?:[23]\\d\\d|4(?:1[024679]|[6-9]\\d))|5(?:5(?:3\\d|4[0-7])|6[67]\\d|7(?:1[04]|2[035]|3[58]|48))|8[0-589]\\d\\d)\\d{3}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "556\\d{4}|(?:[23679]\\d|4[015]|5[0-489]|8[67])\\d{5}", lengths: &[7] }),
+    ] },
+    // GN
+    Metadata { general: "722\\d{6}|(?:3|6\\d)\\d{7}", lengths: &[8, 9], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["3"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[67]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "3(?:0(?:24|3[12]|4[1-35-7]|5[13]|6[189]|[78]1|9[1478])|1\\d\\d)\\d{4}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "6[0-356]\\d{7}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "722\\d{6}", lengths: &[9] }),
+    ] },
+    // GP
+    Metadata { general: "(?:590\\d|7090)\\d{5}|(?:69|80|9\\d)\\d{7}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[5-79]"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["8"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "590(?:0[1-68]|[14][0-24-9]|2[0-68]|3[1-9]|5[3-579]|[68][0-689]|7[08]|9\\d)\\d{4}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:69(?:0\\d\\d|1(?:2[2-9]|3[0-5])|4(?:0[89]|1[2-6]|9\\d)|6(?:1[016-9]|5[0-4]|[67]\\d))|7090[0-4])\\d{4}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[0-5]\\d{6}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "9(?:(?:39[5-7]|76[018])\\d|475[0-6])\\d{4}", lengths: &[9] }),
+    ] },
+    // GQ
+    Metadata { general: "222\\d{6}|(?:3\\d|55|[89]0)\\d{7}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[235]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{6})", leading_digits: &["[89]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "33[0-24-9]\\d[46]\\d{4}|3(?:33|5\\d)\\d[7-9]\\d{4}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:222|55\\d)\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80\\d[1-9]\\d{5}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90\\d[1-9]\\d{5}", lengths: &[9] }),
+    ] },
+    // GR
+    Metadata { general: "5005000\\d{3}|8\\d{9,11}|(?:[269]\\d|70)\\d{8}", lengths: &[10, 11, 12], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{4})(\\d{4})", leading_digits: &["21|7"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{6})", leading_digits: &["2(?:2|3[2-57-9]|4[2-469]|5[2-59]|6[2-9]|7[2-69]|8[2-49])|5"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["[2689]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3,4})(\\d{5})", leading_digits: &["8"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2(?:1\\d\\d|2(?:2[1-46-9]|[36][1-8]|4[1-7]|5[1-4]|7[1-5]|[89][1-9])|3(?:1\\d|2[1-57]|[35][1-3]|4[13]|7[1-7]|8[124-6]|9[1-79])|4(?:1\\d|2[1-8]|3[1-4]|4[13-5]|6[1-578]|9[1-5])|5(?:1\\d|[29][1-4]|3[1-5]|4[124]|5[1-6])|6(?:1\\d|[269][1-6]|3[1245]|4[1-7]|5[13-9]|7[14]|8[1-5])|7(?:1\\d|2[1-5]|3[1-6]|4[1-7]|5[1-57]|6[135]|9[125-7])|8(?:1\\d|2[1-5]|[34][1-4]|9[1-57]))\\d{6}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "68[57-9]\\d{7}|(?:69|94)\\d{8}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{7,9}", lengths: &[10, 11, 12] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90[19]\\d{7}", lengths: &[10] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "8(?:0[16]|12|[27]5|50)\\d{7}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "70\\d{8}", lengths: &[10] }),
+        (PhoneType::Uan, NumberDesc { pattern: "5005000\\d{3}", lengths: &[10] }),
+    ] },
+    // GT
+    Metadata { general: "80\\d{6}|(?:1\\d{3}|[2-7])\\d{7}", lengths: &[8, 11], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[2-8]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})(\\d{4})", leading_digits: &["1"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "[267][2-9]\\d{6}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:[3-5]\\d\\d|80[0-4])\\d{5}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "18[01]\\d{8}", lengths: &[11] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "19\\d{9}", lengths: &[11] }),
+    ] },
+    // GU
+    Metadata { general: "(?:[58]\\d\\d|671|900)\\d{7}", lengths: &[10], leading_digits: Some("671"), national_prefix_for_parsing: Some("([2-9]\\d{6})$|1"), national_prefix_transform_rule: Some("671$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "671(?:2\\d\\d|3(?:00|3[39]|4[349]|55|6[26])|4(?:00|56|7[1-9]|8[02-9])|5(?:55|6[2-5]|88)|6(?:3[2-578]|4[24-9]|5[34]|78|8[235-9])|7(?:[0479]7|2[0167]|3[45]|8[7-9])|8(?:[2-57-9]8|6[478])|9(?:2[29]|6[79]|7[1279]|8[7-9]|9[78]))\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "671(?:2\\d\\d|3(?:00|3[39]|4[349]|55|6[26])|4(?:00|56|7[1-9]|8[02-9])|5(?:55|6[2-5]|88)|6(?:3[2-578]|4[24-9]|5[34]|78|8[235-9])|7(?:[0479]7|2[0167]|3[45]|8[7-9])|8(?:[2-57-9]8|6[478])|9(?:2[29]|6[79]|7[1279]|8[7-9]|9[78]))\\d{4}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+    ] },
+    // GW
+    Metadata { general: "[49]\\d{8}|4\\d{6}", lengths: &[7, 9], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["40"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[49]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "443\\d{6}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "9(?:5\\d|6[569]|77)\\d{6}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "40\\d{5}", lengths: &[7] }),
+    ] },
+    // GY
+    Metadata { general: "(?:[2-8]\\d{3}|9008)\\d{3}", lengths: &[7], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[2-9]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2(?:1[6-9]|2[0-35-9]|3[1-4]|5[3-9]|6\\d|7[0-79])|3(?:2[25-9]|3\\d)|4(?:4[0-24]|5[56])|50[0-6]|77[1-57])\\d{4}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:51[01]|6\\d\\d|7(?:[0-5]\\d|6[0-59]|70))\\d{4}", lengths: &[7] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "(?:289|8(?:00|6[28]|88|99))\\d{4}", lengths: &[7] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "9008\\d{3}", lengths: &[7] }),
+        (PhoneType::Voip, NumberDesc { pattern: "515\\d{4}", lengths: &[7] }),
+    ] },
+    // HK
+    Metadata { general: "8[0-46-9]\\d{6,7}|9\\d{4,7}|(?:[2-7]|9\\d{3})\\d{7}", lengths: &[5, 6, 7, 8, 9, 11], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{2,5})", leading_digits: &["900", "9003"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[2-7]|8[1-4]|9(?:0[1-9]|[1-8])"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["8"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["9"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2(?:[13-9]\\d|2[013-9])\\d|3(?:(?:[1569][0-24-9]|4[0-246-9]|7[0-24-69])\\d|8(?:4[0-8]|[579]\\d|6[0-5]))|58(?:0[1-9]|1[2-9]))\\d{4}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:4(?:44[0-35-9]|6(?:4[0-57-9]|6[0-6])|7(?:4[0-48]|6[0-5]))|5(?:25[3-7]|35[4-8]|73[0-6]|95[0-8])|6(?:26[013-8]|(?:66|78)[0-5])|70(?:7[1-8]|8[0-8])|84(?:4[0-2]|8[0-35-9])|9(?:29[013-9]|39[014-9]|59[0-467]|899))\\d{4}|(?:4(?:4[0-35-9]|6[0-357-9]|7[0-35])|5(?:[1-59][0-46-9]|6[0-4689]|7[0-246-9])|6(?:0[1-9]|[13-59]\\d|[268][0-57-9]|7[0-79])|70[1-59]|84[0-39]|9(?:0[1-9]|1[02-9]|[2358][0-8]|[467]\\d))\\d{5}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{6}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900(?:[0-24-9]\\d{7}|3\\d{1,4})", lengths: &[5, 6, 7, 8, 11] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "8(?:1[0-4679]\\d|2(?:[0-36]\\d|7[0-4])|3(?:[034]\\d|2[09]|70))\\d{4}", lengths: &[8] }),
+        (PhoneType::Pager, NumberDesc { pattern: "7(?:1(?:0[0-38]|1[0-3679]|3[013]|69|9[0136])|2(?:[02389]\\d|1[18]|7[27-9])|3(?:[0-38]\\d|7[0-369]|9[2357-9])|47\\d|5(?:[178]\\d|5[0-5])|6(?:0[0-7]|2[236-9]|[35]\\d)|7(?:[27]\\d|8[7-9])|8(?:[23689]\\d|7[1-9])|9(?:[025]\\d|6[0-246-8]|7[0-36-9]|8[238]))\\d{4}", lengths: &[8] }),
+        (PhoneType::Uan, NumberDesc { pattern: "30(?:0[1-9]|[15-7]\\d|2[047]|89)\\d{4}", lengths: &[8] }),
+    ] },
+    // HN
+    Metadata { general: "8\\d{10}|[237-9]\\d{7}", lengths: &[8, 11], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[237-9]"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: Some("${1}-${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})(\\d{4})", leading_digits: &["8"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: None },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2(?:2(?:0[0-59]|1[1-9]|[23]\\d|4[02-7]|5[57]|6[245]|7[0135689]|8[01346-9]|9[0-2])|4(?:0[578]|2[3-59]|3[13-9]|4[0-68]|5[1-3589])|5(?:0[2357-9]|1[1-356]|4[03-5]|5\\d|6[014-69]|7[04]|80)|6(?:[056]\\d|17|2[067]|3[047]|4[0-378]|[78][0-8]|9[01])|7(?:0[5-79]|6[46-9]|7[02-9]|8[034]|91)|8(?:79|8[0-357-9]|9[1-57-9]))\\d{4}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "[37-9]\\d{7}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8002\\d{7}", lengths: &[11] }),
+    ] },
+    // HR
+    Metadata { general: "[2-69]\\d{8}|80\\d{5,7}|[1-79]\\d{7}|6\\d{6}", lengths: &[7, 8, 9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{3})", leading_digits: &["6[01]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2,3})", leading_digits: &["8"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d)(\\d{4})(\\d{3})", leading_digits: &["1"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3,4})", leading_digits: &["6|7[245]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3,4})", leading_digits: &["9"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3,4})", leading_digits: &["[2-57]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["8"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "1\\d{7}|(?:2[0-3]|3[1-5]|4[02-47-9]|5[1-3])\\d{6,7}", lengths: &[8, 9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "9(?:(?:0[1-9]|[12589]\\d)\\d\\d|7(?:[0679]\\d\\d|5(?:[01]\\d|44|55|77|9[5-79])))\\d{4}|98\\d{6}", lengths: &[8, 9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80\\d{5,7}", lengths: &[7, 8, 9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "6[01459]\\d{6}|6[01]\\d{5}", lengths: &[7, 8] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "7[45]\\d{6}", lengths: &[8] }),
+        (PhoneType::Uan, NumberDesc { pattern: "62\\d{6,7}|72\\d{6}", lengths: &[8, 9] }),
+    ] },
+    // HT
+    Metadata { general: "[2-589]\\d{7}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{4})", leading_digits: &["[2-589]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2(?:2\\d|5[1-5]|81|9[149])\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:[34]\\d|5[56])\\d{6}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8\\d{7}", lengths: &[8] }),
+        (PhoneType::Voip, NumberDesc { pattern: "9(?:[67][0-4]|8[0-3589]|9\\d)\\d{5}", lengths: &[8] }),
+    ] },
+    // HU
+    Metadata { general: "[235-7]\\d{8}|[1-9]\\d{7}", lengths: &[8, 9], leading_digits: None, national_prefix_for_parsing: Some("06"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{4})", leading_digits: &["1"], format: "${1} ${2} ${3}", national_format: "(06 ${1}) ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["[27][2-9]|3[2-7]|4[24-9]|5[2-79]|6|8[2-57-9]|9[2-69]"], format: "${1} ${2} ${3}", national_format: "(06 ${1}) ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3,4})", leading_digits: &["[2-9]"], format: "${1} ${2} ${3}", national_format: "06 ${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:1\\d|[27][2-9]|3[2-7]|4[24-9]|5[2-79]|6[23689]|8[2-57-9]|9[2-69])\\d{6}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:[257]0|3[01])\\d{7}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "(?:[48]0\\d|680[29])\\d{5}", lengths: &[8, 9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "9[01]\\d{6}", lengths: &[8] }),
+        (PhoneType::Voip, NumberDesc { pattern: "21\\d{7}", lengths: &[9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "38\\d{7}", lengths: &[9] }),
+    ] },
+    // ID
+    Metadata { general: "00[1-9]\\d{9,14}|(?:[1-36]|8\\d{5})\\d{6}|00\\d{9}|[1-9]\\d{8,10}|[2-9]\\d{7}", lengths: &[7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{3})", leading_digits: &["15"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{5,9})", leading_digits: &["2[124]|[36]1"], format: "${1} ${2}", national_format: "(0${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{5,7})", leading_digits: &["800"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{5,8})", leading_digits: &["[2-79]"], format: "${1} ${2}", national_format: "(0${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3,4})(\\d{3})", leading_digits: &["8[1-35-9]"], format: "${1}-${2}-${3}", national_format: "0${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{6,8})", leading_digits: &["1"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["804"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d)(\\d{3})(\\d{3})", leading_digits: &["80"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})(\\d{4,5})", leading_digits: &["8"], format: "${1}-${2}-${3}", national_format: "0${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})(\\d{2,8})", leading_digits: &["001"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: None },
+        NumberFormat { pattern: "(\\d{2})(\\d{4})(\\d{3})(\\d{4})", leading_digits: &["0"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: None },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2[124]\\d{7,8}|619\\d{8}|2(?:1(?:14|500)|2\\d{3})\\d{3}|61\\d{5,8}|(?:2(?:[35][1-4]|6[0-8]|7[1-6]|8\\d|9[1-8])|3(?:1|[25][1-8]|3[1-68]|4[1-3]|6[1-3568]|7[0-469]|8\\d)|4(?:0[1-589]|1[01347-9]|2[0-36-8]|3[0-24-68]|43|5[1-378]|6[1-5]|7[134]|8[1245])|5(?:1[1-35-9]|2[25-8]|3[124-9]|4[1-3589]|5[1-46]|6[1-8])|6(?:[25]\\d|3[1-69]|4[1-6])|7(?:02|[125][1-9]|[36]\\d|4[1-8]|7[0-36-9])|9(?:0[12]|1[013-8]|2[0-479]|5[125-8]|6[23679]|7[159]|8[01346]))\\d{5,8}", lengths: &[7, 8, 9, 10, 11] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "8[1-35-9]\\d{7,10}", lengths: &[9, 10, 11, 12] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "00(?:1803\\d{5,11}|7803\\d{7})|(?:177\\d|800)\\d{5,7}", lengths: &[8, 9, 10, 11, 12, 13, 14, 15, 16, 17] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "809\\d{7}", lengths: &[10] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "804\\d{7}", lengths: &[10] }),
+        (PhoneType::Uan, NumberDesc { pattern: "(?:1500|8071\\d{3})\\d{3}", lengths: &[7, 10] }),
+    ] },
+    // IE
+    Metadata { general: "(?:1\\d|[2569])\\d{6,8}|4\\d{6,9}|7\\d{8}|8\\d{8,9}", lengths: &[7, 8, 9, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{5})", leading_digits: &["2[24-9]|47|58|6[237-9]|9[35-9]"], format: "${1} ${2}", national_format: "(0${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{5})", leading_digits: &["[45]0"], format: "${1} ${2}", national_format: "(0${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d)(\\d{3,4})(\\d{4})", leading_digits: &["1"], format: "${1} ${2} ${3}", national_format: "(0${1}) ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3,4})", leading_digits: &["[2569]|4[1-69]|7[14]"], format: "${1} ${2} ${3}", national_format: "(0${1}) ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["70"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["81"], format: "${1} ${2} ${3}", national_format: "(0${1}) ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["[78]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})(\\d{3})", leading_digits: &["1"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{4})(\\d{4})", leading_digits: &["4"], format: "${1} ${2} ${3}", national_format: "(0${1}) ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d)(\\d{3})(\\d{4})", leading_digits: &["8"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:1\\d|21)\\d{6,7}|(?:2[24-9]|4(?:0[24]|5\\d|7)|5(?:0[45]|1\\d|8)|6(?:1\\d|[237-9])|9(?:1\\d|[35-9]))\\d{5}|(?:23|4(?:[1-469]|8\\d)|5[23679]|6[4-6]|7[14]|9[04])\\d{7}", lengths: &[7, 8, 9, 10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "8(?:22|[35-9]\\d)\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "1800\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "15(?:1[2-8]|[2-8]0|9[089])\\d{6}", lengths: &[10] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "18[59]0\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "700\\d{6}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "76\\d{7}", lengths: &[9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "818\\d{6}", lengths: &[9] }),
+        (PhoneType::Voicemail, NumberDesc { pattern: "88210[1-9]\\d{4}|8(?:[35-79]5\\d\\d|8(?:[013-9]\\d\\d|2(?:[01][1-9]|[2-9]\\d)))\\d{5}", lengths: &[10] }),
+    ] },
+    // IL
+    Metadata { general: "1\\d{6}(?:\\d{3,5})?|[57]\\d{8}|[1-489]\\d{7}", lengths: &[7, 8, 9, 10, 11, 12], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})(\\d{3})", leading_digits: &["125"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: Some("${1}-${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{2})(\\d{2})", leading_digits: &["121"], format: "${1}-${2}-${3}", national_format: "${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{4})", leading_digits: &["[2-489]"], format: "${1}-${2}-${3}", national_format: "0${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["[57]"], format: "${1}-${2}-${3}", national_format: "0${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})(\\d{3})", leading_digits: &["12"], format: "${1}-${2}-${3}", national_format: "${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{6})", leading_digits: &["159"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: Some("${1}-${2}") },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["1[7-9]"], format: "${1}-${2}-${3}-${4}", national_format: "${1}-${2}-${3}-${4}", intl_format: Some("${1}-${2}-${3}-${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{1,2})(\\d{3})(\\d{4})", leading_digits: &["15"], format: "${1}-${2} ${3}-${4}", national_format: "${1}-${2} ${3}-${4}", intl_format: Some("${1}-${2} ${3}-${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "153\\d{8,9}|29[1-9]\\d{5}|(?:2[0-8]|[3489]\\d)\\d{6}", lengths: &[8, 11, 12] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "55(?:4(?:0[0-2]|[16]0)|57[0-289])\\d{4}|5(?:(?:[0-2][02-9]|[36]\\d|[49][2-9]|8[3-7])\\d|5(?:01|2\\d|3[0-3]|4[3-5]|5[0-25689]|6[6-8]|7[0-267]|8[7-9]|9[1-9]))\\d{5}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "1(?:255|80[019]\\d{3})\\d{3}", lengths: &[7, 10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "1212\\d{4}|1(?:200|9(?:0[0-2]|19))\\d{6}", lengths: &[8, 10] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "1700\\d{6}", lengths: &[10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "7(?:38(?:[05]\\d|8[0138])|8(?:33|55|77|81)\\d)\\d{4}|7(?:18|2[23]|3[237]|47|6[258]|7\\d|82|9[2-9])\\d{6}", lengths: &[9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "1599\\d{6}", lengths: &[10] }),
+        (PhoneType::Voicemail, NumberDesc { pattern: "151\\d{8,9}", lengths: &[11, 12] }),
+    ] },
+    // IM
+    Metadata { general: "1624\\d{6}|(?:[3578]\\d|90)\\d{8}", lengths: &[10], leading_digits: Some("74576|(?:16|7[56])24"), national_prefix_for_parsing: Some("([25-8]\\d{5})$|0|180020"), national_prefix_transform_rule: Some("1624$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "1624(?:230|[5-8]\\d\\d)\\d{3}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "76245[06]\\d{4}|7(?:4576|[59]24\\d|624[0-4689])\\d{5}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "808162\\d{4}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "8(?:440[49]06|72299\\d)\\d{3}|(?:8(?:45|70)|90[0167])624\\d{4}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "70\\d{8}", lengths: &[10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "56\\d{8}", lengths: &[10] }),
+        (PhoneType::Uan, NumberDesc { pattern: "3440[49]06\\d{3}|(?:3(?:08162|3\\d{4}|45624|7(?:0624|2299))|55\\d{4})\\d{4}", lengths: &[10] }),
+    ] },
+    // IN
+    Metadata { general: "(?:000800|[2-9]\\d\\d)\\d{7}|1\\d{7,12}", lengths: &[8, 9, 10, 11, 12, 13], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{7})", leading_digits: &["575"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{8})", leading_digits: &["5(?:0|2[23]|3[03]|[67]1|88)", "5(?:0|2(?:21|3)|3(?:0|3[23])|616|717|888)", "5(?:0|2(?:21|3)|3(?:0|3[23])|616|717|8888)"], format: "${1}", national_format: "${1}", intl_format: Some("${1}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4,5})", leading_digits: &["180", "1800"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["140"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{4})(\\d{4})", leading_digits: &["11|2[02]|33|4[04]|79[1-7]|80[2-46]", "11|2[02]|33|4[04]|79(?:[1-6]|7[19])|80(?:[2-4]|6[0-589])", "11|2[02]|33|4[04]|79(?:[124-6]|3(?:[02-9]|1[0-24-9])|7(?:1|9[1-6]))|80(?:[2-4]|6[0-589])"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["1(?:2[0-249]|3[0-25]|4[145]|[68]|7[1257])|2(?:1[257]|3[013]|4[01]|5[0137]|6[0158]|78|8[1568])|3(?:26|4[1-3]|5[34]|6[01489]|7[02-46]|8[159])|4(?:1[36]|2[1-47]|5[12]|6[0-26-9]|7[0-24-9]|8[013-57]|9[014-7])|5(?:1[025]|22|[36][25]|4[28]|5[12]|[78]1)|6(?:12|[2-4]1|5[17]|6[13]|80)|7(?:12|3[134]|4[47]|61|88)|8(?:16|2[014]|3[126]|6[136]|7[078]|8[34]|91)|(?:43|59|75)[15]|(?:1[59]|29|67|72)[14]", "1(?:2[0-24]|3[0-25]|4[145]|[59][14]|6[1-9]|7[1257]|8[1-57-9])|2(?:1[257]|3[013]|4[01]|5[0137]|6[058]|78|8[1568]|9[14])|3(?:26|4[1-3]|5[34]|6[01489]|7[02-46]|8[159])|4(?:1[36]|2[1-47]|3[15]|5[12]|6[0-26-9]|7[0-24-9]|8[013-57]|9[014-7])|5(?:1[025]|22|[36][25]|4[28]|[578]1|9[15])|674|7(?:(?:2[14]|3[34]|5[15])[2-6]|61[346]|88[0-8])|8(?:70[2-6]|84[235-7]|91[3-7])|(?:1(?:29|60|8[06])|261|552|6(?:12|[2-47]1|5[17]|6[13]|80)|7(?:12|31|4[47])|8(?:16|2[014]|3[126]|6[136]|7[78]|83))[2-7]", "1(?:2[0-24]|3[0-25]|4[145]|[59][14]|6[1-9]|7[1257]|8[1-57-9])|2(?:1[257]|3[013]|4[01]|5[0137]|6[058]|78|8[1568]|9[14])|3(?:26|4[1-3]|5[34]|6[01489]|7[02-46]|8[159])|4(?:1[36]|2[1-47]|3[15]|5[12]|6[0-26-9]|7[0-24-9]|8[013-57]|9[014-7])|5(?:1[025]|22|[36][25]|4[28]|[578]1|9[15])|6(?:12(?:[2-6]|7[0-8])|74[2-7])|7(?:(?:2[14]|5[15])[2-6]|3171|61[346]|88(?:[2-7]|82))|8(?:70[2-6]|84(?:[2356]|7[19])|91(?:[3-6]|7[19]))|73[134][2-6]|(?:74[47]|8(?:16|2[014]|3[126]|6[136]|7[78]|83))(?:[2-6]|7[19])|(?:1(?:29|60|8[06])|261|552|6(?:[2-4]1|5[17]|6[13]|7(?:1|4[0189])|80)|7(?:12|88[01]))[2-7]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})(\\d{3})", leading_digits: &["1(?:[2-479]|5[0235-9])|[2-5]|6(?:1[1358]|2[2457-9]|3[2-5]|4[235-7]|5[2-689]|6[24578]|7[235689]|8[1-6])|7(?:1[013-9]|28|3[129]|4[1-35689]|5[29]|6[02-5]|70)|807", "1(?:[2-479]|5[0235-9])|[2-5]|6(?:1[1358]|2(?:[2457]|84|95)|3(?:[2-4]|55)|4[235-7]|5[2-689]|6[24578]|7[235689]|8[1-6])|7(?:1(?:[013-8]|9[6-9])|28[6-8]|3(?:17|2[0-49]|9[2-57])|4(?:1[2-4]|[29][0-7]|3[0-8]|[56]|8[0-24-7])|5(?:2[1-3]|9[0-6])|6(?:0[5689]|2[5-9]|3[02-8]|4|5[0-367])|70[13-7])|807[19]", "1(?:[2-479]|5(?:[0236-9]|5[013-9]))|[2-5]|6(?:2(?:84|95)|355|8(?:28[235-7]|3))|73179|807(?:1|9[1-3])|(?:1552|6(?:(?:1[1358]|2[2457]|3[2-4]|4[235-7]|5[2-689]|6[24578]|7[235689])\\d|8(?:[14-6]\\d|2[0-79]))|7(?:1(?:[013-8]\\d|9[6-9])|28[6-8]|3(?:2[0-49]|9[2-57])|4(?:1[2-4]|[29][0-7]|3[0-8]|[56]\\d|8[0-24-7])|5(?:2[1-3]|9[0-6])|6(?:0[5689]|2[5-9]|3[02-8]|4\\d|5[0-367])|70[13-7]))[2-7]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{5})(\\d{5})", leading_digits: &["16|[6-9]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{2,4})(\\d{4})", leading_digits: &["18[06]", "18[06]0"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["0"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: None },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["18"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2717(?:[2-7]\\d|95)|6828[235-7]\\d)\\d{4}|(?:170[24]|280[13468]|4(?:20[24]|72[2-8])|552[1-7])\\d{6}|(?:271[0-689]|682[0-79]|782[0-6])[2-7]\\d{5}|(?:2(?:[02][2-79]|90)|3(?:23|80)|683|79[1-7])\\d{7}|(?:11|33|4[04]|80)[2-7]\\d{7}|(?:342|674|788)(?:[0189][2-7]|[2-7]\\d)\\d{5}|(?:1(?:2[0-249]|3[0-25]|4[145]|[59][14]|6[014]|7[1257]|8[01346])|2(?:1[257]|3[013]|4[01]|5[0137]|6[0158]|78|8[1568]|9[14])|3(?:26|4[13]|5[34]|6[01489]|7[02-46]|8[159])|4(?:1[36]|2[1-47]|3[15]|5[12]|6[0-26-9]|7[014-9]|8[013-57]|9[014-7])|5(?:1[025]|22|[36][25]|4[28]|[578]1|9[15])|6(?:12|[2-47]1|5[17]|6[13]|80)|7(?:12|2[14]|3[134]|4[47]|5[15]|[67]1)|8(?:16|2[014]|3[126]|6[136]|7[078]|8[34]|91))[2-7]\\d{6}|(?:1(?:2[35-8]|3[346-9]|4[236-9]|[59][0235-9]|6[235-9]|7[34689]|8[257-9])|2(?:1[134689]|3[24-8]|4[2-8]|5[25689]|6[2-4679]|7[3-79]|8[2-479]|9[235-9])|3(?:01|1[79]|2[1245]|4[5-8]|5[125689]|6[235-7]|7[157-9]|8[2-46-8])|4(?:1[14578]|2[5689]|3[2-467]|5[4-7]|6[35]|73|8[2689]|9[2389])|5(?:[16][146-9]|2[14-8]|3[1346]|4[14-69]|5[46]|7[2-4]|8[2-8]|9[246])|6(?:1[1358]|2[2457]|3[2-4]|4[235-7]|5[2-689]|6[24578]|7[235689]|8[14-6])|7(?:1[013-9]|2[0235-9]|3[2679]|4[1-35689]|5[2-46-9]|[67][02-9]|8[013-7]|9[089])|8(?:1[1357-9]|2[235-8]|3[03-57-9]|4[0-24-9]|5\\d|6[2457-9]|7[1-6]|8[1256]|9[2-4]))\\d[2-7]\\d{5}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:6(?:1279|828[01489])|7(?:887[02-9]|9(?:313|79[07-9]))|8(?:079[04-9]|(?:84|91)7[02-8]))\\d{5}|(?:160[01]|6(?:12|[2-47]1|5[17]|6[13]|80)[0189]|7(?:1(?:2[0189]|9[0-5])|2(?:[14][017-9]|8[0-59])|3(?:2[5-8]|[34][017-9]|9[016-9])|4(?:1[015-9]|[29][89]|39|8[389])|5(?:[15][017-9]|2[04-9]|9[7-9])|6(?:0[0-47]|1[0-257-9]|2[0-4]|3[19]|5[4589])|70[0289]|88[089]|97[02-8])|8(?:0(?:6[67]|7[02-8])|70[017-9]|84[01489]|91[0-289]))\\d{6}|(?:7(?:31|4[47])|8(?:16|2[014]|3[126]|6[136]|7[78]|83))(?:[0189]\\d|7[02-8])\\d{5}|(?:6(?:[09]\\d|1[04679]|2[03689]|3[05-9]|4[0489]|50|6[069]|7[07]|8[7-9])|7(?:0\\d|2[0235-79]|3[05-8]|40|5[0346-8]|6[6-9]|7[1-9]|8[0-79]|9[089])|8(?:0[01589]|1[0-57-9]|2[235-9]|3[03-57-9]|[45]\\d|6[02457-9]|7[1-69]|8[0-25-9]|9[02-9])|9\\d\\d)\\d{7}|(?:6(?:(?:1[1358]|2[2457]|3[2-4]|4[235-7]|5[2-689]|6[24578])\\d|7(?:[235689]\\d|4[0189])|8(?:[14-6]\\d|2[0-79]))|7(?:1(?:[013-8]\\d|9[6-9])|28[6-8]|3(?:2[0-49]|9[2-5])|4(?:1[2-4]|[29][0-7]|3[0-8]|[56]\\d|8[0-24-7])|5(?:2[1-3]|9[0-6])|6(?:0[5689]|2[5-9]|3[02-8]|4\\d|5[0-367])|70[13-7]|881))[0189]\\d{5}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "000800\\d{7}|180(?:0\\d{4,9}|3\\d{9})", lengths: &[8, 9, 10, 11, 12, 13] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "186[12]\\d{9}", lengths: &[13] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "1860\\d{7}", lengths: &[11] }),
+        (PhoneType::Uan, NumberDesc { pattern: "140\\d{7}", lengths: &[10] }),
+    ] },
+    // IO
+    Metadata { general: "3\\d{6}", lengths: &[7], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["3"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "37\\d{5}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "38\\d{5}", lengths: &[7] }),
+    ] },
+    // IQ
+    Metadata { general: "(?:1|7\\d\\d)\\d{7}|[2-6]\\d{7,8}", lengths: &[8, 9, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{4})", leading_digits: &["1"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3,4})", leading_digits: &["[2-6]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["7"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "1\\d{7}|(?:2[13-5]|3[02367]|4[023]|5[03]|6[026])\\d{6,7}", lengths: &[8, 9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "7[3-9]\\d{8}", lengths: &[10] }),
+    ] },
+    // IR
+    Metadata { general: "[1-9]\\d{9}|(?:[1-8]\\d\\d|9)\\d{3,4}", lengths: &[4, 5, 6, 7, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4,5})", leading_digits: &["96"], format: "${1}", national_format: "0${1}", intl_format: Some("${1}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{4,5})", leading_digits: &["(?:1[137]|2[13-68]|3[1458]|4[145]|5[1468]|6[16]|7[1467]|8[13467])[12689]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3,4})", leading_digits: &["9"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{4})(\\d{4})", leading_digits: &["[1-8]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:1[137]|2[13-68]|3[1458]|4[145]|5[1468]|6[16]|7[1467]|8[13467])(?:[03-57]\\d{7}|[16]\\d{3}(?:\\d{4})?|[289]\\d{3}(?:\\d(?:\\d{3})?)?)|94(?:000[09]|(?:12\\d|30[0-2])\\d|2(?:121|[2689]0\\d)|4(?:111|40\\d))\\d{4}", lengths: &[6, 7, 10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "9(?:(?:0[0-5]|[13]\\d|2[0-3])\\d\\d|9(?:[0-46]\\d\\d|5(?:10|5\\d)|8(?:[12]\\d|88)|9(?:[0159]\\d|21|69|77|8[7-9])))\\d{5}", lengths: &[10] }),
+        (PhoneType::Uan, NumberDesc { pattern: "96(?:0[12]|2[16-8]|3(?:08|[14]5|[23]|66)|4(?:0|80)|5[01]|6[89]|86|9[19])", lengths: &[4, 5] }),
+    ] },
+    // IS
+    Metadata { general: "(?:38\\d|[4-9])\\d{6}", lengths: &[7, 9], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[4-9]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["3"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:4(?:1[0-24-69]|2[0-7]|[37][0-8]|4[0-24589]|5[0-68]|6\\d|8[0-36-8])|5(?:05|[156]\\d|2[02578]|3[0-579]|4[03-7]|7[0-2578]|8[0-35-9]|9[013-689])|872)\\d{4}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:38[589]\\d\\d|6(?:1[1-8]|2[0-6]|3[026-9]|4[014679]|5[0159]|6[0-69]|70|8[06-8]|9\\d)|7(?:5[057]|[6-9]\\d)|8(?:2[0-59]|[3-69]\\d|8[238]))\\d{4}", lengths: &[7, 9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[0-8]\\d{4}", lengths: &[7] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90(?:0\\d|1[5-79]|2[015-79]|3[135-79]|4[125-7]|5[25-79]|7[1-37]|8[0-35-7])\\d{3}", lengths: &[7] }),
+        (PhoneType::Voip, NumberDesc { pattern: "49[0-24-79]\\d{4}", lengths: &[7] }),
+        (PhoneType::Uan, NumberDesc { pattern: "809\\d{4}", lengths: &[7] }),
+        (PhoneType::Voicemail, NumberDesc { pattern: "(?:689|8(?:7[18]|80)|95[48])\\d{4}", lengths: &[7] }),
+    ] },
+    // IT
+    Metadata { general: "0\\d{5,11}|1\\d{8,10}|3(?:[0-8]\\d{7,10}|9\\d{7,8})|(?:43|55|70)\\d{8}|8\\d{5}(?:\\d{2,4})?", lengths: &[6, 7, 8, 9, 10, 11, 12], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4,5})", leading_digits: &["1(?:0|9[246])", "1(?:0|9(?:2[2-9]|[46]))"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{6})", leading_digits: &["1(?:1|92)"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{2})(\\d{4,6})", leading_digits: &["0[26]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3,6})", leading_digits: &["0[13-57-9][0159]|8(?:03|4[17]|9[2-5])", "0[13-57-9][0159]|8(?:03|4[17]|9(?:2|3[04]|[45][0-4]))"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{2,6})", leading_digits: &["0(?:[13-579][2-46-8]|8[236-8])"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["894"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3,4})(\\d{4})", leading_digits: &["0[26]|5"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3,4})", leading_digits: &["1(?:44|[679])|[378]|43"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3,4})(\\d{4})", leading_digits: &["0[13-57-9][0159]|14"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{4})(\\d{5})", leading_digits: &["0[26]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})(\\d{4})", leading_digits: &["0"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})(\\d{4,5})", leading_digits: &["[03]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "0(?:669[0-79]\\d{1,6}|831\\d{2,8})|0(?:1(?:[0159]\\d|[27][1-5]|31|4[1-4]|6[1356]|8[2-57])|2\\d\\d|3(?:[0159]\\d|2[1-4]|3[12]|[48][1-6]|6[2-59]|7[1-7])|4(?:[0159]\\d|[23][1-9]|4[245]|6[1-5]|7[1-4]|81)|5(?:[0159]\\d|2[1-5]|3[2-6]|4[1-79]|6[4-6]|7[1-578]|8[3-8])|6(?:[0-57-9]\\d|6[0-8])|7(?:[0159]\\d|2[12]|3[1-7]|4[2-46]|6[13569]|7[13-6]|8[1-59])|8(?:[0159]\\d|2[3-578]|3[2356]|[6-8][1-5])|9(?:[0159]\\d|[238][1-5]|4[12]|6[1-8]|7[1-6]))\\d{2,7}", lengths: &[6, 7, 8, 9, 10, 11, 12] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "3[2-9]\\d{7,8}|(?:31|43)\\d{8}", lengths: &[9, 10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80(?:0\\d{3}|3)\\d{3}", lengths: &[6, 9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "(?:0878\\d{3}|89(?:2\\d|3[04]|4(?:[0-4]|[5-9]\\d\\d)|5[0-4]))\\d\\d|(?:1(?:44|6[346])|89(?:38|5[5-9]|9))\\d{6}", lengths: &[6, 8, 9, 10] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "84(?:[08]\\d{3}|[17])\\d{3}", lengths: &[6, 9] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "1(?:78\\d|99)\\d{6}", lengths: &[9, 10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "55\\d{8}", lengths: &[10] }),
+        (PhoneType::Voicemail, NumberDesc { pattern: "3[2-8]\\d{9,10}", lengths: &[11, 12] }),
+    ] },
+    // JE
+    Metadata { general: "1534\\d{6}|(?:[3578]\\d|90)\\d{8}", lengths: &[10], leading_digits: None, national_prefix_for_parsing: Some("([0-24-8]\\d{5})$|0|180020"), national_prefix_transform_rule: Some("1534$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "1534[0-24-8]\\d{5}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "7(?:(?:(?:50|82)9|937)\\d|7(?:00[378]|97\\d))\\d{5}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80(?:07(?:35|81)|8901)\\d{4}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "(?:8(?:4(?:4(?:4(?:05|42|69)|703)|5(?:041|800))|7(?:0002|1206))|90(?:066[59]|1810|71(?:07|55)))\\d{4}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "701511\\d{4}", lengths: &[10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "56\\d{8}", lengths: &[10] }),
+        (PhoneType::Pager, NumberDesc { pattern: "76(?:464|652)\\d{5}|76(?:0[0-28]|2[356]|34|4[01347]|5[49]|6[0-369]|77|8[14]|9[139])\\d{6}", lengths: &[10] }),
+        (PhoneType::Uan, NumberDesc { pattern: "(?:3(?:0(?:07(?:35|81)|8901)|3\\d{4}|4(?:4(?:4(?:05|42|69)|703)|5(?:041|800))|7(?:0002|1206))|55\\d{4})\\d{4}", lengths: &[10] }),
+    ] },
+    // JM
+    Metadata { general: "(?:[58]\\d\\d|658|900)\\d{7}", lengths: &[10], leading_digits: Some("658|876"), national_prefix_for_parsing: Some("1"), national_prefix_transform_rule: None, formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "8766060\\d{3}|(?:658(?:2(?:[0-8]\\d|9[0-46-9])|[3-9]\\d\\d)|876(?:52[35]|6(?:0[1-3579]|1[0235-9]|[23]\\d|40|5[06]|6[2-589]|7[0-25-9]|8[04]|9[4-9])|7(?:0[2-689]|[1-6]\\d|8[056]|9[45])|9(?:0[1-8]|1[02378]|[2-8]\\d|9[2-468])))\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:658295|876(?:2(?:0[1-9]|[13-9]\\d|2[013-9])|[348]\\d\\d|5(?:0[1-9]|[1-9]\\d)|6(?:4[89]|6[67])|7(?:0[07]|7\\d|8[1-47-9]|9[0-36-9])|9(?:[01]9|9[0579])))\\d{4}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+    ] },
+    // JO
+    Metadata { general: "(?:(?:[2689]|7\\d)\\d|32|427|53)\\d{6}", lengths: &[8, 9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{4})", leading_digits: &["[2356]|87"], format: "${1} ${2} ${3}", national_format: "(0${1}) ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{5,6})", leading_digits: &["[89]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{7})", leading_digits: &["70"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d)(\\d{4})(\\d{4})", leading_digits: &["[47]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "87(?:000|90[01])\\d{3}|(?:2(?:6(?:2[0-35-9]|3[0-578]|4[24-7]|5[0-24-8]|[6-8][023]|9[0-3])|7(?:0[1-79]|10|2[014-7]|3[0-689]|4[019]|5[0-3578]))|32(?:0[1-69]|1[1-35-7]|2[024-7]|3\\d|4[0-3]|[5-7][023])|53(?:0[0-3]|[13][023]|2[0-59]|49|5[0-35-9]|6[15]|7[45]|8[1-6]|9[0-36-9])|6(?:2(?:[05]0|22)|3(?:00|33)|4(?:0[0-25]|1[2-7]|2[0569]|[38][07-9]|4[025689]|6[0-589]|7\\d|9[0-2])|5(?:[01][056]|2[034]|3[0-57-9]|4[178]|5[0-69]|6[0-35-9]|7[1-379]|8[0-68]|9[0239]))|87(?:20|7[078]|99))\\d{4}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:427|7(?:[78][0-25-9]|9\\d))\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80\\d{6}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "9\\d{7}", lengths: &[8] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "85\\d{6}", lengths: &[8] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "70\\d{7}", lengths: &[9] }),
+        (PhoneType::Pager, NumberDesc { pattern: "74(?:66|77)\\d{5}", lengths: &[9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "8(?:10|8\\d)\\d{5}", lengths: &[8] }),
+    ] },
+    // JP
+    Metadata { general: "00[1-9]\\d{6,14}|[25-9]\\d{9}|(?:00|[1-9]\\d\\d)\\d{6}", lengths: &[8, 9, 10, 11, 12, 13, 14, 15, 16, 17], leading_digits: None, national_prefix_for_parsing: Some("(000[2569]\\d{4,6})$|(?:(?:003768)0?)|0"), national_prefix_transform_rule: Some("$1"), formats: &[
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["007", "0077", "00777", "00777[01]"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d{8,10})", leading_digits: &["000"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["(?:12|57|99)0"], format: "${1}-${2}-${3}", national_format: "0${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d)(\\d{4})", leading_digits: &["1(?:26|3[79]|4[56]|5[4-68]|6[3-5])|499|5(?:76|97)|746|8(?:3[89]|47|51)|9(?:80|9[16])", "1(?:267|3(?:7[247]|9[278])|466|5(?:47|58|64)|6(?:3[245]|48|5[4-68]))|499[2468]|5(?:76|97)9|7468|8(?:3(?:8[7-9]|96)|477|51[2-9])|9(?:802|9(?:1[23]|69))|1(?:45|58)[67]", "1(?:267|3(?:7[247]|9[278])|466|5(?:47|58|64)|6(?:3[245]|48|5[4-68]))|499[2468]|5(?:769|979[2-69])|7468|8(?:3(?:8[7-9]|96[2457-9])|477|51[2-9])|9(?:802|9(?:1[23]|69))|1(?:45|58)[67]"], format: "${1}-${2}-${3}", national_format: "0${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["60"], format: "${1}-${2}-${3}", national_format: "0${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d)(\\d{4})(\\d{4})", leading_digits: &["3|4(?:2[09]|7[01])|6[1-9]", "3|4(?:2(?:0|9[02-69])|7(?:0[019]|1))|6[1-9]"], format: "${1}-${2}-${3}", national_format: "0${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["1(?:1|5[45]|77|88|9[69])|2(?:2[1-37]|3[0-269]|4[59]|5|6[24]|7[1-358]|8[1369]|9[0-38])|4(?:[28][1-9]|3[0-57]|[45]|6[248]|7[2-579]|9[29])|5(?:2|3[0459]|4[0-369]|5[29]|8[02389]|9[0-389])|7(?:2[02-46-9]|34|[58]|6[0249]|7[57]|9[2-6])|8(?:2[124589]|3[26-9]|49|51|6|7[0-468]|8[68]|9[019])|9(?:[23][1-9]|4[15]|5[138]|6[1-3]|7[156]|8[189]|9[1-489])", "1(?:1|5(?:4[018]|5[017])|77|88|9[69])|2(?:2(?:[127]|3[014-9])|3[0-269]|4[59]|5(?:[1-3]|5[0-69]|9[19])|62|7(?:[1-35]|8[0189])|8(?:[16]|3[0134]|9[0-5])|9(?:[028]|17))|4(?:2(?:[13-79]|8[014-6])|3[0-57]|[45]|6[248]|7[2-47]|8[1-9]|9[29])|5(?:2|3(?:[045]|9[0-8])|4[0-369]|5[29]|8[02389]|9[0-3])|7(?:2[02-46-9]|34|[58]|6[0249]|7[57]|9(?:[23]|4[0-59]|5[01569]|6[0167]))|8(?:2(?:[1258]|4[0-39]|9[0-2469])|3(?:[29]|60)|49|51|6(?:[0-24]|36|5[0-3589]|7[23]|9[01459])|7[0-468]|8[68])|9(?:[23][1-9]|4[15]|5[138]|6[1-3]|7[156]|8[189]|9(?:[1289]|3[34]|4[0178]))|(?:264|837)[016-9]|2(?:57|93)[015-9]|(?:25[0468]|422|838)[01]|(?:47[59]|59[89]|8(?:6[68]|9))[019]", "1(?:1|5(?:4[018]|5[017])|77|88|9[69])|2(?:2[127]|3[0-269]|4[59]|5(?:[1-3]|5[0-69]|9(?:17|99))|6(?:2|4[016-9])|7(?:[1-35]|8[0189])|8(?:[16]|3[0134]|9[0-5])|9(?:[028]|17))|4(?:2(?:[13-79]|8[014-6])|3[0-57]|[45]|6[248]|7[2-47]|9[29])|5(?:2|3(?:[045]|9(?:[0-58]|6[4-9]|7[0-35689]))|4[0-369]|5[29]|8[02389]|9[0-3])|7(?:2[02-46-9]|34|[58]|6[0249]|7[57]|9(?:[23]|4[0-59]|5[01569]|6[0167]))|8(?:2(?:[1258]|4[0-39]|9[0169])|3(?:[29]|60|7(?:[017-9]|6[6-8]))|49|51|6(?:[0-24]|36[2-57-9]|5(?:[0-389]|5[23])|6(?:[01]|9[178])|7(?:2[2-468]|3[78])|9[0145])|7[0-468]|8[68])|9(?:4[15]|5[138]|7[156]|8[189]|9(?:[1289]|3(?:31|4[357])|4[0178]))|(?:8294|96)[1-3]|2(?:57|93)[015-9]|(?:223|8699)[014-9]|(?:25[0468]|422|838)[01]|(?:48|8292|9[23])[1-9]|(?:47[59]|59[89]|8(?:68|9))[019]"], format: "${1}-${2}-${3}", national_format: "0${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{4})", leading_digits: &["[14]|[289][2-9]|5[3-9]|7[2-4679]"], format: "${1}-${2}-${3}", national_format: "0${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{2})(\\d{3,4})", leading_digits: &["007", "0077"], format: "${1}-${2}-${3}", national_format: "${1}-${2}-${3}", intl_format: None },
+        NumberFormat { pattern: "(\\d{4})(\\d{2})(\\d{4})", leading_digits: &["008"], format: "${1}-${2}-${3}", national_format: "${1}-${2}-${3}", intl_format: None },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["800"], format: "${1}-${2}-${3}", national_format: "0${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{4})(\\d{4})", leading_digits: &["[25-9]"], format: "${1}-${2}-${3}", national_format: "0${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})(\\d{3,4})", leading_digits: &["0"], format: "${1}-${2}-${3}", national_format: "${1}-${2}-${3}", intl_format: None },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})(\\d{4,5})", leading_digits: &["0"], format: "${1}-${2}-${3}", national_format: "${1}-${2}-${3}", intl_format: None },
+        NumberFormat { pattern: "(\\d{4})(\\d{5})(\\d{5,6})", leading_digits: &["0"], format: "${1}-${2}-${3}", national_format: "${1}-${2}-${3}", intl_format: None },
+        NumberFormat { pattern: "(\\d{4})(\\d{6})(\\d{6,7})", leading_digits: &["0"], format: "${1}-${2}-${3}", national_format: "${1}-${2}-${3}", intl_format: None },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:1(?:1[235-8]|2[3-6]|3[3-9]|4[2-6]|[58][2-8]|6[2-7]|7[2-9]|9[1-9])|(?:2[2-9]|[36][1-9])\\d|4(?:[2-578]\\d|6[02-8]|9[2-59])|5(?:[2-589]\\d|6[1-9]|7[2-8])|7(?:[25-9]\\d|3[4-9]|4[02-9])|8(?:[2679]\\d|3[2-9]|4[5-9]|5[1-9]|8[03-9])|9(?:[2-58]\\d|[679][1-9]))\\d{6}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:601[0-4]0|[7-9]0[1-9]\\d\\d)\\d{5}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "00777(?:[01]|5\\d)\\d\\d|(?:00(?:7778|882[1245])|(?:120|800\\d)\\d\\d)\\d{4}|00(?:37|66|78)\\d{6,13}", lengths: &[8, 9, 10, 11, 12, 13, 14, 15, 16, 17] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "990\\d{6}", lengths: &[9] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "60\\d{7}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "50[1-9]\\d{7}", lengths: &[10] }),
+        (PhoneType::Pager, NumberDesc { pattern: "20\\d{8}", lengths: &[10] }),
+        (PhoneType::Uan, NumberDesc { pattern: "570\\d{6}", lengths: &[9] }),
+    ] },
+    // KE
+    Metadata { general: "(?:[17]\\d\\d|900)\\d{6}|(?:2|80)0\\d{6,7}|[4-6]\\d{6,8}", lengths: &[7, 8, 9, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{5,7})", leading_digits: &["[24-6]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{6})", leading_digits: &["[17]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3,4})", leading_digits: &["[89]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:4[245]|5[1-79]|6[01457-9])\\d{5,7}|(?:4[136]|5[08]|62)\\d{7}|(?:[24]0|66)\\d{6,7}", lengths: &[7, 8, 9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:1(?:0[0-8]|1\\d|2[014]|[34]0)|7\\d\\d)\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800[02-8]\\d{5,6}", lengths: &[9, 10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[02-9]\\d{5}", lengths: &[9] }),
+    ] },
+    // KG
+    Metadata { general: "8\\d{9}|[235-9]\\d{8}", lengths: &[9, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})(\\d{5})", leading_digits: &["3(?:1[346]|[24-79])"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[235-79]|88"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d)(\\d{2,3})", leading_digits: &["8"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "312(?:5[0-79]\\d|9(?:[0-689]\\d|7[0-24-9]))\\d{3}|(?:3(?:1(?:2[0-46-8]|3[1-9]|47|[56]\\d)|2(?:22|3[0-479]|6[0-7])|4(?:22|5[6-9]|6\\d)|5(?:22|3[4-7]|59|6\\d)|6(?:22|5[35-7]|6\\d)|7(?:22|3[468]|4[1-9]|59|[67]\\d)|9(?:22|4[1-8]|6\\d))|6(?:09|12|2[2-4])\\d)\\d{5}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "312(?:58\\d|973)\\d{3}|(?:2(?:0[0-35]|2\\d)|5[0-24-7]\\d|600|7(?:[07]\\d|55)|88[08]|9(?:12|9[05-9]))\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{6,7}", lengths: &[9, 10] }),
+    ] },
+    // KH
+    Metadata { general: "1\\d{9}|[1-9]\\d{7,8}", lengths: &[8, 9, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3,4})", leading_digits: &["[1-9]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})(\\d{3})", leading_digits: &["1"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "23(?:4(?:[2-4]|[56]\\d)|[568]\\d\\d)\\d{4}|23[236-9]\\d{5}|(?:2[4-6]|3[2-6]|4[2-4]|[5-7][2-5])(?:(?:[237-9]|4[56]|5\\d)\\d{5}|6\\d{5,6})", lengths: &[8, 9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:(?:1[28]|3[18]|9[67])\\d|6[016-9]|7(?:[07-9]|[16]\\d)|8(?:[013-79]|8\\d))\\d{6}|(?:1\\d|9[0-57-9])\\d{6}|(?:2[3-6]|3[2-6]|4[2-4]|[5-7][2-5])48\\d{5}", lengths: &[8, 9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "1800(?:1\\d|2[019])\\d{4}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "1900(?:1\\d|2[09])\\d{4}", lengths: &[10] }),
+    ] },
+    // KI
+    Metadata { general: "(?:[37]\\d|6[0-79])\\d{6}|(?:[2-48]\\d|50)\\d{3}", lengths: &[5, 8], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:[24]\\d|3[1-9]|50|65(?:02[12]|12[56]|22[89]|[3-5]00)|7(?:27\\d\\d|3100|5(?:02[12]|12[56]|22[89]|[34](?:00|81)|500))|8[0-5])\\d{3}", lengths: &[5, 8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:6200[01]|7(?:310[1-9]|5(?:02[03-9]|12[0-47-9]|22[0-7]|[34](?:0[1-9]|8[02-9])|50[1-9])))\\d{3}|(?:63\\d\\d|7(?:(?:[0146-9]\\d|2[0-689])\\d|3(?:[02-9]\\d|1[1-9])|5(?:[0-2][013-9]|[34][1-79]|5[1-9]|[6-9]\\d)))\\d{4}", lengths: &[8] }),
+        (PhoneType::Voip, NumberDesc { pattern: "30(?:0[01]\\d\\d|12(?:11|20))\\d\\d", lengths: &[8] }),
+    ] },
+    // KM
+    Metadata { general: "[3478]\\d{6}", lengths: &[7], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["[3478]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "7[4-7]\\d{5}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "[34]\\d{6}", lengths: &[7] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "8\\d{6}", lengths: &[7] }),
+    ] },
+    // KN
+    Metadata { general: "(?:[58]\\d\\d|900)\\d{7}", lengths: &[10], leading_digits: Some("869"), national_prefix_for_parsing: Some("([2-7]\\d{6})$|1"), national_prefix_transform_rule: Some("869$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "869(?:2(?:29|36)|302|4(?:6[015-9]|70)|56[5-7])\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "869(?:48[89]|55[6-8]|66\\d|76[02-7])\\d{4}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+    ] },
+    // KP
+    Metadata { general: "85\\d{6}|(?:19\\d|[2-7])\\d{7}", lengths: &[8, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["8"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{4})", leading_digits: &["[2-7]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["1"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:(?:195|2)\\d|3[19]|4[159]|5[37]|6[17]|7[39]|85)\\d{6}", lengths: &[8, 10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "19[1-3]\\d{7}", lengths: &[10] }),
+    ] },
+    // KR
+    Metadata { general: "00[1-9]\\d{8,11}|(?:[12]|5\\d{3})\\d{7}|[13-6]\\d{9}|(?:[1-6]\\d|80)\\d{7}|[3-6]\\d{4,5}|(?:00|7)0\\d{8}", lengths: &[5, 6, 8, 9, 10, 11, 12, 13, 14], leading_digits: None, national_prefix_for_parsing: Some("0(8(?:[1-46-8]|5\\d\\d))?"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{5})", leading_digits: &["1[016-9]1", "1[016-9]11", "1[016-9]114"], format: "${1}", national_format: "0${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{2})(\\d{3,4})", leading_digits: &["(?:3[1-3]|[46][1-4]|5[1-5])1"], format: "${1}-${2}", national_format: "0${1}-${2}", intl_format: Some("${1}-${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["1"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: Some("${1}-${2}") },
+        NumberFormat { pattern: "(\\d)(\\d{3,4})(\\d{4})", leading_digits: &["2"], format: "${1}-${2}-${3}", national_format: "0${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["[36]0|8"], format: "${1}-${2}-${3}", national_format: "0${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3,4})(\\d{4})", leading_digits: &["[1346]|5[1-5]"], format: "${1}-${2}-${3}", national_format: "0${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{4})(\\d{4})", leading_digits: &["[57]"], format: "${1}-${2}-${3}", national_format: "0${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d{5})(\\d{3})(\\d{3})", leading_digits: &["003", "0030"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: None },
+        NumberFormat { pattern: "(\\d{2})(\\d{5})(\\d{4})", leading_digits: &["5"], format: "${1}-${2}-${3}", national_format: "0${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d{5})(\\d{3,4})(\\d{4})", leading_digits: &["0"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: None },
+        NumberFormat { pattern: "(\\d{5})(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["0"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: None },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2|3[1-3]|[46][1-4]|5[1-5])[1-9]\\d{6,7}|(?:3[1-3]|[46][1-4]|5[1-5])1\\d{2,3}", lengths: &[5, 6, 8, 9, 10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "1(?:05(?:[0-8]\\d|9[0-6])|22[13]\\d)\\d{4,5}|1(?:0[0-46-9]|[16-9]\\d|2[013-9])\\d{6,7}", lengths: &[9, 10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "00(?:308\\d{6,7}|798\\d{7,9})|(?:00368|[38]0)\\d{7}", lengths: &[9, 11, 12, 13, 14] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "60[2-9]\\d{6}", lengths: &[9] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "50\\d{8,9}", lengths: &[10, 11] }),
+        (PhoneType::Voip, NumberDesc { pattern: "70\\d{8}", lengths: &[10] }),
+        (PhoneType::Pager, NumberDesc { pattern: "15\\d{7,8}", lengths: &[9, 10] }),
+        (PhoneType::Uan, NumberDesc { pattern: "1(?:5(?:22|33|44|5[15]|66|77|88|99)|6(?:[07]0|44|6[0168]|88)|8(?:00|11|33|44|55|66|77|99))\\d{4}", lengths: &[8] }),
+    ] },
+    // KW
+    Metadata { general: "18\\d{5}|(?:[2569]\\d|41)\\d{6}", lengths: &[7, 8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})(\\d{3,4})", leading_digits: &["[169]|2(?:[235]|4[1-35-9])|52"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{5})", leading_digits: &["[245]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2(?:[23]\\d\\d|4(?:[1-35-9]\\d|44)|5(?:0[034]|[2-46]\\d|5[1-3]|7[1-7]))\\d{4}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:41\\d\\d|5(?:(?:[05]\\d|1[0-7]|6[56])\\d|2(?:22|5[25])|7(?:55|77)|88[58])|6(?:(?:0[034679]|5[015-9]|6\\d)\\d|1(?:00|11|6[16])|2[26]2|3[36]3|4[46]4|7(?:0[013-9]|[67]\\d)|8[68]8|9(?:[069]\\d|3[039]))|9(?:(?:[04679]\\d|8[057-9])\\d|1(?:00|1[01]|99)|2(?:00|2\\d)|3(?:00|3[03])|5(?:00|5\\d)))\\d{4}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "18\\d{5}", lengths: &[7] }),
+    ] },
+    // KY
+    Metadata { general: "(?:345|[58]\\d\\d|900)\\d{7}", lengths: &[10], leading_digits: Some("345"), national_prefix_for_parsing: Some("([2-9]\\d{6})$|1"), national_prefix_transform_rule: Some("345$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "345(?:2(?:22|3[23]|44|66)|333|444|6(?:23|38|40)|7(?:30|4[35-79]|6[6-9]|77)|8(?:00|1[45]|4[89]|88)|9(?:14|4[035-9]))\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "345(?:32[1-9]|42[0-4]|5(?:1[67]|2[5-79]|4[6-9]|50|76)|649|82[56]|9(?:1[679]|2[2-9]|3[06-9]|90))\\d{4}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "(?:345976|900[2-9]\\d\\d)\\d{4}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+    ] },
+    // KZ
+    Metadata { general: "(?:33622|8\\d{8})\\d{5}|[78]\\d{9}", lengths: &[10, 14], leading_digits: Some("33622|7"), national_prefix_for_parsing: Some("8"), national_prefix_transform_rule: None, formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:33622|7(?:1(?:0(?:[23]\\d|4[0-3]|59|63)|1(?:[23]\\d|4[0-79]|59)|2(?:[23]\\d|59)|3(?:2\\d|3[0-79]|4[0-35-9]|59)|4(?:[24]\\d|3[013-9]|5[1-9]|97)|5(?:2\\d|3[1-9]|4[0-7]|59)|6(?:[2-4]\\d|5[19]|61)|72\\d|8(?:[27]\\d|3[1-46-9]|4[0-5]|59))|2(?:1(?:[23]\\d|4[46-9]|5[3469])|2(?:2\\d|3[0679]|46|5[12679])|3(?:[2-4]\\d|5[139])|4(?:2\\d|3[1-35-9]|59)|5(?:[23]\\d|4[0-8]|59|61)|6(?:2\\d|3[1-9]|4[0-4]|59)|7(?:[2379]\\d|40|5[279])|8(?:[23]\\d|4[0-3]|59)|9(?:2\\d|3[124578]|59))))\\d{5}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "7(?:0[0-25-8]|47|6[0-4]|7[15-8]|85)\\d{7}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|108\\d{3})\\d{7}", lengths: &[10, 14] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "809\\d{7}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "808\\d{7}", lengths: &[10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "751\\d{7}", lengths: &[10] }),
+    ] },
+    // LA
+    Metadata { general: "[23]\\d{9}|3\\d{8}|(?:[235-8]\\d|41)\\d{6}", lengths: &[8, 9, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["2[13]|3[14]|[4-8]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{3})", leading_digits: &["3"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["[23]"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2[13]|[35-7][14]|41|8[1468])\\d{6}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:20(?:[23579]\\d|8[78])|30[24]\\d)\\d{6}|30\\d{7}", lengths: &[9, 10] }),
+    ] },
+    // LB
+    Metadata { general: "[27-9]\\d{7}|[13-9]\\d{6}", lengths: &[7, 8], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{3})", leading_digits: &["[13-69]|7(?:[2-57]|62|8[0-6]|9[04-9])|8[02-9]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["[27-9]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "7(?:62|8[0-6]|9[04-9])\\d{4}|(?:[14-69]\\d|2(?:[14-69]\\d|[78][1-9])|7[2-57]|8[02-9])\\d{5}", lengths: &[7, 8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:(?:3|81)\\d|7(?:[01]\\d|6[013-9]|8[7-9]|9[0-4]))\\d{5}", lengths: &[7, 8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "9[01]\\d{6}", lengths: &[8] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "80\\d{6}", lengths: &[8] }),
+    ] },
+    // LC
+    Metadata { general: "(?:[58]\\d\\d|758|900)\\d{7}", lengths: &[10], leading_digits: Some("758"), national_prefix_for_parsing: Some("([2-8]\\d{6})$|1"), national_prefix_transform_rule: Some("758$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "758(?:234|4(?:30|5\\d|6[2-9]|8[0-2])|57[0-2]|(?:63|75)8)\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "758(?:28[4-7]|384|4(?:6[01]|8[4-9])|5(?:1[89]|20|84)|7(?:1[2-9]|2\\d|3[0-3])|812)\\d{4}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+    ] },
+    // LI
+    Metadata { general: "[68]\\d{8}|(?:[2378]\\d|90)\\d{5}", lengths: &[7, 9], leading_digits: None, national_prefix_for_parsing: Some("(1001)|0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["[2379]|8(?:0[09]|7)", "[2379]|8(?:0(?:02|9)|7)"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["8"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["69"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["6"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2(?:01|1[27]|2[024]|3\\d|6[02-578]|96)|3(?:[24]0|33|7[0135-7]|8[048]|9[0269]))\\d{4}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:6(?:(?:4[5-9]|5\\d)\\d|6(?:[024-68]\\d|1[01]|3[7-9]|70))\\d|7(?:[37-9]\\d|42|56))\\d{4}", lengths: &[7, 9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8002[28]\\d\\d|80(?:05\\d|9)\\d{4}", lengths: &[7, 9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90(?:02[258]|1(?:23|3[14])|66[136])\\d\\d", lengths: &[7] }),
+        (PhoneType::Uan, NumberDesc { pattern: "870(?:28|87)\\d\\d", lengths: &[7] }),
+        (PhoneType::Voicemail, NumberDesc { pattern: "697(?:42|56|[78]\\d)\\d{4}", lengths: &[9] }),
+    ] },
+    // LK
+    Metadata { general: "[1-9]\\d{8}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["7"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[1-689]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:12[2-9]|602|8[12]\\d|9(?:1\\d|22|9[245]))\\d{6}|(?:11|2[13-7]|3[1-8]|4[157]|5[12457]|6[35-7])[2-57]\\d{6}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "7(?:[0-25-8]\\d|4[0-4])\\d{6}", lengths: &[9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "1973\\d{5}", lengths: &[9] }),
+    ] },
+    // LR
+    Metadata { general: "(?:[2457]\\d|33|88)\\d{7}|(?:2\\d|[4-6])\\d{6}", lengths: &[7, 8, 9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{3})", leading_digits: &["4[67]|[56]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["2"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["[2-578]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2\\d{7}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:(?:(?:22|33)0|555|7(?:6[01]|7\\d)|88\\d)\\d|4(?:240|[67]))\\d{5}|[56]\\d{6}", lengths: &[7, 9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "332(?:02|[34]\\d)\\d{4}", lengths: &[9] }),
+    ] },
+    // LS
+    Metadata { general: "(?:[256]\\d\\d|800)\\d{5}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[2568]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2\\d{7}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "[56]\\d{7}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800[1256]\\d{4}", lengths: &[8] }),
+    ] },
+    // LT
+    Metadata { general: "(?:[3469]\\d|52|[78]0)\\d{6}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: Some("[08]"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{4})", leading_digits: &["52[0-7]"], format: "${1} ${2} ${3}", national_format: "(0-${1}) ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{3})", leading_digits: &["[7-9]"], format: "${1} ${2} ${3}", national_format: "0 ${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{6})", leading_digits: &["37|4(?:[15]|6[1-8])"], format: "${1} ${2}", national_format: "(0-${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{5})", leading_digits: &["[3-6]"], format: "${1} ${2}", national_format: "(0-${1}) ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:3[1478]|4[124-6]|52)\\d{6}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "6\\d{7}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[02]\\d{5}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "9(?:0[0239]|10)\\d{5}", lengths: &[8] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "808\\d{5}", lengths: &[8] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "70[05]\\d{5}", lengths: &[8] }),
+        (PhoneType::Voip, NumberDesc { pattern: "[89]01\\d{5}", lengths: &[8] }),
+        (PhoneType::Uan, NumberDesc { pattern: "70[67]\\d{5}", lengths: &[8] }),
+    ] },
+    // LU
+    Metadata { general: "35[013-9]\\d{4,8}|6\\d{8}|35\\d{2,4}|(?:[2457-9]\\d|3[0-46-9])\\d{2,9}", lengths: &[4, 5, 6, 7, 8, 9, 10, 11], leading_digits: None, national_prefix_for_parsing: Some("(15(?:0[06]|1[12]|[35]5|4[04]|6[26]|77|88|99)\\d)"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3})", leading_digits: &["2(?:0[2-689]|[2-9])|[3-57]|8(?:0[2-9]|[13-9])|9(?:0[89]|[2-579])"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["2(?:0[2-689]|[2-9])|[3-57]|8(?:0[2-9]|[13-9])|9(?:0[89]|[2-579])"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{3})", leading_digits: &["20[2-689]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{1,2})", leading_digits: &["2(?:[0367]|4[3-8])"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{3})", leading_digits: &["80[01]|90[015]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{3})", leading_digits: &["20"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["6"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})(\\d{1,2})", leading_digits: &["2(?:[0367]|4[3-8])"], format: "${1} ${2} ${3} ${4} ${5}", national_format: "${1} ${2} ${3} ${4} ${5}", intl_format: Some("${1} ${2} ${3} ${4} ${5}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{1,5})", leading_digits: &["[3-57]|8[13-9]|9(?:0[89]|[2-579])|(?:2|80)[2-9]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:35[013-9]|80[2-9]|90[89])\\d{1,8}|(?:2[2-9]|3[0-46-9]|[457]\\d|8[13-9]|9[2-579])\\d{2,9}", lengths: &[4, 5, 6, 7, 8, 9, 10, 11] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "6(?:[269][18]|5[1568]|7[189]|81)\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{5}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90[015]\\d{5}", lengths: &[8] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "801\\d{5}", lengths: &[8] }),
+        (PhoneType::Voip, NumberDesc { pattern: "20(?:1\\d{5}|[2-689]\\d{1,7})", lengths: &[4, 5, 6, 7, 8, 9, 10] }),
+    ] },
+    // LV
+    Metadata { general: "(?:[268]\\d|78|90)\\d{6}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["[2679]|8[01]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "6\\d{7}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "2333[0-8]\\d{3}|2(?:[0-24-9]\\d\\d|3(?:0[07]|[14-9]\\d|2[02-9]|3[0-24-9]))\\d{4}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80\\d{6}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90\\d{6}", lengths: &[8] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "81\\d{6}", lengths: &[8] }),
+    ] },
+    // LY
+    Metadata { general: "[2-9]\\d{8}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{7})", leading_digits: &["[2-9]"], format: "${1}-${2}", national_format: "0${1}-${2}", intl_format: Some("${1}-${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2(?:0[56]|[1-6]\\d|7[124579]|8[124])|3(?:1\\d|2[2356])|4(?:[17]\\d|2[1-357]|5[2-4]|8[124])|5(?:[1347]\\d|2[1-469]|5[13-5]|8[1-4])|6(?:[1-479]\\d|5[2-57]|8[1-5])|7(?:[13]\\d|2[13-79])|8(?:[124]\\d|5[124]|84))\\d{6}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "9[1-6]\\d{7}", lengths: &[9] }),
+    ] },
+    // MA
+    Metadata { general: "[5-8]\\d{8}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["5[45]"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{5})", leading_digits: &["5(?:[19]|2[2-46-9]|3[3-9])|8(?:0[89]|92)"], format: "${1}-${2}", national_format: "0${1}-${2}", intl_format: Some("${1}-${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{7})", leading_digits: &["8"], format: "${1}-${2}", national_format: "0${1}-${2}", intl_format: Some("${1}-${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{6})", leading_digits: &["[5-7]"], format: "${1}-${2}", national_format: "0${1}-${2}", intl_format: Some("${1}-${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "5(?:(?:18|4[0679]|5[03])\\d|2(?:[0-25-79]\\d|3[1-578]|4[02-46-8]|8[0235-7])|3(?:[0-47]\\d|5[02-9]|6[02-8]|8[014-9]|9[3-9]))\\d{5}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:6(?:[0-79]\\d|8[0-247-9])|7(?:[016-8]\\d|2[0-8]|5[0-5]))\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[0-7]\\d{6}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "89\\d{7}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "(?:592(?:4[0-2]|93)|80[89]\\d\\d)\\d{4}", lengths: &[9] }),
+    ] },
+    // MC
+    Metadata { general: "(?:[3489]|[67]\\d)\\d{7}", lengths: &[8, 9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{2})", leading_digits: &["87"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: None },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["4"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[389]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d)(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[67]"], format: "${1} ${2} ${3} ${4} ${5}", national_format: "0${1} ${2} ${3} ${4} ${5}", intl_format: Some("${1} ${2} ${3} ${4} ${5}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:870|9[2-47-9]\\d)\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "4(?:[469]\\d|5[1-9])\\d{5}|(?:3|[67]\\d)\\d{7}", lengths: &[8, 9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "(?:800|90\\d)\\d{5}", lengths: &[8] }),
+    ] },
+    // MD
+    Metadata { general: "(?:[235-7]\\d|[89]0)\\d{6}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{5})", leading_digits: &["[89]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["22|3"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{3})", leading_digits: &["[25-7]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:(?:2[1-9]|3[1-79])\\d|5(?:33|5[257]))\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "562\\d{5}|(?:6\\d|7[16-9])\\d{6}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{5}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90[056]\\d{5}", lengths: &[8] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "808\\d{5}", lengths: &[8] }),
+        (PhoneType::Voip, NumberDesc { pattern: "3[08]\\d{6}", lengths: &[8] }),
+        (PhoneType::Uan, NumberDesc { pattern: "803\\d{5}", lengths: &[8] }),
+    ] },
+    // ME
+    Metadata { general: "(?:20|[3-79]\\d)\\d{6}|80\\d{6,7}", lengths: &[8, 9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3,4})", leading_digits: &["[2-9]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:20[2-8]|3(?:[0-2][2-7]|3[24-7])|4(?:0[2-467]|1[2467])|5(?:0[2467]|1[24-7]|2[2-467]))\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "6(?:[07-9]\\d|3[024]|6[0-25])\\d{5}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80(?:[0-2578]|9\\d)\\d{5}", lengths: &[8, 9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "9(?:4[1568]|5[178])\\d{5}", lengths: &[8] }),
+        (PhoneType::Voip, NumberDesc { pattern: "78[1-49]\\d{5}", lengths: &[8] }),
+        (PhoneType::Uan, NumberDesc { pattern: "77[1-9]\\d{5}", lengths: &[8] }),
+    ] },
+    // MF
+    Metadata { general: "(?:590\\d|7090)\\d{5}|(?:69|80|9\\d)\\d{7}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "590(?:0[079]|[14]3|[27][79]|3[03-7]|5[0-268]|87)\\d{4}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:69(?:0\\d\\d|1(?:2[2-9]|3[0-5])|4(?:0[89]|1[2-6]|9\\d)|6(?:1[016-9]|5[0-4]|[67]\\d))|7090[0-4])\\d{4}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[0-5]\\d{6}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "9(?:(?:39[5-7]|76[018])\\d|475[0-6])\\d{4}", lengths: &[9] }),
+    ] },
+    // MG
+    Metadata { general: "[23]\\d{8}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: Some("([24-9]\\d{6})$|0"), national_prefix_transform_rule: Some("20$1"), formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{3})(\\d{2})", leading_digits: &["[23]"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2072[29]\\d{4}|20(?:2\\d|4[47]|5[3467]|6[279]|7[356]|8[268]|9[2457])\\d{5}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "3[2-9]\\d{7}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "22\\d{7}", lengths: &[9] }),
+    ] },
+    // MH
+    Metadata { general: "329\\d{4}|(?:[256]\\d|45)\\d{5}", lengths: &[7], leading_digits: None, national_prefix_for_parsing: Some("1"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[2-6]"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: Some("${1}-${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:247|528|625)\\d{4}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:(?:23|54)5|329|45[35-8])\\d{4}", lengths: &[7] }),
+        (PhoneType::Voip, NumberDesc { pattern: "635\\d{4}", lengths: &[7] }),
+    ] },
+    // MK
+    Metadata { general: "[2-578]\\d{7}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{4})", leading_digits: &["2|34[47]|4(?:[37]7|5[47]|64)"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["[347]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d)(\\d{2})(\\d{2})", leading_digits: &["[58]"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:(?:2(?:62|77)0|3444)\\d|4[56]440)\\d{3}|(?:34|4[357])700\\d{3}|(?:2(?:[0-3]\\d|5[0-578]|6[01]|82)|3(?:1[3-68]|[23][2-68]|4[23568])|4(?:[23][2-68]|4[3-68]|5[2568]|6[25-8]|7[24-68]|8[4-68]))\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "7(?:3555|(?:474|9[019]7)7)\\d{3}|7(?:[0-25-8]\\d\\d|3(?:[1-478]\\d|6[01])|4(?:2\\d|60|7[01578])|9(?:[2-4]\\d|5[01]|7[015]))\\d{4}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{5}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "5\\d{7}", lengths: &[8] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "8(?:0[1-9]|[1-9]\\d)\\d{5}", lengths: &[8] }),
+    ] },
+    // ML
+    Metadata { general: "[24-9]\\d{7}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})", leading_digits: &["67[057-9]|74[045]", "67(?:0[09]|[59]9|77|8[89])|74(?:0[02]|44|55)"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[24-9]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2(?:07[0-8]|12[67])\\d{4}|(?:2(?:02|1[4-689])|4(?:0[0-4]|4[1-59]))\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "2(?:0(?:01|79)|17\\d)\\d{4}|(?:5[0-3]|[679]\\d|8[2-59])\\d{6}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80\\d{6}", lengths: &[8] }),
+    ] },
+    // MM
+    Metadata { general: "1\\d{5,7}|95\\d{6}|(?:[4-7]|9[0-46-9])\\d{6,8}|(?:2|8\\d)\\d{5,8}", lengths: &[6, 7, 8, 9, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{2})(\\d{3})", leading_digits: &["16|2"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{3})", leading_digits: &["4(?:[2-46]|5[3-5])|5|6(?:[1-689]|7[235-7])|7(?:[0-4]|5[2-7])|8[1-5]|(?:60|86)[23]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{3,4})", leading_digits: &["[12]|452|678|86", "[12]|452|6788|86"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3,4})", leading_digits: &["[4-7]|8[1-35]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{4,6})", leading_digits: &["9(?:2[0-4]|[35-9]|4[137-9])"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d)(\\d{4})(\\d{4})", leading_digits: &["2"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["8"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["92"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d)(\\d{5})(\\d{4})", leading_digits: &["9"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:1(?:(?:12|[28]\\d|3[56]|7[3-6]|9[0-6])\\d|4(?:2[29]|7[0-2]|83)|6)|2(?:2(?:00|8[34])|4(?:0\\d|22|7[0-2]|83)|51\\d\\d)|4(?:2(?:2\\d\\d|48[013])|3(?:20\\d|4(?:70|83)|56)|420\\d|5(?:2\\d|470))|6(?:0(?:[23]|88\\d)|(?:124|[56]2\\d)\\d|2472|3(?:20\\d|470)|4(?:2[04]\\d|472)|7(?:3\\d\\d|4[67]0|8(?:[01459]\\d|8))))\\d{4}|5(?:2(?:2\\d{5,6}|47[02]\\d{4})|(?:3472|4(?:2(?:1|86)|470)|522\\d|6(?:20\\d|483)|7(?:20\\d|48[01])|8(?:20\\d|47[02])|9(?:20\\d|470))\\d{4})|7(?:(?:0470|4(?:25\\d|470)|5(?:202|470|96\\d))\\d{4}|1(?:20\\d{4,5}|4(?:70|83)\\d{4}))|8(?:1(?:2\\d{5,6}|4(?:10|7[01]\\d)\\d{3})|2(?:2\\d{5,6}|(?:320|490\\d)\\d{3})|(?:3(?:2\\d\\d|470)|4[24-7]|5(?:(?:2\\d|51)\\d|4(?:[1-35-9]\\d|4[0-57-9]))|6[23])\\d{4})|(?:1[2-6]\\d|4(?:2[24-8]|3[2-7]|[46][2-6]|5[3-5])|5(?:[27][2-8]|3[2-68]|4[24-8]|5[23]|6[2-4]|8[24-7]|9[2-7])|6(?:[19]20|42[03-6]|(?:52|7[45])\\d)|7(?:[04][24-8]|[15][2-7]|22|3[2-4])|8(?:1[2-689]|2[2-8]|(?:[35]2|64)\\d))\\d{4}|25\\d{5,6}|(?:2[2-9]|6(?:1[2356]|[24][2-6]|3[24-6]|5[2-4]|6[2-8]|7[235-7]|8[245]|9[24])|8(?:3[24]|5[245]))\\d{4}", lengths: &[6, 7, 8, 9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:17[01]|9(?:2(?:[0-4]|[56]\\d\\d)|(?:3(?:[0-36]|4\\d)|(?:6\\d|8[89]|9[4-8])\\d|7(?:3|40|[5-9]\\d))\\d|4(?:(?:[0245]\\d|[1379])\\d|88)|5[0-6])\\d)\\d{4}|9[69]1\\d{6}|9(?:[68]\\d|9[089])\\d{5}", lengths: &[7, 8, 9, 10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80080(?:0[1-9]|2\\d)\\d{3}", lengths: &[10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "1333\\d{4}", lengths: &[8] }),
+    ] },
+    // MN
+    Metadata { general: "[12]\\d{7,9}|[5-9]\\d{7}", lengths: &[8, 9, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{4})", leading_digits: &["[12]1"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[5-9]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{5,6})", leading_digits: &["[12]2[1-3]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{5,6})", leading_digits: &["[12](?:27|3[2-8]|4[2-68]|5[1-4689])", "[12](?:27|3[2-8]|4[2-68]|5[1-4689])[0-3]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{5})(\\d{4,5})", leading_digits: &["[12]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "[12]2[1-3]\\d{5,6}|(?:(?:[12](?:1|27)|5[368])\\d\\d|7(?:0(?:[0-5]\\d|7[078]|80)|128))\\d{4}|[12](?:3[2-8]|4[2-68]|5[1-4689])\\d{6,7}", lengths: &[8, 9, 10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "92[0139]\\d{5}|(?:5[05]|6[069]|7[28]|8[0135689]|9[013-9])\\d{6}", lengths: &[8] }),
+        (PhoneType::Voip, NumberDesc { pattern: "712[0-79]\\d{4}|7(?:1[013-9]|[5-79]\\d)\\d{5}", lengths: &[8] }),
+    ] },
+    // MO
+    Metadata { general: "0800\\d{3}|(?:28|[68]\\d)\\d{6}", lengths: &[7, 8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})(\\d{3})", leading_digits: &["0"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[268]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:28[2-9]|8(?:11|[2-57-9]\\d))\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "6800[0-79]\\d{3}|6(?:[235]\\d\\d|6(?:0[0-5]|[1-9]\\d)|8(?:0[1-9]|[14-8]\\d|2[5-9]|[39][0-4]))\\d{4}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "0800\\d{3}", lengths: &[7] }),
+    ] },
+    // MP
+    Metadata { general: "[58]\\d{9}|(?:67|90)0\\d{7}", lengths: &[10], leading_digits: Some("670"), national_prefix_for_parsing: Some("([2-9]\\d{6})$|1"), national_prefix_transform_rule: Some("670$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "670(?:2(?:3[3-7]|56|8[4-8])|32[1-38]|4(?:33|8[348])|5(?:32|55|88)|6(?:64|70|82)|78[3589]|8[3-9]8|989)\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "670(?:2(?:3[3-7]|56|8[4-8])|32[1-38]|4(?:33|8[348])|5(?:32|55|88)|6(?:64|70|82)|78[3589]|8[3-9]8|989)\\d{4}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+    ] },
+    // MQ
+    Metadata { general: "(?:596\\d|7091)\\d{5}|(?:69|[89]\\d)\\d{7}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[5-79]|8(?:0[6-9]|[36])"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["8"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:596(?:[03-7]\\d|1[05]|2[7-9]|8[0-39]|9[04-9])|80[6-9]\\d\\d|9(?:477[6-9]|767[4589]))\\d{4}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:69[67]\\d\\d|7091[0-3])\\d{4}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[0-5]\\d{6}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "8[129]\\d{7}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "9(?:397[0-3]|477[0-5]|76(?:6\\d|7[0-367]))\\d{4}", lengths: &[9] }),
+    ] },
+    // MR
+    Metadata { general: "(?:[2-4]\\d\\d|800)\\d{5}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[2-48]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:25[08]|35\\d|45[1-7])\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "[2-4][0-46-9]\\d{6}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{5}", lengths: &[8] }),
+    ] },
+    // MS
+    Metadata { general: "(?:[58]\\d\\d|664|900)\\d{7}", lengths: &[10], leading_digits: Some("664"), national_prefix_for_parsing: Some("([34]\\d{6})$|1"), national_prefix_transform_rule: Some("664$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "6644(?:1[0-3]|91)\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "664(?:3(?:49|9[1-6])|49[2-6])\\d{4}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+    ] },
+    // MT
+    Metadata { general: "3550\\d{4}|(?:[2579]\\d\\d|800)\\d{5}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[2357-9]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "20(?:3[1-4]|6[059])\\d{4}|2(?:0[19]|[1-357]\\d|60)\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:7(?:210|[79]\\d\\d)|9(?:[29]\\d\\d|69[67]|8(?:1[1-3]|89|97)))\\d{4}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800(?:02|[3467]\\d)\\d{3}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "5(?:0(?:0(?:37|43)|(?:6\\d|70|9[0168])\\d)|[12]\\d0[1-5])\\d{3}", lengths: &[8] }),
+        (PhoneType::Voip, NumberDesc { pattern: "3550\\d{4}", lengths: &[8] }),
+        (PhoneType::Pager, NumberDesc { pattern: "7117\\d{4}", lengths: &[8] }),
+        (PhoneType::Uan, NumberDesc { pattern: "501\\d{5}", lengths: &[8] }),
+    ] },
+    // MU
+    Metadata { general: "(?:[57]|8\\d\\d)\\d{7}|[2-468]\\d{6}", lengths: &[7, 8, 10], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[2-46]|8[013]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[57]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{5})(\\d{5})", leading_digits: &["8"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2(?:[0346-8]\\d|1[0-8])|4(?:[013568]\\d|2[4-8]|71|90)|54(?:[3-5]\\d|71)|6\\d\\d|8(?:14|3[129]))\\d{4}", lengths: &[7, 8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "5(?:4(?:2[1-389]|7[1-9])|87[15-8])\\d{4}|(?:5(?:2[5-9]|4[3-689]|[57]\\d|8[0-689]|9[0-8])|7(?:0[0-7]|3[013]))\\d{5}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "802\\d{7}|80[0-2]\\d{4}", lengths: &[7, 10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "30\\d{5}", lengths: &[7] }),
+        (PhoneType::Voip, NumberDesc { pattern: "3(?:20|9\\d)\\d{4}", lengths: &[7] }),
+        (PhoneType::Pager, NumberDesc { pattern: "219\\d{4}", lengths: &[7] }),
+    ] },
+    // MV
+    Metadata { general: "(?:800|9[0-57-9]\\d)\\d{7}|[34679]\\d{6}", lengths: &[7, 10], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[34679]"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: Some("${1}-${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["[89]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:3(?:0[0-4]|3[0-59])|6(?:[58][024689]|6[024-68]|7[02468]))\\d{4}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:46[46]|[79]\\d\\d)\\d{4}", lengths: &[7] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{7}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900\\d{7}", lengths: &[10] }),
+        (PhoneType::Uan, NumberDesc { pattern: "4(?:0[01]|50)\\d{4}", lengths: &[7] }),
+    ] },
+    // MW
+    Metadata { general: "(?:[1289]\\d|31|77)\\d{7}|1\\d{6}", lengths: &[7, 9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{3})", leading_digits: &["1[2-9]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["2"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[137-9]"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:1[2-9]|2[12]\\d\\d)\\d{5}", lengths: &[7, 9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "111\\d{6}|(?:31|77|[89][89])\\d{7}", lengths: &[9] }),
+    ] },
+    // MX
+    Metadata { general: "[2-9]\\d{9}", lengths: &[10], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{5})", leading_digits: &["53"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{2})(\\d{4})(\\d{4})", leading_digits: &["33|5[56]|81"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["[2-9]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2(?:0[01]|2\\d|3[1-35-8]|4[13-9]|7[1-689]|8[1-578]|9[467])|3(?:1[1-79]|[2458][1-9]|3\\d|7[1-8]|9[1-5])|4(?:1[1-57-9]|[267][1-9]|3[1-8]|[45]\\d|8[1-35-9]|9[2-689])|5(?:[56]\\d|88|9[1-79])|6(?:1[2-68]|[2-4][1-9]|5[1-36-9]|6[0-57-9]|7[1-7]|8[67]|9[4-8])|7(?:[1346][1-9]|[27]\\d|5[13-9]|8[1-69]|9[17])|8(?:1\\d|2[13-689]|3[1-6]|4[124-6]|6[1246-9]|7[0-378]|9[12479])|9(?:1[346-9]|2[1-4]|3[2-46-8]|5[1348]|[69]\\d|7[12]|8[1-8]))\\d{7}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:2(?:2\\d|3[1-35-8]|4[13-9]|7[1-689]|8[1-578]|9[467])|3(?:1[1-79]|[2458][1-9]|3\\d|7[1-8]|9[1-5])|4(?:1[1-57-9]|[267][1-9]|3[1-8]|[45]\\d|8[1-35-9]|9[2-689])|5(?:[56]\\d|88|9[1-79])|6(?:1[2-68]|[2-4][1-9]|5[1-36-9]|6[0-57-9]|7[1-7]|8[67]|9[4-8])|7(?:[1346][1-9]|[27]\\d|5[13-9]|8[1-69]|9[17])|8(?:1\\d|2[13-689]|3[1-6]|4[124-6]|6[1246-9]|7[0-378]|9[12479])|9(?:1[346-9]|2[1-4]|3[2-46-8]|5[1348]|[69]\\d|7[12]|8[1-8]))\\d{7}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|88)\\d{7}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900\\d{7}", lengths: &[10] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "300\\d{7}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "500\\d{7}", lengths: &[10] }),
+    ] },
+    // MY
+    Metadata { general: "1\\d{8,9}|(?:3\\d|[4-9])\\d{7}", lengths: &[8, 9, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{4})", leading_digits: &["[4-79]"], format: "${1}-${2} ${3}", national_format: "0${1}-${2} ${3}", intl_format: Some("${1}-${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3,4})", leading_digits: &["1(?:[02469]|[378][1-9]|53)|8", "1(?:[02469]|[37][1-9]|53|8(?:[1-46-9]|5[7-9]))|8"], format: "${1}-${2} ${3}", national_format: "0${1}-${2} ${3}", intl_format: Some("${1}-${2} ${3}") },
+        NumberFormat { pattern: "(\\d)(\\d{4})(\\d{4})", leading_digits: &["3"], format: "${1}-${2} ${3}", national_format: "0${1}-${2} ${3}", intl_format: Some("${1}-${2} ${3}") },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{2})(\\d{4})", leading_digits: &["1(?:[367]|80)"], format: "${1}-${2}-${3}-${4}", national_format: "${1}-${2}-${3}-${4}", intl_format: Some("${1}-${2}-${3}-${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["15"], format: "${1}-${2} ${3}", national_format: "0${1}-${2} ${3}", intl_format: Some("${1}-${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{4})(\\d{4})", leading_digits: &["1"], format: "${1}-${2} ${3}", national_format: "0${1}-${2} ${3}", intl_format: Some("${1}-${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "427[01]\\d{4}|(?:3(?:2[0-36-9]|3[0-368]|4[0-278]|5[0-24-8]|6[0-467]|7[1246-9]|8\\d|9[0-57])\\d|4(?:2[0-689]|[3-79]\\d|8[1-35689])|5(?:2[0-589]|[3468]\\d|5[0-489]|7[1-9]|9[23])|6(?:2[2-9]|3[1357-9]|[46]\\d|5[0-6]|7[0-35-9]|85|9[015-8])|7(?:[2579]\\d|3[03-68]|4[0-8]|6[5-9]|8[0-35-9])|8(?:[24][2-8]|3[2-5]|5[2-7]|6[2-589]|7[2-578]|[89][2-9])|9(?:0[57]|13|[25-7]\\d|[3489][0-8]))\\d{5}", lengths: &[8, 9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "1(?:1888[689]|4400|8(?:47|8[27])[0-4])\\d{4}|1(?:0(?:[23568]\\d|4[0-6]|7[016-9]|9[0-8])|1(?:[1-5]\\d\\d|6(?:0[5-9]|[1-9]\\d)|7(?:[0-4]\\d|5[0-7]))|(?:[269]\\d|[37][1-9]|4[235-9])\\d|5(?:31|9\\d\\d)|8(?:1[23]|[236]\\d|4[06]|5(?:46|[7-9])|7[016-9]|8[01]|9[0-8]))\\d{5}", lengths: &[9, 10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "1[378]00\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "1600\\d{6}", lengths: &[10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "15(?:4(?:6[0-4]\\d|8(?:0[125]|[17]\\d|21|3[01]|4[01589]|5[014]|6[02]))|6(?:32[0-6]|78\\d))\\d{4}", lengths: &[10] }),
+    ] },
+    // MZ
+    Metadata { general: "(?:2|8\\d)\\d{7}", lengths: &[8, 9], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3,4})", leading_digits: &["2|8[2-79]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["8"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2(?:[1346]\\d|5[0-2]|[78][12]|93)\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "8[2-79]\\d{7}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{6}", lengths: &[9] }),
+    ] },
+    // NA
+    Metadata { general: "[68]\\d{7,8}", lengths: &[8, 9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["88"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3,4})", leading_digits: &["6"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["87"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["8"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "64426\\d{3}|6(?:1(?:2[2-7]|3[01378]|4[0-4])|254|32[0237]|4(?:27|41|5[25])|52[236-8]|626|7(?:2[2-4]|30))\\d{4,5}|6(?:1(?:(?:0\\d|2[0189]|3[24-69]|4[5-9])\\d|17|69|7[014])|2(?:17|5[0-36-8]|69|70)|3(?:17|2[14-689]|34|6[289]|7[01]|81)|4(?:17|2[0-2]|4[06]|5[0137]|69|7[01])|5(?:17|2[0459]|69|7[01])|6(?:17|25|38|42|69|7[01])|7(?:17|2[569]|3[13]|6[89]|7[01]))\\d{4}", lengths: &[8, 9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:60|8[1245])\\d{7}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80\\d{7}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "8701\\d{5}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "8(?:3\\d\\d|86)\\d{5}", lengths: &[8, 9] }),
+    ] },
+    // NC
+    Metadata { general: "(?:050|[2-57-9]\\d\\d)\\d{3}", lengths: &[6], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})", leading_digits: &["5[6-8]"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[02-57-9]"], format: "${1}.${2}.${3}", national_format: "${1}.${2}.${3}", intl_format: Some("${1}.${2}.${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2[03-9]|3[0-5]|4[1-7]|88)\\d{4}", lengths: &[6] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:[579]\\d|8[0-79])\\d{4}", lengths: &[6] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "050\\d{3}", lengths: &[6] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "36\\d{4}", lengths: &[6] }),
+    ] },
+    // NE
+    Metadata { general: "[027-9]\\d{7}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["08"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[089]|2[013]|7[0467]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2(?:0(?:20|3[1-8]|4[13-5]|5[14]|6[14578]|7[1-578])|1(?:4[145]|5[14]|6[14-68]|7[169]|88))\\d{4}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:23|7[0467]|[89]\\d)\\d{6}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "08\\d{6}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "09\\d{6}", lengths: &[8] }),
+    ] },
+    // NF
+    Metadata { general: "[13]\\d{5}", lengths: &[6], leading_digits: None, national_prefix_for_parsing: Some("([0-258]\\d{4})$"), national_prefix_transform_rule: Some("3$1"), formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{4})", leading_digits: &["1[0-3]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d)(\\d{5})", leading_digits: &["[13]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:1(?:06|17|28|39)|3[0-2]\\d)\\d{3}", lengths: &[6] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:14|3[58])\\d{4}", lengths: &[6] }),
+    ] },
+    // NG
+    Metadata { general: "(?:20|9\\d)\\d{8}|[78]\\d{9,13}", lengths: &[10, 11, 12, 13, 14], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3,4})", leading_digits: &["[7-9]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["20[129]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{2})(\\d{4})", leading_digits: &["2"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})(\\d{4,5})", leading_digits: &["[78]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{5})(\\d{5,6})", leading_digits: &["[78]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "20(?:[1259]\\d|3[013-9]|4[1-8]|6[024-689]|7[1-79]|8[2-9])\\d{6}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:702[0-24-9]|819[01])\\d{6}|(?:7(?:0[13-9]|[12]\\d)|8(?:0[1-9]|1[0-8])|9(?:0[1-9]|1[1-6]))\\d{7}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{7,11}", lengths: &[10, 11, 12, 13, 14] }),
+        (PhoneType::Uan, NumberDesc { pattern: "700\\d{7,11}", lengths: &[10, 11, 12, 13, 14] }),
+    ] },
+    // NI
+    Metadata { general: "(?:1800|[25-8]\\d{3})\\d{4}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[125-8]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2\\d{7}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:5(?:5[0-7]|[78]\\d)|6(?:20|3[035]|4[045]|5[05]|77|8[1-9]|9[059])|(?:7[5-8]|8\\d)\\d)\\d{5}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "1800\\d{4}", lengths: &[8] }),
+    ] },
+    // NL
+    Metadata { general: "(?:[124-7]\\d\\d|3(?:[02-9]\\d|1[0-8]))\\d{6}|8\\d{6,9}|9\\d{6,10}|1\\d{4,5}", lengths: &[5, 6, 7, 8, 9, 10, 11], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})", leading_digits: &["1[238]|[34]"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{2})(\\d{3,4})", leading_digits: &["14"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d{6})", leading_digits: &["1"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{3})(\\d{4,7})", leading_digits: &["[89]0"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{7})", leading_digits: &["66"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d)(\\d{8})", leading_digits: &["6"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["1[16-8]|2[259]|3[124]|4[17-9]|5[124679]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["[1-578]|91"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{5})", leading_digits: &["9"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:1(?:[035]\\d|1[13-578]|6[124-8]|7[24]|8[0-467])|2(?:[0346]\\d|2[2-46-9]|5[125]|9[479])|3(?:[03568]\\d|1[3-8]|2[01]|4[1-8])|4(?:[0356]\\d|1[1-368]|7[58]|8[15-8]|9[23579])|5(?:[0358]\\d|[19][1-9]|2[1-57-9]|4[13-8]|6[126]|7[0-3578])|7\\d\\d)\\d{6}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:6[1-58]|970\\d)\\d{7}", lengths: &[9, 11] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{4,7}", lengths: &[7, 8, 9, 10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90[069]\\d{4,7}", lengths: &[7, 8, 9, 10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "(?:85|91)\\d{7}", lengths: &[9] }),
+        (PhoneType::Pager, NumberDesc { pattern: "66\\d{7}", lengths: &[9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "140(?:1[035]|2[0346]|3[03568]|4[0356]|5[0358]|8[458])|(?:140(?:1[16-8]|2[259]|3[124]|4[17-9]|5[124679]|7)|8[478]\\d{6})\\d", lengths: &[5, 6, 9] }),
+    ] },
+    // NO
+    Metadata { general: "(?:0|[2-9]\\d{3})\\d{4}", lengths: &[5, 8], leading_digits: Some("[02-689]|7[0-8]"), national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{3})", leading_digits: &["8"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[2-79]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2[1-4]|3[1-3578]|5[1-35-7]|6[1-4679]|7[0-8])\\d{6}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:4[015-8]|9\\d)\\d{6}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[01]\\d{5}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "82[09]\\d{5}", lengths: &[8] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "810(?:0[0-6]|[2-8]\\d)\\d{3}", lengths: &[8] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "880\\d{5}", lengths: &[8] }),
+        (PhoneType::Voip, NumberDesc { pattern: "85[0-5]\\d{5}", lengths: &[8] }),
+        (PhoneType::Uan, NumberDesc { pattern: "(?:0[235-9]|81(?:0(?:0[7-9]|1\\d)|5\\d\\d))\\d{3}", lengths: &[5, 8] }),
+        (PhoneType::Voicemail, NumberDesc { pattern: "81[23]\\d{5}", lengths: &[8] }),
+    ] },
+    // NP
+    Metadata { general: "(?:1\\d|9)\\d{9}|[1-9]\\d{7}", lengths: &[8, 10, 11], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{7})", leading_digits: &["1[2-6]"], format: "${1}-${2}", national_format: "0${1}-${2}", intl_format: Some("${1}-${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{6})", leading_digits: &["1[01]|[2-8]|9(?:[1-59]|[67][2-6])"], format: "${1}-${2}", national_format: "0${1}-${2}", intl_format: Some("${1}-${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{7})", leading_digits: &["9"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: Some("${1}-${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{2})(\\d{5})", leading_digits: &["1"], format: "${1}-${2}-${3}", national_format: "${1}-${2}-${3}", intl_format: None },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:1[0-6]\\d|99[02-6])\\d{5}|(?:2[13-79]|3[135-8]|4[146-9]|5[135-7]|6[13-9]|7[15-9]|8[1-46-9]|9[1-7])[2-6]\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "9(?:00|6[0-3]|7[0-24-6]|8[0-24-68])\\d{7}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "1(?:66001|800\\d\\d)\\d{5}", lengths: &[11] }),
+    ] },
+    // NR
+    Metadata { general: "(?:222|444|(?:55|8\\d)\\d|666|777|999)\\d{4}", lengths: &[7], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[24-9]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "444\\d{4}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:222|55[3-9]|666|777|8\\d\\d|999)\\d{4}", lengths: &[7] }),
+    ] },
+    // NU
+    Metadata { general: "(?:[4-7]|888\\d)\\d{3}", lengths: &[4, 7], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["8"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "[47]\\d{3}", lengths: &[4] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:[56]|888[1-9])\\d{3}", lengths: &[4, 7] }),
+    ] },
+    // NZ
+    Metadata { general: "[1289]\\d{9}|50\\d{5}(?:\\d{2,3})?|[27-9]\\d{7,8}|(?:[34]\\d|6[0-35-9])\\d{6}|8\\d{4,6}", lengths: &[5, 6, 7, 8, 9, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3,8})", leading_digits: &["8[1-79]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2,3})", leading_digits: &["50[036-8]|8|90", "50(?:[0367]|88)|8|90"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{4})", leading_digits: &["24|[346]|7[2-57-9]|9[2-9]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3,4})", leading_digits: &["2(?:10|74)|[589]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3,4})(\\d{4})", leading_digits: &["1|2[028]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3,5})", leading_digits: &["2(?:[169]|7[0-35-9])|7"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "240\\d{5}|(?:3[2-79]|[49][2-9]|6[235-9]|7[2-57-9])\\d{6}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "2(?:[0-27-9]\\d|6)\\d{6,7}|2(?:1\\d|75)\\d{5}", lengths: &[8, 9, 10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "508\\d{6,7}|80\\d{6,8}", lengths: &[8, 9, 10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "(?:1[13-57-9]\\d{5}|50(?:0[08]|30|66|77|88))\\d{3}|90\\d{6,8}", lengths: &[7, 8, 9, 10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "70\\d{7}", lengths: &[9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "8(?:1[16-9]|22|3\\d|4[045]|5[459]|6[235-9]|7[0-3579]|90)\\d{2,7}", lengths: &[5, 6, 7, 8, 9, 10] }),
+    ] },
+    // OM
+    Metadata { general: "(?:1505|[279]\\d{3}|500)\\d{4}|800\\d{5,6}", lengths: &[7, 8, 9], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4,6})", leading_digits: &["[58]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{6})", leading_digits: &["2"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[179]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2[1-6]\\d{6}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:1505|90[1-9]\\d)\\d{4}|(?:7[124-9]|9[1-9])\\d{6}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8007\\d{4,5}|(?:500|800[05])\\d{4}", lengths: &[7, 8, 9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900\\d{5}", lengths: &[8] }),
+    ] },
+    // PA
+    Metadata { general: "(?:00800|8\\d{3})\\d{6}|[68]\\d{7}|[1-57-9]\\d{6}", lengths: &[7, 8, 10, 11], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[1-57-9]"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: Some("${1}-${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[68]"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: Some("${1}-${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["8"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:1(?:0\\d|1[0479]|2[37]|3[0137]|4[17]|5[05]|6[058]|7[0167]|8[2358]|9[1389])|2(?:[0235-79]\\d|1[0-7]|4[013-9]|8[02-9])|3(?:[047-9]\\d|1[0-8]|2[0-5]|33|5[0-35]|6[068])|4(?:00|3[0-579]|4\\d|7[0-57-9])|5(?:[01]\\d|2[0-7]|[56]0|79)|7(?:0[09]|2[0-26-8]|3[03]|4[04]|5[05-9]|6[0156]|7[0-24-9]|8[4-9]|90)|8(?:09|2[89]|3\\d|4[0-24-689]|5[014]|8[02])|9(?:0[5-9]|1[0135-8]|2[036-9]|3[35-79]|40|5[0457-9]|6[05-9]|7[04-9]|8[35-8]|9\\d))\\d{4}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:1[16]1|21[89]|6\\d{3}|8(?:1[01]|7[23]))\\d{4}", lengths: &[7, 8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{4,5}|(?:00800|800\\d)\\d{6}", lengths: &[7, 8, 10, 11] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "(?:8(?:22|55|60|7[78]|86)|9(?:00|81))\\d{4}", lengths: &[7] }),
+    ] },
+    // PE
+    Metadata { general: "(?:[14-8]|9\\d)\\d{7}", lengths: &[8, 9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{5})", leading_digits: &["80"], format: "${1} ${2}", national_format: "(0${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d)(\\d{7})", leading_digits: &["1"], format: "${1} ${2}", national_format: "(0${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{6})", leading_digits: &["[4-8]"], format: "${1} ${2}", national_format: "(0${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["9"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:(?:(?:4[34]|5[14])[0-8]|687)\\d|7(?:173|(?:3[0-8]|55)\\d)|8(?:10[05689]|6(?:0[06-9]|1[6-9]|29)|7(?:0[0569]|[56]0)))\\d{4}|(?:1[0-8]|4[12]|5[236]|6[1-7]|7[246]|8[2-4])\\d{6}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "9\\d{8}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{5}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "805\\d{5}", lengths: &[8] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "801\\d{5}", lengths: &[8] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "80[24]\\d{5}", lengths: &[8] }),
+    ] },
+    // PF
+    Metadata { general: "4\\d{5}(?:\\d{2})?|8\\d{7,8}", lengths: &[6, 8, 9], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["44"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["4|8[7-9]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["8"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "4(?:0[4-689]|9[4-68])\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "8[7-9]\\d{6}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[0-5]\\d{6}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "499\\d{5}", lengths: &[8] }),
+        (PhoneType::Uan, NumberDesc { pattern: "44\\d{4}", lengths: &[6] }),
+    ] },
+    // PG
+    Metadata { general: "(?:180|[78]\\d{3})\\d{4}|(?:[2-589]\\d|64)\\d{5}", lengths: &[7, 8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["18|[2-69]|85"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[78]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:(?:3[0-2]|4[257]|5[34]|9[78])\\d|64[1-9]|85[02-46-9])\\d{4}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:7\\d|8[1-48])\\d{6}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "180\\d{4}", lengths: &[7] }),
+        (PhoneType::Voip, NumberDesc { pattern: "2(?:0[0-57]|7[568])\\d{4}", lengths: &[7] }),
+        (PhoneType::Pager, NumberDesc { pattern: "27[01]\\d{4}", lengths: &[7] }),
+    ] },
+    // PH
+    Metadata { general: "(?:[2-7]|9\\d)\\d{8}|2\\d{5}|(?:1800|8)\\d{7,9}", lengths: &[6, 8, 9, 10, 11, 12, 13], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{5})", leading_digits: &["2"], format: "${1} ${2}", national_format: "(0${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4,6})", leading_digits: &["3(?:23|39|46)|4(?:2[3-6]|[35]9|4[26]|76)|544|88[245]|(?:52|64|86)2", "3(?:230|397|461)|4(?:2(?:35|[46]4|51)|396|4(?:22|63)|59[347]|76[15])|5(?:221|446)|642[23]|8(?:622|8(?:[24]2|5[13]))"], format: "${1} ${2}", national_format: "(0${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{5})(\\d{4})", leading_digits: &["346|4(?:27|9[35])|883", "3469|4(?:279|9(?:30|56))|8834"], format: "${1} ${2}", national_format: "(0${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d)(\\d{4})(\\d{4})", leading_digits: &["2"], format: "${1} ${2} ${3}", national_format: "(0${1}) ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["[3-7]|8[2-8]"], format: "${1} ${2} ${3}", national_format: "(0${1}) ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["[89]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})(\\d{4})", leading_digits: &["1"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{1,2})(\\d{3})(\\d{4})", leading_digits: &["1"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:(?:2[3-8]|3[2-68]|4[2-9]|5[2-6]|6[2-58]|7[24578])\\d{3}|88(?:22\\d\\d|42))\\d{4}|(?:2|8[2-8]\\d\\d)\\d{5}", lengths: &[6, 8, 9, 10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:8(?:1[37]|9[5-8])|9(?:0[5-9]|1[0-24-9]|[235-7]\\d|4[2-9]|8[135-9]|9[1-9]))\\d{7}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "1800\\d{7,9}", lengths: &[11, 12, 13] }),
+    ] },
+    // PK
+    Metadata { general: "122\\d{6}|[24-8]\\d{10,11}|9(?:[013-9]\\d{8,10}|2(?:[01]\\d\\d|2(?:[06-8]\\d|1[01]))\\d{7})|(?:[2-8]\\d{3}|92(?:[0-7]\\d|8[1-9]))\\d{6}|[24-9]\\d{8}|[89]\\d{7}", lengths: &[8, 9, 10, 11, 12], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{2,7})", leading_digits: &["[89]0"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{5})", leading_digits: &["1"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{6,7})", leading_digits: &["2(?:3[2358]|4[2-4]|9[2-8])|45[3479]|54[2-467]|60[468]|72[236]|8(?:2[2-689]|3[23578]|4[3478]|5[2356])|9(?:2[2-8]|3[27-9]|4[2-6]|6[3569]|9[25-8])", "9(?:2[3-8]|98)|(?:2(?:3[2358]|4[2-4]|9[2-8])|45[3479]|54[2-467]|60[468]|72[236]|8(?:2[2-689]|3[23578]|4[3478]|5[2356])|9(?:22|3[27-9]|4[2-6]|6[3569]|9[25-7]))[2-9]"], format: "${1} ${2}", national_format: "(0${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{7,8})", leading_digits: &["(?:2[125]|4[0-246-9]|5[1-35-7]|6[1-8]|7[14]|8[16]|91)[2-9]"], format: "${1} ${2}", national_format: "(0${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{5})(\\d{5})", leading_digits: &["58"], format: "${1} ${2}", national_format: "(0${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{7})", leading_digits: &["3"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["2[125]|4[0-246-9]|5[1-35-7]|6[1-8]|7[14]|8[16]|91"], format: "${1} ${2} ${3} ${4}", national_format: "(0${1}) ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[24-9]"], format: "${1} ${2} ${3} ${4}", national_format: "(0${1}) ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:(?:21|42)[2-9]|58[126])\\d{7}|(?:2[25]|4[0146-9]|5[1-35-7]|6[1-8]|7[14]|8[16]|91)[2-9]\\d{6,7}|(?:2(?:3[2358]|4[2-4]|9[2-8])|45[3479]|54[2-467]|60[468]|72[236]|8(?:2[2-689]|3[23578]|4[3478]|5[2356])|9(?:2[2-8]|3[27-9]|4[2-6]|6[3569]|9[25-8]))[2-9]\\d{5,6}", lengths: &[9, 10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "3(?:[0-247]\\d|3[0-79]|55|64)\\d{7}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{5}(?:\\d{3})?", lengths: &[8, 11] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900\\d{5}", lengths: &[8] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "122\\d{6}", lengths: &[9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "(?:2(?:[125]|3[2358]|4[2-4]|9[2-8])|4(?:[0-246-9]|5[3479])|5(?:[1-35-7]|4[2-467])|6(?:0[468]|[1-8])|7(?:[14]|2[236])|8(?:[16]|2[2-689]|3[23578]|4[3478]|5[2356])|9(?:1|22|3[27-9]|4[2-6]|6[3569]|9[2-7]))111\\d{6}", lengths: &[11, 12] }),
+    ] },
+    // PL
+    Metadata { general: "(?:6|8\\d\\d)\\d{7}|[1-9]\\d{6}(?:\\d{2})?|[26]\\d{5}", lengths: &[6, 7, 8, 9, 10], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{5})", leading_digits: &["19"], format: "${1}", national_format: "${1}", intl_format: Some("${1}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})", leading_digits: &["11|20|64"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{3})", leading_digits: &["(?:1[2-8]|2[2-69]|3[2-4]|4[1-468]|5[24-689]|6[1-3578]|7[14-7]|8[1-79]|9[145])1", "(?:1[2-8]|2[2-69]|3[2-4]|4[1-468]|5[24-689]|6[1-3578]|7[14-7]|8[1-79]|9[145])19"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2,3})", leading_digits: &["64"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["21|39|45|5[0137]|6[0469]|7[02389]|8(?:0[14]|8)"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["1[2-8]|[2-7]|8[1-79]|9[145]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3,4})", leading_digits: &["8"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "47\\d{7}|(?:1[2-8]|2[2-69]|3[2-4]|4[1-468]|5[24-689]|6[1-3578]|7[14-7]|8[1-79]|9[145])(?:[02-9]\\d{6}|1(?:[0-8]\\d{5}|9\\d{3}(?:\\d{2})?))", lengths: &[7, 9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "2131[89]\\d{4}|21(?:1[013-5]|2\\d|3[2-9])\\d{5}|(?:45|5[0137]|6[069]|7[2389]|88)\\d{7}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{6,7}", lengths: &[9, 10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "70[01346-8]\\d{6}", lengths: &[9] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "801\\d{6}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "39\\d{7}", lengths: &[9] }),
+        (PhoneType::Pager, NumberDesc { pattern: "64\\d{4,7}", lengths: &[6, 7, 8, 9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "804\\d{6}", lengths: &[9] }),
+    ] },
+    // PM
+    Metadata { general: "[45]\\d{5}|(?:708|8\\d\\d)\\d{6}", lengths: &[6, 9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[45]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["7"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["8"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:4[1-35-9]|5[0-47-9]|80[6-9]\\d\\d)\\d{4}", lengths: &[6, 9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:4[02-489]|5[02-9]|708(?:4[0-5]|5[0-6]))\\d{4}", lengths: &[6, 9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[0-5]\\d{6}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "8[129]\\d{7}", lengths: &[9] }),
+    ] },
+    // PR
+    Metadata { general: "(?:[589]\\d\\d|787)\\d{7}", lengths: &[10], leading_digits: Some("787|939"), national_prefix_for_parsing: Some("1"), national_prefix_transform_rule: None, formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:787|939)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:787|939)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+    ] },
+    // PS
+    Metadata { general: "[2489]2\\d{6}|(?:1\\d|5)\\d{8}", lengths: &[8, 9, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{4})", leading_digits: &["[2489]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["5"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})(\\d{3})", leading_digits: &["1"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:22[2-47-9]|42[45]|82[014-68]|92[3569])\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "5[69]\\d{7}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "1800\\d{6}", lengths: &[10] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "1700\\d{6}", lengths: &[10] }),
+    ] },
+    // PT
+    Metadata { general: "1693\\d{5}|(?:[26-9]\\d|30)\\d{7}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["2[12]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["16|[236-9]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2(?:[12]\\d|3[1-689]|4[1-59]|[57][1-9]|6[1-35689]|8[1-69]|9[1256])\\d{6}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "6(?:[06]92(?:30|9\\d)|[35]92(?:[049]\\d|3[034]))\\d{3}|(?:(?:16|6[0356])93|9(?:[1-36]\\d\\d|480))\\d{5}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[02]\\d{6}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "(?:6(?:0[178]|4[68])\\d|76(?:0[1-57]|1[2-47]|2[237]))\\d{5}", lengths: &[9] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "80(?:8\\d|9[1579])\\d{5}", lengths: &[9] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "884[0-4689]\\d{5}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "30\\d{7}", lengths: &[9] }),
+        (PhoneType::Pager, NumberDesc { pattern: "6(?:222\\d|89(?:00|88|99))\\d{4}", lengths: &[9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "70(?:38[01]|596|(?:7\\d|8[17])\\d)\\d{4}", lengths: &[9] }),
+        (PhoneType::Voicemail, NumberDesc { pattern: "600\\d{6}|6[06]92(?:0\\d|3[349]|49)\\d{3}", lengths: &[9] }),
+    ] },
+    // PW
+    Metadata { general: "(?:[24-8]\\d\\d|345|900)\\d{4}", lengths: &[7], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[2-9]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2(?:55|77)|345|488|5(?:35|44|87)|6(?:22|54|79)|7(?:33|47)|8(?:24|55|76)|900)\\d{4}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:(?:46|83)[0-5]|(?:6[2-4689]|78)0)\\d{4}|(?:45|77|88)\\d{5}", lengths: &[7] }),
+    ] },
+    // PY
+    Metadata { general: "59\\d{4,6}|9\\d{5,10}|(?:[2-46-8]\\d|5[0-8])\\d{4,7}", lengths: &[6, 7, 8, 9, 10, 11], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3,6})", leading_digits: &["[2-9]0"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{7})", leading_digits: &["[15]"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{2})(\\d{5})", leading_digits: &["[26]1|3[289]|4[1246-8]|7[1-3]|8[1-36]"], format: "${1} ${2}", national_format: "(0${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4,5})", leading_digits: &["2[279]|3[13-5]|4[359]|5|6(?:[34]|7[1-46-8])|7[46-8]|85"], format: "${1} ${2}", national_format: "(0${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3,4})", leading_digits: &["2[14-68]|3[26-9]|4[1246-8]|6(?:1|75)|7[1-35]|8[1-36]"], format: "${1} ${2} ${3}", national_format: "(0${1}) ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["87"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{6})", leading_digits: &["9(?:[5-79]|8[1-7])"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[2-8]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})(\\d{4})", leading_digits: &["9"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:[26]1|3[289]|4[1246-8]|7[1-3]|8[1-36])\\d{5,7}|(?:2(?:2[4-68]|[4-68]\\d|7[15]|9[1-5])|3(?:18|3[167]|4[2357]|51|[67]\\d)|4(?:3[12]|5[13]|9[1-47])|5(?:[1-4]\\d|5[02-4])|6(?:3[1-3]|44|7[1-8])|7(?:4[0-4]|5\\d|6[1-578]|75|8[0-8])|858)\\d{5,6}", lengths: &[7, 8, 9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "9(?:51|6[129]|7[1-6]|8[1-7]|9[1-5])\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "9800\\d{5,7}", lengths: &[9, 10, 11] }),
+        (PhoneType::Voip, NumberDesc { pattern: "8700[0-4]\\d{4}", lengths: &[9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "[2-9]0\\d{4,7}", lengths: &[6, 7, 8, 9] }),
+    ] },
+    // QA
+    Metadata { general: "800\\d{4}|(?:2|800)\\d{6}|(?:0080|[3-7])\\d{7}", lengths: &[7, 8, 9, 11], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["2[136]|8"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[3-7]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "4(?:(?:[014]\\d\\d|999)\\d|2022)\\d{3}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "[35-7]\\d{7}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{4}|(?:0080[01]|800)\\d{6}", lengths: &[7, 9, 11] }),
+        (PhoneType::Pager, NumberDesc { pattern: "2[136]\\d{5}", lengths: &[7] }),
+    ] },
+    // RE
+    Metadata { general: "709\\d{6}|(?:26|[689]\\d)\\d{7}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[26-9]"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "26(?:2\\d\\d|3(?:0\\d|1[0-6]))\\d{4}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:69(?:2\\d\\d|3(?:[06][0-6]|1[0-3]|2[0-2]|3[0-39]|4\\d|5[0-5]|7[0-37]|8[0-8]|9[0-479]))|7092[0-3])\\d{4}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80\\d{7}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "89[1-37-9]\\d{6}", lengths: &[9] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "8(?:1[019]|2[0156]|84|90)\\d{6}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "9(?:399[0-3]|479[0-6]|76(?:2[278]|3[0-37]))\\d{4}", lengths: &[9] }),
+    ] },
+    // RO
+    Metadata { general: "(?:[236-8]\\d|90)\\d{7}|[23]\\d{5}", lengths: &[6, 9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3})", leading_digits: &["2[3-6]", "2[3-6]\\d9"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{4})", leading_digits: &["219|31"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["[23]1"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[236-9]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "[23][13-6]\\d{7}|(?:2(?:19\\d|[3-6]\\d9)|31\\d\\d)\\d\\d", lengths: &[6, 9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:630|702)0\\d{5}|(?:6(?:00|2\\d)|7(?:0[013-9]|1[0-3]|[2-7]\\d|8[03-8]|9[0-39]))\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{6}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90[0136]\\d{6}", lengths: &[9] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "801\\d{6}", lengths: &[9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "(?:37\\d|80[578])\\d{6}", lengths: &[9] }),
+    ] },
+    // RS
+    Metadata { general: "38[02-9]\\d{6,9}|6\\d{7,9}|90\\d{4,8}|38\\d{5,6}|(?:7\\d\\d|800)\\d{3,9}|(?:[12]\\d|3[0-79])\\d{5,10}", lengths: &[6, 7, 8, 9, 10, 11, 12], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3,9})", leading_digits: &["(?:2[389]|39)0|[7-9]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{5,10})", leading_digits: &["[1-36]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:11[1-9]\\d|(?:2[389]|39)(?:0[2-9]|[2-9]\\d))\\d{3,8}|(?:1[02-9]|2[0-24-7]|3[0-8])[2-9]\\d{4,9}", lengths: &[7, 8, 9, 10, 11, 12] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "6(?:[0-689]|7\\d)\\d{6,7}", lengths: &[8, 9, 10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{3,9}", lengths: &[6, 7, 8, 9, 10, 11, 12] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "(?:78\\d|90[0169])\\d{3,7}", lengths: &[6, 7, 8, 9, 10] }),
+        (PhoneType::Uan, NumberDesc { pattern: "7[06]\\d{4,10}", lengths: &[6, 7, 8, 9, 10, 11, 12] }),
+    ] },
+    // RU
+    Metadata { general: "8\\d{13}|[347-9]\\d{9}", lengths: &[10, 14], leading_digits: None, national_prefix_for_parsing: Some("8"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["[0-79]"], format: "${1}-${2}-${3}", national_format: "${1}-${2}-${3}", intl_format: None },
+        NumberFormat { pattern: "(\\d{4})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["7(?:1[0-8]|2[1-9])", "7(?:1(?:[0-356]2|4[29]|7|8[27])|2(?:1[23]|[2-9]2))", "7(?:1(?:[0-356]2|4[29]|7|8[27])|2(?:13[03-69]|62[013-9]))|72[1-57-9]2"], format: "${1} ${2} ${3} ${4}", national_format: "8 (${1}) ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{5})(\\d)(\\d{2})(\\d{2})", leading_digits: &["7(?:1[0-68]|2[1-9])", "7(?:1(?:[06][3-6]|[18]|2[35]|[3-5][3-5])|2(?:[13][3-5]|[24-689]|7[457]))", "7(?:1(?:0(?:[356]|4[023])|[18]|2(?:3[013-9]|5)|3[45]|43[013-79]|5(?:3[1-8]|4[1-7]|5)|6(?:3[0-35-9]|[4-6]))|2(?:1(?:3[178]|[45])|[24-689]|3[35]|7[457]))|7(?:14|23)4[0-8]|71(?:33|45)[1-79]"], format: "${1} ${2} ${3} ${4}", national_format: "8 (${1}) ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["7"], format: "${1} ${2} ${3}", national_format: "8 (${1}) ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["[349]|8(?:[02-7]|1[1-8])"], format: "${1} ${2}-${3}-${4}", national_format: "8 (${1}) ${2}-${3}-${4}", intl_format: Some("${1} ${2}-${3}-${4}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})(\\d{3})(\\d{3})", leading_digits: &["8"], format: "${1} ${2} ${3} ${4}", national_format: "8 (${1}) ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "336(?:[013-9]\\d|2[013-9])\\d{5}|(?:3(?:0[12]|4[1-35-79]|5[1-3]|65|8[1-58]|9[0145])|4(?:01|1[1356]|2[13467]|7[1-5]|8[1-7]|9[1-689])|8(?:1[1-8]|2[01]|3[13-6]|4[0-8]|5[15-7]|6[0-35-79]|7[1-37-9]))\\d{7}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "9\\d{9}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:0[04]|108\\d{3})\\d{7}", lengths: &[10, 14] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "80[39]\\d{7}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "808\\d{7}", lengths: &[10] }),
+    ] },
+    // RW
+    Metadata { general: "(?:06|[27]\\d\\d|[89]00)\\d{6}", lengths: &[8, 9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["0"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["2"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[7-9]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:06|2[23568]\\d)\\d{6}", lengths: &[8, 9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "7[237-9]\\d{7}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{6}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900\\d{6}", lengths: &[9] }),
+    ] },
+    // SA
+    Metadata { general: "(?:[15]\\d|800|92)\\d{7}", lengths: &[9, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})(\\d{5})", leading_digits: &["9"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["1"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["5"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["8"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "1(?:1\\d|2[24-8]|3[35-8]|4[3-68]|6[2-5]|7[235-7])\\d{6}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "579[01]\\d{5}|5(?:[013-689]\\d|7[0-8])\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{7}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "925\\d{6}", lengths: &[9] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "920\\d{6}", lengths: &[9] }),
+    ] },
+    // SB
+    Metadata { general: "[6-9]\\d{6}|[1-6]\\d{4}", lengths: &[5, 7], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{5})", leading_digits: &["6[89]|7|8[4-9]|9(?:[1-8]|9[0-8])"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:1[4-79]|[23]\\d|4[0-2]|5[03]|6[0-37])\\d{3}", lengths: &[5] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "48\\d{3}|(?:(?:6[89]|7[1-9]|8[4-9])\\d|9(?:1[2-9]|2[013-9]|3[0-2]|[46]\\d|5[0-46-9]|7[0-689]|8[0-79]|9[0-8]))\\d{4}", lengths: &[5, 7] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "1[38]\\d{3}", lengths: &[5] }),
+        (PhoneType::Voip, NumberDesc { pattern: "5[12]\\d{3}", lengths: &[5] }),
+    ] },
+    // SC
+    Metadata { general: "(?:[2489]\\d|64)\\d{5}", lengths: &[7], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{3})", leading_digits: &["[246]|9[57]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "4[2-46]\\d{5}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "2[125-8]\\d{5}", lengths: &[7] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800[08]\\d{3}", lengths: &[7] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "85\\d{5}", lengths: &[7] }),
+        (PhoneType::Voip, NumberDesc { pattern: "971\\d{4}|(?:64|95)\\d{5}", lengths: &[7] }),
+    ] },
+    // SD
+    Metadata { general: "[19]\\d{8}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["[19]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "1(?:5\\d|8[35-7])\\d{6}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:1[0-2]|9[0-3569])\\d{7}", lengths: &[9] }),
+    ] },
+    // SE
+    Metadata { general: "(?:[26]\\d\\d|9)\\d{9}|[1-9]\\d{8}|[1-689]\\d{7}|[1-4689]\\d{6}|2\\d{5}", lengths: &[6, 7, 8, 9, 10, 12], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2,3})(\\d{2})", leading_digits: &["20"], format: "${1}-${2} ${3}", national_format: "0${1}-${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["9(?:00|39|44|9)"], format: "${1}-${2}", national_format: "0${1}-${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{2})", leading_digits: &["[12][136]|3[356]|4[0246]|6[03]|90[1-9]"], format: "${1}-${2} ${3}", national_format: "0${1}-${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d)(\\d{2,3})(\\d{2})(\\d{2})", leading_digits: &["8"], format: "${1}-${2} ${3} ${4}", national_format: "0${1}-${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2,3})(\\d{2})", leading_digits: &["1[2457]|2(?:[247-9]|5[0138])|3[0247-9]|4[1357-9]|5[0-35-9]|6(?:[125689]|4[02-57]|7[0-2])|9(?:[125-8]|3[02-5]|4[0-3])"], format: "${1}-${2} ${3}", national_format: "0${1}-${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2,3})(\\d{3})", leading_digits: &["9(?:00|39|44)"], format: "${1}-${2} ${3}", national_format: "0${1}-${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2,3})(\\d{2})(\\d{2})", leading_digits: &["1[13689]|2[0136]|3[1356]|4[0246]|54|6[03]|90[1-9]"], format: "${1}-${2} ${3} ${4}", national_format: "0${1}-${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["10|7"], format: "${1}-${2} ${3} ${4}", national_format: "0${1}-${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{3})(\\d{2})", leading_digits: &["8"], format: "${1}-${2} ${3} ${4}", national_format: "0${1}-${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[13-5]|2(?:[247-9]|5[0138])|6(?:[124-689]|7[0-2])|9(?:[125-8]|3[02-5]|4[0-3])"], format: "${1}-${2} ${3} ${4}", national_format: "0${1}-${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{3})", leading_digits: &["9"], format: "${1}-${2} ${3} ${4}", national_format: "0${1}-${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["[26]"], format: "${1}-${2} ${3} ${4} ${5}", national_format: "0${1}-${2} ${3} ${4} ${5}", intl_format: Some("${1} ${2} ${3} ${4} ${5}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:(?:[12][136]|3[356]|4[0246]|6[03]|8\\d)\\d|90[1-9])\\d{4,6}|(?:1(?:2[0-35]|4[0-4]|5[0-25-9]|7[13-6]|[89]\\d)|2(?:2[0-7]|4[0136-8]|5[0138]|7[018]|8[01]|9[0-57])|3(?:0[0-4]|1\\d|2[0-25]|4[056]|7[0-2]|8[0-3]|9[023])|4(?:1[013-8]|3[0135]|5[14-79]|7[0-246-9]|8[0156]|9[0-689])|5(?:0[0-6]|[15][0-5]|2[0-68]|3[0-4]|4\\d|6[03-5]|7[013]|8[0-79]|9[01])|6(?:1[1-3]|2[0-4]|4[02-57]|5[0-37]|6[0-3]|7[0-2]|8[0247]|9[0-356])|9(?:1[0-68]|2\\d|3[02-5]|4[0-3]|5[0-4]|[68][01]|7[0135-8]))\\d{5,6}", lengths: &[7, 8, 9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "7[02369]\\d{7}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "20\\d{4,7}", lengths: &[6, 7, 8, 9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "649\\d{6}|99[1-59]\\d{4}(?:\\d{3})?|9(?:00|39|44)[1-8]\\d{3,6}", lengths: &[7, 8, 9, 10] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "77[0-7]\\d{6}", lengths: &[9] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "75[1-8]\\d{6}", lengths: &[9] }),
+        (PhoneType::Pager, NumberDesc { pattern: "74[02-9]\\d{6}", lengths: &[9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "10[1-8]\\d{6}", lengths: &[9] }),
+        (PhoneType::Voicemail, NumberDesc { pattern: "(?:25[245]|67[3-68])\\d{9}", lengths: &[12] }),
+    ] },
+    // SG
+    Metadata { general: "(?:(?:1\\d|8)\\d\\d|7000)\\d{7}|[3689]\\d{7}", lengths: &[8, 10, 11], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4,5})", leading_digits: &["1[013-9]|77", "1(?:[013-8]|9(?:0[1-9]|[1-9]))|77"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[369]|8(?:0[1-9]|[1-9])"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["8"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})(\\d{3})", leading_digits: &["7"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})(\\d{4})", leading_digits: &["1"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "662[0-24-9]\\d{4}|6(?:[0-578]\\d|6[013-57-9]|9[0-35-9])\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "89(?:8[02-9]|9[0-4])\\d{4}|(?:8(?:0[1-9]|[1-8]\\d|9[0-7])|9[0-8]\\d)\\d{5}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "(?:18|8)00\\d{7}", lengths: &[10, 11] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "1900\\d{7}", lengths: &[11] }),
+        (PhoneType::Voip, NumberDesc { pattern: "(?:3[12]\\d|666)\\d{5}", lengths: &[8] }),
+        (PhoneType::Uan, NumberDesc { pattern: "7000\\d{7}", lengths: &[11] }),
+    ] },
+    // SH
+    Metadata { general: "(?:[256]\\d|8)\\d{3}", lengths: &[4, 5], leading_digits: Some("[256]"), national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2(?:[0-57-9]\\d|6[4-9])\\d\\d", lengths: &[4, 5] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "[56]\\d{4}", lengths: &[5] }),
+        (PhoneType::Voip, NumberDesc { pattern: "262\\d\\d", lengths: &[5] }),
+    ] },
+    // SI
+    Metadata { general: "[1-7]\\d{7}|8\\d{4,7}|90\\d{4,6}", lengths: &[5, 6, 7, 8], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3,6})", leading_digits: &["8[09]|9"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{5})", leading_digits: &["59|8"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["[37][01]|4[0139]|51|6"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["[1-57]"], format: "${1} ${2} ${3} ${4}", national_format: "(0${1}) ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:[1-357][2-8]|4[24-8])\\d{6}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "65(?:[178]\\d|5[56]|6[01])\\d{4}|(?:[37][01]|4[0139]|51|6[489])\\d{6}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80\\d{4,6}", lengths: &[6, 7, 8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "89[1-3]\\d{2,5}|90\\d{4,6}", lengths: &[5, 6, 7, 8] }),
+        (PhoneType::Voip, NumberDesc { pattern: "(?:59\\d\\d|8(?:1(?:[67]\\d|8[0-589])|2(?:0\\d|2[0-37-9]|8[0-2489])|3[389]\\d))\\d{4}", lengths: &[8] }),
+    ] },
+    // SJ
+    Metadata { general: "0\\d{4}|(?:[489]\\d|79)\\d{6}", lengths: &[5, 8], leading_digits: Some("79"), national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "79\\d{6}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:4[015-8]|9\\d)\\d{6}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[01]\\d{5}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "82[09]\\d{5}", lengths: &[8] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "810(?:0[0-6]|[2-8]\\d)\\d{3}", lengths: &[8] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "880\\d{5}", lengths: &[8] }),
+        (PhoneType::Voip, NumberDesc { pattern: "85[0-5]\\d{5}", lengths: &[8] }),
+        (PhoneType::Uan, NumberDesc { pattern: "(?:0[235-9]|81(?:0(?:0[7-9]|1\\d)|5\\d\\d))\\d{3}", lengths: &[5, 8] }),
+        (PhoneType::Voicemail, NumberDesc { pattern: "81[23]\\d{5}", lengths: &[8] }),
+    ] },
+    // SK
+    Metadata { general: "[2-689]\\d{8}|[2-59]\\d{6}|[2-5]\\d{5}", lengths: &[6, 7, 9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{2})(\\d{3,4})", leading_digits: &["21"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2,3})", leading_digits: &["[3-5][1-8]1", "[3-5][1-8]1[67]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})", leading_digits: &["909", "9090"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{3})(\\d{2})", leading_digits: &["2"], format: "${1}/${2} ${3} ${4}", national_format: "0${1}/${2} ${3} ${4}", intl_format: Some("${1}/${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[689]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["[3-5]"], format: "${1}/${2} ${3} ${4}", national_format: "0${1}/${2} ${3} ${4}", intl_format: Some("${1}/${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2(?:16|[2-9]\\d{3})|(?:(?:[3-5][1-8]\\d|819)\\d|601[1-5])\\d)\\d{4}|(?:2|[3-5][1-8])1[67]\\d{3}|[3-5][1-8]16\\d\\d", lengths: &[6, 7, 9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "909[1-9]\\d{5}|9(?:0[1-8]|1[0-24-9]|4[03-57-9]|5\\d)\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{6}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "9(?:00|[78]\\d)\\d{6}", lengths: &[9] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "8[5-9]\\d{7}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "6(?:02|5[0-4]|9[0-6])\\d{6}", lengths: &[9] }),
+        (PhoneType::Pager, NumberDesc { pattern: "9090\\d{3}", lengths: &[7] }),
+        (PhoneType::Uan, NumberDesc { pattern: "96\\d{7}", lengths: &[9] }),
+    ] },
+    // SL
+    Metadata { general: "(?:[237-9]\\d|66)\\d{6}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{6})", leading_digits: &["[236-9]"], format: "${1} ${2}", national_format: "(0${1}) ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "22[2-4][2-9]\\d{4}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:25|3[0-5]|66|7[1-9]|8[08]|9[09])\\d{6}", lengths: &[8] }),
+    ] },
+    // SM
+    Metadata { general: "(?:0549|[5-7]\\d)\\d{6}", lengths: &[8, 10], leading_digits: None, national_prefix_for_parsing: Some("([89]\\d{5})$"), national_prefix_transform_rule: Some("0549$1"), formats: &[
+        NumberFormat { pattern: "(\\d{6})", leading_digits: &["[89]"], format: "${1}", national_format: "${1}", intl_format: None },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[5-7]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{6})", leading_digits: &["0"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "0549(?:8[0157-9]|9\\d)\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "6[16]\\d{6}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "7[178]\\d{6}", lengths: &[8] }),
+        (PhoneType::Voip, NumberDesc { pattern: "5[158]\\d{6}", lengths: &[8] }),
+    ] },
+    // SN
+    Metadata { general: "(?:[378]\\d|93)\\d{7}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["8"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["[379]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "3(?:0(?:1[0-2]|80)|282|3(?:8[1-9]|9[3-9])|611)\\d{5}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "7(?:[015-8]\\d|21|90)\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{6}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "88[4689]\\d{6}", lengths: &[9] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "81[02468]\\d{6}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "(?:3(?:392|9[01]\\d)\\d|93(?:3[13]0|929))\\d{4}", lengths: &[9] }),
+    ] },
+    // SO
+    Metadata { general: "[346-9]\\d{8}|[12679]\\d{7}|[1-5]\\d{6}|[1348]\\d{5}", lengths: &[6, 7, 8, 9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{4})", leading_digits: &["8[125]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{6})", leading_digits: &["[134]"], format: "${1}", national_format: "${1}", intl_format: Some("${1}") },
+        NumberFormat { pattern: "(\\d)(\\d{6})", leading_digits: &["[15]|2[0-79]|3[0-46-8]|4[0-7]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d)(\\d{7})", leading_digits: &["(?:2|90)4|[67]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[348]|64|79|90"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{5,7})", leading_digits: &["1|28|6[0-35-9]|7[67]|9[2-9]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:1\\d|2[0-79]|3[0-46-8]|4[0-7]|5[57-9])\\d{5}|(?:[134]\\d|8[125])\\d{4}", lengths: &[6, 7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:(?:15|(?:3[59]|4[89]|6\\d|7[679]|8[08])\\d|9(?:0\\d|[2-9]))\\d|2(?:4\\d|8))\\d{5}|(?:[67]\\d\\d|904)\\d{5}", lengths: &[7, 8, 9] }),
+    ] },
+    // SR
+    Metadata { general: "(?:[2-5]|[6-8]\\d|90)\\d{5}", lengths: &[6, 7], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["56"], format: "${1}-${2}-${3}", national_format: "${1}-${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})", leading_digits: &["[2-5]"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: Some("${1}-${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[6-9]"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: Some("${1}-${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2[1-3]|3[0-7]|4\\d|5[2-58])\\d{4}", lengths: &[6] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:6[08]|7[124-7]|8[1-9])\\d{5}", lengths: &[7] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80\\d{5}", lengths: &[7] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90\\d{5}", lengths: &[7] }),
+        (PhoneType::Voip, NumberDesc { pattern: "56\\d{4}", lengths: &[6] }),
+    ] },
+    // SS
+    Metadata { general: "[19]\\d{8}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[19]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "1[89]\\d{7}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:12|9[1257-9])\\d{7}", lengths: &[9] }),
+    ] },
+    // ST
+    Metadata { general: "(?:22|9\\d)\\d{5}", lengths: &[7], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[29]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "22\\d{5}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "900[5-9]\\d{3}|9(?:0[1-9]|[89]\\d)\\d{4}", lengths: &[7] }),
+    ] },
+    // SV
+    Metadata { general: "[25-7]\\d{7}|(?:80\\d|900)\\d{4}(?:\\d{4})?", lengths: &[7, 8, 11], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[89]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[25-7]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})(\\d{4})", leading_digits: &["[89]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2(?:79(?:0[0347-9]|[1-9]\\d)|89(?:0[024589]|[1-9]\\d))\\d{3}|2(?:[1-69]\\d|[78][0-8])\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "[5-7]\\d{7}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{8}|80[01]\\d{4}", lengths: &[7, 11] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900\\d{4}(?:\\d{4})?", lengths: &[7, 11] }),
+    ] },
+    // SX
+    Metadata { general: "7215\\d{6}|(?:[58]\\d\\d|900)\\d{7}", lengths: &[10], leading_digits: Some("721"), national_prefix_for_parsing: Some("(5\\d{6})$|1"), national_prefix_transform_rule: Some("721$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "7215(?:4[2-8]|8[239]|9[056])\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "7215(?:1[02]|2\\d|5[034679]|8[014-8])\\d{4}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+    ] },
+    // SY
+    Metadata { general: "[1-359]\\d{8}|[1-5]\\d{7}", lengths: &[8, 9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3,4})", leading_digits: &["[1-4]|5[1-3]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[59]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "21\\d{6,7}|(?:1(?:[14]\\d|[2356])|2[235]|3(?:[13]\\d|4)|4[134]|5[1-3])\\d{6}", lengths: &[8, 9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:50|9[1-9])\\d{7}", lengths: &[9] }),
+    ] },
+    // SZ
+    Metadata { general: "0800\\d{4}|(?:[237]\\d|900)\\d{6}", lengths: &[8, 9], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[0237]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{5})(\\d{4})", leading_digits: &["9"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "[23][2-5]\\d{6}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "7[5-9]\\d{6}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "0800\\d{4}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900\\d{6}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "70\\d{6}", lengths: &[8] }),
+    ] },
+    // TA
+    Metadata { general: "8\\d{3}", lengths: &[4], leading_digits: Some("8"), national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "8\\d{3}", lengths: &[4] }),
+    ] },
+    // TC
+    Metadata { general: "(?:[58]\\d\\d|649|900)\\d{7}", lengths: &[10], leading_digits: Some("649"), national_prefix_for_parsing: Some("([2-479]\\d{6})$|1"), national_prefix_transform_rule: Some("649$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "649(?:266|712|9(?:4\\d|50))\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "649(?:2(?:3[129]|4[1-79])|3\\d\\d|4[34][1-3])\\d{4}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "649(?:71[01]|966)\\d{4}", lengths: &[10] }),
+    ] },
+    // TD
+    Metadata { general: "(?:22|30|[689]\\d|77)\\d{6}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[236-9]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "22(?:[37-9]0|5[0-5]|6[89])\\d{4}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:30|[69]\\d|77|8[5-7])\\d{6}", lengths: &[8] }),
+    ] },
+    // TG
+    Metadata { general: "[279]\\d{7}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[279]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2(?:2[2-7]|3[23]|4[45]|55|6[67]|77)\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:7[0-29]|9[0-36-9])\\d{6}", lengths: &[8] }),
+    ] },
+    // TH
+    Metadata { general: "(?:001800|[2-57]|[689]\\d)\\d{7}|1\\d{7,9}", lengths: &[8, 9, 10, 13], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{4})", leading_digits: &["2"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3,4})", leading_digits: &["[13-9]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})(\\d{3})", leading_digits: &["1"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:1[0689]|2\\d|3[2-9]|4[2-5]|5[2-6]|7[3-7])\\d{6}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "67(?:1[0-8]|2[4-7])\\d{5}|(?:14|6[1-6]|[89]\\d)\\d{7}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "(?:001800\\d|1800)\\d{6}", lengths: &[10, 13] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "1900\\d{6}", lengths: &[10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "6[08]\\d{7}", lengths: &[9] }),
+    ] },
+    // TJ
+    Metadata { general: "(?:[0-57-9]\\d|66)\\d{7}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{6})(\\d)(\\d{2})", leading_digits: &["331", "3317"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{4})", leading_digits: &["44[02-479]|[34]7"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d)(\\d{4})", leading_digits: &["3(?:[1245]|3[12])"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["\\d"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:3(?:1[3-5]|2[245]|3[12]|4[24-7]|5[25]|72)|4(?:46|74|87))\\d{6}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:33[03-9]|4(?:1[18]|4[02-479])|81[1-9])\\d{6}|(?:[09]\\d|1[0-27-9]|2[0-27]|3[08]|40|5[05]|66|7[01578]|8[078])\\d{7}", lengths: &[9] }),
+    ] },
+    // TK
+    Metadata { general: "[2-47]\\d{3,6}", lengths: &[4, 5, 6, 7], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2[2-4]|[34]\\d)\\d{2,5}", lengths: &[4, 5, 6, 7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "7[2-4]\\d{2,5}", lengths: &[4, 5, 6, 7] }),
+    ] },
+    // TL
+    Metadata { general: "7\\d{7}|(?:[2-47]\\d|[89]0)\\d{5}", lengths: &[7, 8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[2-489]|70"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["7"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2[1-5]|3[1-9]|4[1-4])\\d{5}", lengths: &[7] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "7[2-8]\\d{6}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80\\d{5}", lengths: &[7] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90\\d{5}", lengths: &[7] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "70\\d{5}", lengths: &[7] }),
+    ] },
+    // TM
+    Metadata { general: "(?:[1-6]\\d|71)\\d{6}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: Some("8"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["12"], format: "${1} ${2}-${3}-${4}", national_format: "(8 ${1}) ${2}-${3}-${4}", intl_format: Some("${1} ${2}-${3}-${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d)(\\d{2})(\\d{2})", leading_digits: &["[1-5]"], format: "${1} ${2}-${3}-${4}", national_format: "(8 ${1}) ${2}-${3}-${4}", intl_format: Some("${1} ${2}-${3}-${4}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{6})", leading_digits: &["[67]"], format: "${1} ${2}", national_format: "8 ${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:1(?:2\\d|3[1-9])|2(?:22|4[0-35-8])|3(?:22|4[03-9])|4(?:22|3[128]|4\\d|6[15])|5(?:22|5[7-9]|6[014-689]))\\d{5}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:6\\d|71)\\d{6}", lengths: &[8] }),
+    ] },
+    // TN
+    Metadata { general: "[2-57-9]\\d{7}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["[2-57-9]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "81200\\d{3}|(?:3[0-2]|7\\d)\\d{6}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "3(?:001|[12]40)\\d{4}|(?:(?:[259]\\d|4[0-8])\\d|3(?:1[1-35]|6[0-4]|91))\\d{5}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8010\\d{4}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "88\\d{6}", lengths: &[8] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "8[12]10\\d{4}", lengths: &[8] }),
+    ] },
+    // TO
+    Metadata { general: "(?:0800|(?:[5-8]\\d\\d|999)\\d)\\d{3}|[2-8]\\d{4}", lengths: &[5, 7], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3})", leading_digits: &["[2-4]|50|6[09]|7[0-24-69]|8[05]"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: Some("${1}-${2}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3})", leading_digits: &["0"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[5-9]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2\\d|3[0-8]|4[0-4]|50|6[09]|7[0-24-69]|8[05])\\d{3}", lengths: &[5] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:5(?:4[0-5]|5[4-6])|6(?:[09]\\d|3[02]|8[15-9])|(?:7\\d|8[46-9])\\d|999)\\d{4}", lengths: &[7] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "0800\\d{3}", lengths: &[7] }),
+        (PhoneType::Voip, NumberDesc { pattern: "55[0-37-9]\\d{4}", lengths: &[7] }),
+    ] },
+    // TR
+    Metadata { general: "4\\d{6}|8\\d{11,12}|(?:[2-58]\\d\\d|900)\\d{7}", lengths: &[7, 10, 12, 13], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d)(\\d{3})", leading_digits: &["444"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: None },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["512|8[01589]|90"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["5(?:[0-59]|61)", "5(?:[0-59]|61[06])", "5(?:[0-59]|61[06]1)"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["[24][1-8]|3[1-9]"], format: "${1} ${2} ${3} ${4}", national_format: "(0${1}) ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{6,7})", leading_digits: &["80"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2(?:[13][26]|[28][2468]|[45][268]|[67][246])|3(?:[13][28]|[24-6][2468]|[78][02468]|92)|4(?:[16][246]|[23578][2468]|4[26]))\\d{7}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "561(?:011|61\\d)\\d{4}|5(?:0[15-7]|1[06]|24|[34]\\d|5[1-59]|9[46])\\d{7}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00\\d{7}(?:\\d{2,3})?|11\\d{7})", lengths: &[10, 12, 13] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "(?:8[89]8|900)\\d{7}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "592(?:21[12]|461)\\d{4}", lengths: &[10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "850\\d{7}", lengths: &[10] }),
+        (PhoneType::Pager, NumberDesc { pattern: "512\\d{7}", lengths: &[10] }),
+        (PhoneType::Uan, NumberDesc { pattern: "444\\d{4}", lengths: &[7] }),
+    ] },
+    // TT
+    Metadata { general: "(?:[58]\\d\\d|900)\\d{7}", lengths: &[10], leading_digits: Some("868"), national_prefix_for_parsing: Some("([2-46-8]\\d{6})$|1"), national_prefix_transform_rule: Some("868$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "868(?:2(?:01|1[5-9]|[23]\\d|4[0-2])|6(?:0[7-9]|1[02-8]|2[1-9]|[3-69]\\d|7[0-79])|82[124])\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "868(?:(?:2[5-9]|3\\d)\\d|4(?:3[0-6]|[6-9]\\d)|6(?:20|78|8\\d)|7(?:0[1-9]|1[02-9]|[2-9]\\d))\\d{4}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::Voicemail, NumberDesc { pattern: "868619\\d{4}", lengths: &[10] }),
+    ] },
+    // TV
+    Metadata { general: "(?:2|7\\d\\d|90)\\d{4}", lengths: &[5, 6, 7], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3})", leading_digits: &["2"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{4})", leading_digits: &["90"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{5})", leading_digits: &["7"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2[02-9]\\d{3}", lengths: &[5] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:7[01]\\d|90)\\d{4}", lengths: &[6, 7] }),
+    ] },
+    // TW
+    Metadata { general: "[2-689]\\d{8}|7\\d{9,10}|[2-8]\\d{7}|2\\d{6}", lengths: &[7, 8, 9, 10, 11], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d)(\\d{4})", leading_digits: &["202"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3,4})", leading_digits: &["[258]0"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d)(\\d{3,4})(\\d{4})", leading_digits: &["[23568]|4(?:0[02-48]|[1-47-9])|7[1-9]", "[23568]|4(?:0[2-48]|[1-47-9])|(?:400|7)[1-9]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[49]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{4})(\\d{4,5})", leading_digits: &["7"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2[2-8]\\d|370|55[01]|7[1-9])\\d{6}|4(?:(?:0(?:0[1-9]|[2-48]\\d)|1[023]\\d)\\d{4,5}|(?:[239]\\d\\d|4(?:0[56]|12|49))\\d{5})|6(?:[01]\\d{7}|4(?:0[56]|12|24|4[09])\\d{4,5})|8(?:(?:2(?:3\\d|4[0-269]|[578]0|66)|36[24-9]|90\\d\\d)\\d{4}|4(?:0[56]|12|24|4[09])\\d{4,5})|(?:2(?:2(?:0\\d\\d|4(?:0[68]|[249]0|3[0-467]|5[0-25-9]|6[0235689]))|(?:3(?:[09]\\d|1[0-4])|(?:4\\d|5[0-49]|6[0-29]|7[0-5])\\d)\\d)|(?:(?:3[2-9]|5[2-8]|6[0-35-79]|8[7-9])\\d\\d|4(?:2(?:[089]\\d|7[1-9])|(?:3[0-4]|[78]\\d|9[01])\\d))\\d)\\d{3}", lengths: &[8, 9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:40001[0-2]|9[0-8]\\d{4})\\d{3}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[0-79]\\d{6}|800\\d{5}", lengths: &[8, 9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "20(?:[013-9]\\d\\d|2)\\d{4}", lengths: &[7, 9] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "99\\d{7}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "7010(?:[0-2679]\\d|3[0-7]|8[0-5])\\d{5}|70\\d{8}", lengths: &[10, 11] }),
+        (PhoneType::Uan, NumberDesc { pattern: "50[0-46-9]\\d{6}", lengths: &[9] }),
+    ] },
+    // TZ
+    Metadata { general: "(?:[25-8]\\d|41|90)\\d{7}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{4})", leading_digits: &["[89]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["[24]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{7})", leading_digits: &["5"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[67]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2[2-8]\\d{7}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:6[1-35-9]|7[13-9])\\d{7}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[08]\\d{6}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90\\d{7}", lengths: &[9] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "8(?:40|6[01])\\d{6}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "41\\d{7}", lengths: &[9] }),
+    ] },
+    // UA
+    Metadata { general: "[89]\\d{9}|[3-9]\\d{8}", lengths: &[9, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["6[12][29]|(?:3[1-8]|4[136-8]|5[12457]|6[49])2|(?:56|65)[24]", "6[12][29]|(?:35|4[1378]|5[12457]|6[49])2|(?:56|65)[24]|(?:3[1-46-8]|46)2[013-9]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{5})", leading_digits: &["3[1-8]|4(?:[1367]|[45][6-9]|8[4-6])|5(?:[1-5]|6[0135689]|7[4-6])|6(?:[12][3-7]|[459])", "3[1-8]|4(?:[1367]|[45][6-9]|8[4-6])|5(?:[1-5]|6(?:[015689]|3[02389])|7[4-6])|6(?:[12][3-7]|[459])"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["[3-7]|89|9[1-9]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3,4})", leading_digits: &["[89]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:3[1-8]|4[13-8]|5[1-7]|6[12459])\\d{7}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "790\\d{6}|(?:39|50|6[36-8]|7[1-357]|9[1-9])\\d{7}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800[1-8]\\d{5,6}", lengths: &[9, 10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[239]\\d{5,6}", lengths: &[9, 10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "89[1-579]\\d{6}", lengths: &[9] }),
+    ] },
+    // UG
+    Metadata { general: "800\\d{6}|(?:[29]0|[347]\\d)\\d{7}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})(\\d{5})", leading_digits: &["202", "2024"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{6})", leading_digits: &["[27-9]|4(?:6[45]|[7-9])"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{7})", leading_digits: &["[34]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "20(?:(?:240|30[67])\\d|6(?:00[0-2]|30[0-4]))\\d{3}|(?:20(?:[017]\\d|2[5-9]|3[1-4]|5[0-4]|6[15-9])|[34]\\d{3})\\d{5}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "72[48]0\\d{5}|7(?:[014-8]\\d|2[0167]|3[06]|9[0-2589])\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800[1-3]\\d{5}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90[1-3]\\d{6}", lengths: &[9] }),
+    ] },
+    // US
+    Metadata { general: "[2-9]\\d{9}|3\\d{6}", lengths: &[10], leading_digits: None, national_prefix_for_parsing: Some("1"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["310"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: Some("${1}-${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[24-9]|3(?:[02-9]|1[1-9])"], format: "${1}-${2}", national_format: "${1}-${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["[2-9]"], format: "(${1}) ${2}-${3}", national_format: "(${1}) ${2}-${3}", intl_format: Some("${1}-${2}-${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "3052(?:0[0-8]|[1-9]\\d)\\d{4}|(?:2742|305[3-9])\\d{6}|(?:472|983)[2-47-9]\\d{6}|(?:2(?:0[1-35-9]|1[02-9]|2[03-57-9]|3[1459]|4[08]|5[1-46]|6[0279]|7[0269]|8[13])|3(?:0[1-47-9]|1[02-9]|2[013-79]|3[0-24679]|4[167]|5[0-3]|6[01349]|8[056])|4(?:0[124-9]|1[02-579]|2[3-5]|3[0245]|4[023578]|58|6[349]|7[0589]|8[04])|5(?:0[1-57-9]|1[0235-8]|20|3[0149]|4[01]|5[179]|6[1-47]|7[0-5]|8[0256])|6(?:0[1-35-9]|1[024-9]|2[03689]|3[016]|4[0156]|5[01679]|6[0-279]|78|8[0-269])|7(?:0[1-46-8]|1[2-9]|2[04-8]|3[0-247]|4[0378]|5[47]|6[02359]|7[0-59]|8[156])|8(?:0[1-68]|1[02-8]|2[0168]|3[0-2589]|4[03578]|5[046-9]|6[02-5]|7[028])|9(?:0[1346-9]|1[02-9]|2[0589]|3[0146-8]|4[01357-9]|5[12469]|7[0-3589]|8[04-69]))[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "3052(?:0[0-8]|[1-9]\\d)\\d{4}|(?:2742|305[3-9])\\d{6}|(?:472|983)[2-47-9]\\d{6}|(?:2(?:0[1-35-9]|1[02-9]|2[03-57-9]|3[1459]|4[08]|5[1-46]|6[0279]|7[0269]|8[13])|3(?:0[1-47-9]|1[02-9]|2[013-79]|3[0-24679]|4[167]|5[0-3]|6[01349]|8[056])|4(?:0[124-9]|1[02-579]|2[3-5]|3[0245]|4[023578]|58|6[349]|7[0589]|8[04])|5(?:0[1-57-9]|1[0235-8]|20|3[0149]|4[01]|5[179]|6[1-47]|7[0-5]|8[0256])|6(?:0[1-35-9]|1[024-9]|2[03689]|3[016]|4[0156]|5[01679]|6[0-279]|78|8[0-269])|7(?:0[1-46-8]|1[2-9]|2[04-8]|3[0-247]|4[0378]|5[47]|6[02359]|7[0-59]|8[156])|8(?:0[1-68]|1[02-8]|2[0168]|3[0-2589]|4[03578]|5[046-9]|6[02-5]|7[028])|9(?:0[1346-9]|1[02-9]|2[0589]|3[0146-8]|4[01357-9]|5[12469]|7[0-3589]|8[04-69]))[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "305209\\d{4}", lengths: &[10] }),
+    ] },
+    // UY
+    Metadata { general: "0004\\d{2,9}|[1249]\\d{7}|2\\d{3,4}|(?:[49]\\d|80)\\d{5}", lengths: &[4, 5, 6, 7, 8, 9, 10, 11, 12, 13], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4,5})", leading_digits: &["21"], format: "${1}", national_format: "${1}", intl_format: Some("${1}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3,4})", leading_digits: &["0"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[49]0|8"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["9"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[124]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{2,4})", leading_digits: &["0"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})(\\d{2,4})", leading_digits: &["0"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:1(?:770|9(?:20|[89]7))|(?:2\\d|4[2-7])\\d\\d)\\d{4}", lengths: &[8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "9[1-9]\\d{6}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "0004\\d{2,9}|(?:405|80[05])\\d{4}", lengths: &[6, 7, 8, 9, 10, 11, 12, 13] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90[0-8]\\d{4}", lengths: &[7] }),
+        (PhoneType::Uan, NumberDesc { pattern: "21\\d{2,3}", lengths: &[4, 5] }),
+    ] },
+    // UZ
+    Metadata { general: "(?:20|33|[5-9]\\d)\\d{7}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["[235-9]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:55\\d\\d|6(?:1(?:22|3[124]|4[1-4]|5[1-3578]|64)|2(?:22|3[0-57-9]|41)|5(?:22|3[3-7]|5[024-8])|[69]\\d\\d|7(?:[23]\\d|7[69]))|7(?:0(?:5[4-9]|6[0146]|7[124-6]|9[135-8])|[168]\\d\\d|2(?:22|3[13-57-9]|4[1-3579]|5[14])|3(?:2\\d|3[1578]|4[1-35-7]|5[1-57]|61)|4(?:2\\d|3[1-579]|7[1-79])|5(?:22|5[1-9]|6[1457])|9(?:22|5[1-9])))\\d{5}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:(?:[25]0|33|8[078]|9[0-57-9])\\d{3}|6(?:1(?:2(?:2[01]|98)|35[0-4]|50\\d|61[23]|7(?:[01][017]|4\\d|55|9[5-9]))|2(?:(?:11|7\\d)\\d|2(?:[12]1|9[01379])|5(?:[126]\\d|3[0-4]))|5(?:19[01]|2(?:27|9[26])|(?:30|59|7\\d)\\d)|6(?:2(?:1[5-9]|2[0367]|38|41|52|60)|(?:3[79]|9[0-3])\\d|4(?:56|83)|7(?:[07]\\d|1[017]|3[07]|4[047]|5[057]|67|8[0178]|9[79]))|7(?:2(?:24|3[237]|4[5-9]|7[15-8])|5(?:7[12]|8[0589])|7(?:0\\d|[39][07])|9(?:0\\d|7[079])))|7(?:[07]\\d{3}|2(?:2(?:2[79]|95)|3(?:2[5-9]|6[0-6])|57\\d|7(?:0\\d|1[17]|2[27]|3[37]|44|5[057]|66|88))|3(?:2(?:1[0-6]|21|3[469]|7[159])|(?:33|9[4-6])\\d|5(?:0[0-4]|5[579]|9\\d)|7(?:[0-3579]\\d|4[0467]|6[67]|8[078]))|4(?:2(?:29|5[0257]|6[0-7]|7[1-57])|5(?:1[0-4]|8\\d|9[5-9])|7(?:0\\d|1[024589]|2[0-27]|3[0137]|[46][07]|5[01]|7[5-9]|9[079])|9(?:7[015-9]|[89]\\d))|5(?:112|2(?:0\\d|2[29]|[49]4)|3[1568]\\d|52[6-9]|7(?:0[01578]|1[017]|[23]7|4[047]|[5-7]\\d|8[78]|9[079]))|9(?:22[128]|3(?:2[0-4]|7\\d)|57[02569]|7(?:2[05-9]|3[37]|4\\d|60|7[2579]|87|9[07]))))\\d{4}", lengths: &[9] }),
+    ] },
+    // VA
+    Metadata { general: "0\\d{5,10}|3[0-8]\\d{7,10}|55\\d{8}|8\\d{5}(?:\\d{2,4})?|(?:1\\d|39)\\d{7,8}", lengths: &[6, 7, 8, 9, 10, 11, 12], leading_digits: Some("06698"), national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "06698\\d{1,6}", lengths: &[6, 7, 8, 9, 10, 11] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "3[1-9]\\d{8}|3[2-9]\\d{7}", lengths: &[9, 10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80(?:0\\d{3}|3)\\d{3}", lengths: &[6, 9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "(?:0878\\d{3}|89(?:2\\d|3[04]|4(?:[0-4]|[5-9]\\d\\d)|5[0-4]))\\d\\d|(?:1(?:44|6[346])|89(?:38|5[5-9]|9))\\d{6}", lengths: &[6, 8, 9, 10] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "84(?:[08]\\d{3}|[17])\\d{3}", lengths: &[6, 9] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "1(?:78\\d|99)\\d{6}", lengths: &[9, 10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "55\\d{8}", lengths: &[10] }),
+        (PhoneType::Voicemail, NumberDesc { pattern: "3[2-8]\\d{9,10}", lengths: &[11, 12] }),
+    ] },
+    // VC
+    Metadata { general: "(?:[58]\\d\\d|784|900)\\d{7}", lengths: &[10], leading_digits: Some("784"), national_prefix_for_parsing: Some("([2-7]\\d{6})$|1"), national_prefix_transform_rule: Some("784$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "784(?:266|3(?:6[6-9]|7\\d|8[0-6])|4(?:38|5[0-36-8]|8[0-8])|5(?:55|7[0-2]|93)|638|784)\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "784(?:4(?:3[0-5]|5[45]|89|9[0-8])|5(?:2[6-9]|3[0-4])|720)\\d{4}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "78451[0-2]\\d{4}", lengths: &[10] }),
+    ] },
+    // VE
+    Metadata { general: "[68]00\\d{7}|(?:[24]\\d|[59]0)\\d{8}", lengths: &[10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{7})", leading_digits: &["[24-689]"], format: "${1}-${2}", national_format: "0${1}-${2}", intl_format: Some("${1}-${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2(?:12|3[457-9]|[467]\\d|[58][1-9]|9[1-6])|[4-6]00)\\d{7}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "4(?:1[24-8]|2[246])\\d{7}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{7}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "90[01]\\d{7}", lengths: &[10] }),
+        (PhoneType::Uan, NumberDesc { pattern: "501\\d{7}", lengths: &[10] }),
+    ] },
+    // VG
+    Metadata { general: "(?:284|[58]\\d\\d|900)\\d{7}", lengths: &[10], leading_digits: Some("284"), national_prefix_for_parsing: Some("([2-578]\\d{6})$|1"), national_prefix_transform_rule: Some("284$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "284(?:229|4(?:22|9[45])|774|8(?:52|6[459]))\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "284(?:245|3(?:0[0-3]|4[0-7]|68|9[34])|4(?:4[0-6]|68|9[69])|5(?:4[0-7]|68|9[69]))\\d{4}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+    ] },
+    // VI
+    Metadata { general: "[58]\\d{9}|(?:34|90)0\\d{7}", lengths: &[10], leading_digits: Some("340"), national_prefix_for_parsing: Some("([2-9]\\d{6})$|1"), national_prefix_transform_rule: Some("340$1"), formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "340(?:2(?:0\\d|10|2[06-8]|4[49]|77)|3(?:32|44)|4(?:2[23]|44|7[34]|89)|5(?:1[34]|55)|6(?:2[56]|4[23]|77|9[023])|7(?:1[2-57-9]|2[57]|7\\d)|884|998)\\d{4}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "340(?:2(?:0\\d|10|2[06-8]|4[49]|77)|3(?:32|44)|4(?:2[23]|44|7[34]|89)|5(?:1[34]|55)|6(?:2[56]|4[23]|77|9[023])|7(?:1[2-57-9]|2[57]|7\\d)|884|998)\\d{4}", lengths: &[10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "8(?:00|33|44|55|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900[2-9]\\d{6}", lengths: &[10] }),
+        (PhoneType::PersonalNumber, NumberDesc { pattern: "52(?:3(?:[2-46-9][02-9]\\d|5(?:[02-46-9]\\d|5[0-46-9]))|4(?:[2-478][02-9]\\d|5(?:[034]\\d|2[024-9]|5[0-46-9])|6(?:0[1-9]|[2-9]\\d)|9(?:[05-9]\\d|2[0-5]|49)))\\d{4}|52[34][2-9]1[02-9]\\d{4}|5(?:00|2[125-9]|33|44|66|77|88)[2-9]\\d{6}", lengths: &[10] }),
+    ] },
+    // VN
+    Metadata { general: "[12]\\d{9}|[135-9]\\d{8}|[16]\\d{7}|[16-8]\\d{6}", lengths: &[7, 8, 9, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[17]99"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d{2})(\\d{5})", leading_digits: &["80"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4,5})", leading_digits: &["69"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d{4})(\\d{4,6})", leading_digits: &["1"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{2})(\\d{2})", leading_digits: &["6"], format: "${1} ${2} ${3} ${4}", national_format: "0${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[357-9]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{4})(\\d{4})", leading_digits: &["2[48]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})(\\d{3})", leading_digits: &["2"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "2(?:0[3-9]|1[0-689]|2[0-25-9]|[38][2-9]|4[2-8]|5[124-9]|6[0-39]|7[0-7]|9[0-4679])\\d{7}", lengths: &[10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:5(?:2[238]|59)|89[6-9]|99[013-9])\\d{6}|(?:3\\d|5[1689]|7[06-9]|8[1-8]|9[0-8])\\d{7}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "1800\\d{4,6}|12(?:0[13]|28)\\d{4}", lengths: &[8, 9, 10] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "1900\\d{4,6}", lengths: &[8, 9, 10] }),
+        (PhoneType::Voip, NumberDesc { pattern: "672\\d{6}", lengths: &[9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "(?:[17]99|80\\d)\\d{4}|69\\d{5,6}", lengths: &[7, 8] }),
+    ] },
+    // VU
+    Metadata { general: "[57-9]\\d{6}|(?:[238]\\d|48)\\d{3}", lengths: &[5, 7], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["[57-9]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:38[0-8]|48[4-9])\\d\\d|(?:2[02-9]|3[4-7]|88)\\d{3}", lengths: &[5] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:[58]\\d|7[013-7])\\d{5}", lengths: &[7] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "81[18]\\d\\d", lengths: &[5] }),
+        (PhoneType::Voip, NumberDesc { pattern: "9(?:0[1-9]|1[01])\\d{4}", lengths: &[7] }),
+        (PhoneType::Uan, NumberDesc { pattern: "(?:3[03]|900\\d)\\d{3}", lengths: &[5, 7] }),
+    ] },
+    // WF
+    Metadata { general: "(?:40|72|8\\d{4})\\d{4}|[89]\\d{5}", lengths: &[6, 9], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["[47-9]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{2})(\\d{2})(\\d{2})", leading_digits: &["8"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "72\\d{4}", lengths: &[6] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:72|8[23])\\d{4}", lengths: &[6] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80[0-5]\\d{6}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "9[23]\\d{4}", lengths: &[6] }),
+        (PhoneType::Voicemail, NumberDesc { pattern: "[48]0\\d{4}", lengths: &[6] }),
+    ] },
+    // WS
+    Metadata { general: "(?:[2-6]|8\\d{5})\\d{4}|[78]\\d{6}|[68]\\d{5}", lengths: &[5, 6, 7, 10], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{5})", leading_digits: &["[2-5]|6[1-9]"], format: "${1}", national_format: "${1}", intl_format: Some("${1}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3,7})", leading_digits: &["[68]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{5})", leading_digits: &["7"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "6[1-9]\\d{3}|(?:[2-5]|60)\\d{4}", lengths: &[5, 6] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:7[1-35-8]|8(?:[3-7]|9\\d{3}))\\d{5}", lengths: &[7, 10] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{3}", lengths: &[6] }),
+    ] },
+    // XK
+    Metadata { general: "2\\d{7,8}|3\\d{7,11}|(?:4\\d\\d|[89]00)\\d{5}", lengths: &[8, 9, 10, 11, 12], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{5})", leading_digits: &["[89]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3})", leading_digits: &["[2-4]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["2|39"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{7,10})", leading_digits: &["3"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "38\\d{6,10}|(?:2[89]|39)(?:0\\d{5,6}|[1-9]\\d{5})", lengths: &[8, 9, 10, 11, 12] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "4[3-9]\\d{6}", lengths: &[8] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{5}", lengths: &[8] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "900\\d{5}", lengths: &[8] }),
+    ] },
+    // YE
+    Metadata { general: "(?:1|7\\d)\\d{7}|[1-7]\\d{6}", lengths: &[7, 8, 9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{3,4})", leading_digits: &["[1-6]|7(?:[24-6]|8[0-7])"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["7"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "78[0-7]\\d{4}|17\\d{6}|(?:[12][2-68]|3[2358]|4[2-58]|5[2-6]|6[3-58]|7[24-6])\\d{5}", lengths: &[7, 8] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "7[01378]\\d{7}", lengths: &[9] }),
+    ] },
+    // YT
+    Metadata { general: "7093\\d{5}|(?:80|9\\d)\\d{7}|(?:26|63)9\\d{6}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "269(?:0[0-467]|15|5[0-4]|6\\d|[78]0)\\d{4}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:639(?:0[0-79]|1[019]|[267]\\d|3[09]|40|5[05-9]|9[04-79])|7093[5-7])\\d{4}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80\\d{7}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "9(?:(?:39|47)8[01]|769\\d)\\d{4}", lengths: &[9] }),
+    ] },
+    // ZA
+    Metadata { general: "[1-79]\\d{8}|8\\d{4,9}", lengths: &[5, 6, 7, 8, 9, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{3,4})", leading_digits: &["8[1-4]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{2,3})", leading_digits: &["8[1-4]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["860"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["[1-9]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["8"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:2(?:0330|4302)|52087)0\\d{3}|(?:1[0-8]|2[1-378]|3[1-69]|4\\d|5[1346-8])\\d{7}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:1(?:3492[0-25]|4495[0235]|549(?:20|5[01]))|4[34]492[01])\\d{3}|8[1-4]\\d{3,7}|(?:2[27]|47|54)4950\\d{3}|(?:1(?:049[2-4]|9[12]\\d\\d)|(?:50[0-2]|[67]\\d\\d)\\d\\d|8(?:5\\d{3}|7(?:08[67]|158|28[5-9]|310)))\\d{4}|(?:1[6-8]|28|3[2-69]|4[025689]|5[36-8])4920\\d{3}|(?:12|[2-5]1)492\\d{4}", lengths: &[5, 6, 7, 8, 9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80\\d{7}", lengths: &[9] }),
+        (PhoneType::PremiumRate, NumberDesc { pattern: "(?:86[2-9]|9[0-2]\\d)\\d{6}", lengths: &[9] }),
+        (PhoneType::SharedCost, NumberDesc { pattern: "860\\d{6}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "87(?:08[0-589]|15[0-79]|28[0-4]|31[1-9])\\d{4}|87(?:[02][0-79]|1[0-46-9]|3[02-9]|[4-9]\\d)\\d{5}", lengths: &[9] }),
+        (PhoneType::Uan, NumberDesc { pattern: "861\\d{6,7}", lengths: &[9, 10] }),
+    ] },
+    // ZM
+    Metadata { general: "800\\d{6}|(?:21|[579]\\d|63)\\d{7}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3})", leading_digits: &["[1-9]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: None },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[28]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{7})", leading_digits: &["[579]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "21[1-8]\\d{6}", lengths: &[9] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:[59][5-8]|7[5-9])\\d{7}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "800\\d{6}", lengths: &[9] }),
+        (PhoneType::Voip, NumberDesc { pattern: "63\\d{7}", lengths: &[9] }),
+    ] },
+    // ZW
+    Metadata { general: "2(?:[0-57-9]\\d{6,8}|6[0-24-9]\\d{6,7})|[38]\\d{9}|[35-8]\\d{8}|[3-6]\\d{7}|[1-689]\\d{6}|[1-3569]\\d{5}|[1356]\\d{4}", lengths: &[5, 6, 7, 8, 9, 10], leading_digits: None, national_prefix_for_parsing: Some("0"), national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3,5})", leading_digits: &["2(?:0[45]|2[278]|[49]8)|3(?:[09]8|17)|6(?:[29]8|37|75)|[23][78]|(?:33|5[15]|6[68])[78]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{2,4})", leading_digits: &["[49]"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})", leading_digits: &["80"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{7})", leading_digits: &["24|8[13-59]|(?:2[05-79]|39|5[45]|6[15-8])2", "2(?:02[014]|4|[56]20|[79]2)|392|5(?:42|525)|6(?:[16-8]21|52[013])|8[13-59]"], format: "${1} ${2}", national_format: "(0${1}) ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{4})", leading_digits: &["7"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3,4})", leading_digits: &["2(?:1[39]|2[0157]|[378]|[56][14])|3(?:12|29)", "2(?:1[39]|2[0157]|[378]|[56][14])|3(?:123|29)"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{6})", leading_digits: &["8"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3,5})", leading_digits: &["1|2(?:0[0-36-9]|12|29|[56])|3(?:1[0-689]|[24-6])|5(?:[0236-9]|1[2-4])|6(?:[013-59]|7[0-46-9])|(?:33|55|6[68])[0-69]|(?:29|3[09]|62)[0-79]"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3})(\\d{3,4})", leading_digits: &["29[013-9]|39|54"], format: "${1} ${2} ${3}", national_format: "0${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{3,5})", leading_digits: &["(?:25|54)8", "258|5483"], format: "${1} ${2}", national_format: "0${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::FixedLine, NumberDesc { pattern: "(?:1(?:(?:3\\d|9)\\d|[4-8])|2(?:(?:(?:0(?:2[014]|5)|(?:2[0157]|31|84|9)\\d\\d|[56](?:[14]\\d\\d|20)|7(?:[089]|2[03]|[35]\\d\\d))\\d|4(?:2\\d\\d|8))\\d|1(?:2|[39]\\d{4}))|3(?:(?:123|(?:29\\d|92)\\d)\\d\\d|7(?:[19]|[56]\\d))|5(?:0|1[2-478]|26|[37]2|4(?:2\\d{3}|83)|5(?:25\\d\\d|[78])|[689]\\d)|6(?:(?:[16-8]21|28|52[013])\\d\\d|[39])|8(?:[1349]28|523)\\d\\d)\\d{3}|(?:4\\d\\d|9[2-9])\\d{4,5}|(?:(?:2(?:(?:(?:0|8[146])\\d|7[1-7])\\d|2(?:[278]\\d|92)|58(?:2\\d|3))|3(?:[26]|9\\d{3})|5(?:4\\d|5)\\d\\d)\\d|6(?:(?:(?:[0-246]|[78]\\d)\\d|37)\\d|5[2-8]))\\d\\d|(?:2(?:[569]\\d|8[2-57-9])|3(?:[013-59]\\d|8[37])|6[89]8)\\d{3}", lengths: &[5, 6, 7, 8, 9, 10] }),
+        (PhoneType::Mobile, NumberDesc { pattern: "7(?:[1278]\\d|3[1-9])\\d{6}", lengths: &[9] }),
+        (PhoneType::TollFree, NumberDesc { pattern: "80(?:[01]\\d|20|8[0-8])\\d{3}", lengths: &[7] }),
+        (PhoneType::Voip, NumberDesc { pattern: "86(?:1[12]|22|30|44|55|77|8[368])\\d{6}", lengths: &[10] }),
+    ] },
+    // 001
+    Metadata { general: "(?:00|[1-9]\\d)\\d{6}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["\\d"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::TollFree, NumberDesc { pattern: "(?:00|[1-9]\\d)\\d{6}", lengths: &[8] }),
+    ] },
+    // 001
+    Metadata { general: "[1-9]\\d{7}", lengths: &[8], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{4})(\\d{4})", leading_digits: &["[1-9]"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+    ], types: &[
+        (PhoneType::SharedCost, NumberDesc { pattern: "[1-9]\\d{7}", lengths: &[8] }),
+    ] },
+    // 001
+    Metadata { general: "7\\d{11}|[235-7]\\d{8}", lengths: &[9, 12], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[235-7]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::Mobile, NumberDesc { pattern: "(?:[356]|774[45])\\d{8}|7[6-8]\\d{7}", lengths: &[9, 12] }),
+        (PhoneType::Voip, NumberDesc { pattern: "2\\d{8}", lengths: &[9] }),
+    ] },
+    // 001
+    Metadata { general: "10\\d{10}", lengths: &[12], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{5})(\\d{5})", leading_digits: &["1"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::Voip, NumberDesc { pattern: "10\\d{10}", lengths: &[12] }),
+    ] },
+    // 001
+    Metadata { general: "6\\d{9}|[0-36-9]\\d{8}", lengths: &[9, 10], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{5})", leading_digits: &["[0-37-9]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d)(\\d{3})(\\d{5,6})", leading_digits: &["6"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::Mobile, NumberDesc { pattern: "6\\d{9}|[0-36-9]\\d{8}", lengths: &[9, 10] }),
+    ] },
+    // 001
+    Metadata { general: "[13]\\d{6}(?:\\d{2,5})?|[19]\\d{7}|(?:[25]\\d\\d|4)\\d{7}(?:\\d{2})?", lengths: &[7, 8, 9, 10, 11, 12], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{2})(\\d{5})", leading_digits: &["16|342"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{6})", leading_digits: &["49"], format: "${1} ${2}", national_format: "${1} ${2}", intl_format: Some("${1} ${2}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{2})(\\d{4})", leading_digits: &["1[36]|9"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{4})(\\d{3})", leading_digits: &["3[23]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{3,4})(\\d{4})", leading_digits: &["16"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{4})(\\d{4})", leading_digits: &["10|23|3(?:[15]|4[57])|4|51"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{4})(\\d{4})", leading_digits: &["34"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{2})(\\d{4,5})(\\d{5})", leading_digits: &["[1-35]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::Mobile, NumberDesc { pattern: "342\\d{4}|(?:337|49)\\d{6}|(?:3(?:2|47|7\\d{3})|50\\d{3})\\d{7}", lengths: &[7, 8, 9, 10, 12] }),
+        (PhoneType::Voip, NumberDesc { pattern: "1(?:3(?:0[0347]|[13][0139]|2[035]|4[013568]|6[0459]|7[06]|8[15-8]|9[0689])\\d{4}|6\\d{5,10})|(?:345\\d|9[89])\\d{6}|(?:10|2(?:3|85\\d)|3(?:[15]|[69]\\d\\d)|4[15-8]|51)\\d{8}", lengths: &[7, 8, 9, 10, 11, 12] }),
+        (PhoneType::Voicemail, NumberDesc { pattern: "348[57]\\d{7}", lengths: &[11] }),
+    ] },
+    // 001
+    Metadata { general: "(?:[1-4]\\d|51)\\d{6,10}", lengths: &[8, 9, 10, 11, 12], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{2,8})", leading_digits: &["[14]|2[24-689]|3[02-689]|51[24-9]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["510"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{4})", leading_digits: &["21"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{4})(\\d{4})(\\d{4})", leading_digits: &["51[13]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{3})(\\d{3})", leading_digits: &["[235]"], format: "${1} ${2} ${3} ${4}", national_format: "${1} ${2} ${3} ${4}", intl_format: Some("${1} ${2} ${3} ${4}") },
+    ], types: &[
+        (PhoneType::Voip, NumberDesc { pattern: "(?:2(?:00\\d\\d|10)|(?:370[1-9]|51\\d0)\\d)\\d{7}|51(?:00\\d{5}|[24-9]0\\d{4,7})|(?:1[0-79]|2[24-689]|3[02-689]|4[0-4])0\\d{5,9}", lengths: &[8, 9, 10, 11, 12] }),
+    ] },
+    // 001
+    Metadata { general: "\\d{11}", lengths: &[11], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d{3})(\\d{3})(\\d{5})", leading_digits: &[], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::Uan, NumberDesc { pattern: "\\d{11}", lengths: &[11] }),
+    ] },
+    // 001
+    Metadata { general: "[1359]\\d{8}", lengths: &[9], leading_digits: None, national_prefix_for_parsing: None, national_prefix_transform_rule: None, formats: &[
+        NumberFormat { pattern: "(\\d)(\\d{4})(\\d{4})", leading_digits: &["[1359]"], format: "${1} ${2} ${3}", national_format: "${1} ${2} ${3}", intl_format: Some("${1} ${2} ${3}") },
+    ], types: &[
+        (PhoneType::PremiumRate, NumberDesc { pattern: "[1359]\\d{8}", lengths: &[9] }),
+    ] },
+];
@@ -0,0 +1,627 @@
+//! Phone numbers parsed into E.164 parts
+//!
+//! ```
+//! use toolbox::validation::PhoneNumber;
+//!
+//! let typed = PhoneNumber::parse("020 7946 0958", Some("GB")).unwrap();
+//! let pasted = PhoneNumber::parse("+44 (0)20-7946-0958", None).unwrap();
+//!
+//! assert_eq!(typed, pasted);
+//! assert_eq!(typed.e164(), "+442079460958");
+//! assert_eq!(typed.region(), Some("GB"));
+//! ```
+//!
+//! With the `phone_metadata` feature every region's numbering plan is
+//! compiled in too, so a number can be checked against the lengths and
+//! ranges its region actually uses rather than just its digit count.
+
+#[cfg(feature = "phone_metadata")]
+mod format;
+#[cfg(feature = "phone_metadata")]
+mod metadata;
+#[cfg(feature = "phone_metadata")]
+mod number_type;
+mod regions;
+
+#[cfg(feature = "phone_metadata")]
+pub use format::{AsYouType, PhoneFormat};
+#[cfg(feature = "phone_metadata")]
+pub use number_type::PhoneType;
+
+use super::error::{Reason, ValidationError};
+use regex::Regex;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::LazyLock;
+#[cfg(feature = "phone_metadata")]
+use std::sync::OnceLock;
+
+/// Longest E.164 number, country code included
+const MAX_DIGITS: usize = 15;
+
+/// Shortest national number accepted
+const MIN_NATIONAL: usize = 4;
+
+/// A trailing extension: `ext. 12`, `x12`, `#12`, `;ext=12`
+static EXTENSION: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)^(.*?)\s*(?:;\s*ext=|,|\bext(?:ension)?\.?|x|#)\s*(\d{1,7})#?$")
+        .expect("extension pattern")
+});
+
+/// International (IDD) prefixes per region, compiled on first use
+static INTERNATIONAL_PREFIXES: LazyLock<Vec<Option<Regex>>> = LazyLock::new(|| {
+    regions::REGIONS
+        .iter()
+        .map(|region| {
+            // `~` marks "wait for dial tone", which never appears in typed numbers
+            let prefix = region.international_prefix?.replace('~', "");
+            Regex::new(&format!("^(?:{prefix})")).ok()
+        })
+        .collect()
+});
+
+/// Dialing rules for one region, from libphonenumber's metadata
+#[derive(Debug)]
+pub struct Region {
+    /// CLDR region code such as `GB`, or `001` for non-geographic numbers
+    pub id: &'static str,
+
+    /// Country calling code
+    pub code: u16,
+
+    /// Pattern of the prefix dialed before a foreign number, e.g. `00`
+    pub international_prefix: Option<&'static str>,
+
+    /// Trunk prefix dialed before a domestic number, e.g. `0`
+    pub national_prefix: Option<&'static str>,
+
+    /// Whether this is the main region of a shared code (US for `+1`)
+    pub main: bool,
+}
+
+impl Region {
+    /// Look up a region by its code, case-insensitively
+    pub fn get(id: &str) -> Option<&'static Region> {
+        regions::REGIONS
+            .iter()
+            .find(|region| region.id.eq_ignore_ascii_case(id.trim()))
+    }
+
+    /// Every known region
+    pub fn all() -> &'static [Region] {
+        &regions::REGIONS
+    }
+
+    /// The regions using a country calling code
+    pub fn for_code(code: u16) -> impl Iterator<Item = &'static Region> {
+        regions::REGIONS
+            .iter()
+            .filter(move |region| region.code == code)
+    }
+
+    /// The main region of a shared country code, or its only region
+    fn main_for_code(code: u16) -> Option<&'static Region> {
+        let mut regions = Region::for_code(code).peekable();
+        let first = regions.peek().copied();
+        regions.find(|region| region.main).or(first)
+    }
+
+    fn index(&self) -> usize {
+        regions::REGIONS
+            .iter()
+            .position(|region| std::ptr::eq(region, self))
+            .expect("region from the table")
+    }
+
+    /// This region's numbering plan, compiled on first use
+    #[cfg(feature = "phone_metadata")]
+    fn plan(&self) -> &'static Plan {
+        let index = self.index();
+        PLANS[index].get_or_init(|| Plan::compile(&metadata::METADATA[index]))
+    }
+
+    /// Whether `national` is a significant number this region hands out
+    #[cfg(feature = "phone_metadata")]
+    fn accepts(&self, national: &str) -> bool {
+        number_type::classify(self, national) != PhoneType::Unknown
+    }
+}
+
+/// A region's numbering plan as generated from libphonenumber's metadata
+#[cfg(feature = "phone_metadata")]
+pub(crate) struct Metadata {
+    /// Pattern covering every valid national significant number
+    general: &'static str,
+
+    /// Lengths a valid national significant number may have
+    lengths: &'static [u8],
+
+    /// Tells this region apart from others sharing its country code, e.g.
+    /// `876` for Jamaica within `+1`
+    leading_digits: Option<&'static str>,
+
+    /// Trunk prefix (and any carrier code) a domestic caller dials first
+    national_prefix_for_parsing: Option<&'static str>,
+
+    /// Rewrites what `national_prefix_for_parsing` matched, e.g. `9$1`
+    national_prefix_transform_rule: Option<&'static str>,
+
+    /// How numbers are grouped for display, first match wins
+    formats: &'static [NumberFormat],
+
+    /// The ranges of each type of number the region hands out
+    types: &'static [(PhoneType, NumberDesc)],
+}
+
+/// The numbers of one type in a region, e.g. its mobiles
+#[cfg(feature = "phone_metadata")]
+pub(crate) struct NumberDesc {
+    pattern: &'static str,
+    lengths: &'static [u8],
+}
+
+/// One way of grouping national numbers, e.g. `020 7946 0958`
+#[cfg(feature = "phone_metadata")]
+pub(crate) struct NumberFormat {
+    /// Pattern with one group per block of digits
+    pattern: &'static str,
+
+    /// Patterns for the first 3, 4, … digits of the numbers this format is
+    /// for; the last one is the most precise
+    leading_digits: &'static [&'static str],
+
+    /// Replacement for `pattern`, e.g. `${1} ${2}`
+    format: &'static str,
+
+    /// `format` with the trunk prefix, e.g. `0${1} ${2}`
+    national_format: &'static str,
+
+    /// `format` as dialed from abroad, `None` when it cannot be
+    intl_format: Option<&'static str>,
+}
+
+/// [`Metadata`] with its patterns compiled
+#[cfg(feature = "phone_metadata")]
+struct Plan {
+    general: Regex,
+    lengths: &'static [u8],
+    leading_digits: Option<Regex>,
+    national_prefix: Option<Regex>,
+    national_prefix_transform_rule: Option<&'static str>,
+    types: Vec<number_type::Range>,
+}
+
+#[cfg(feature = "phone_metadata")]
+static PLANS: LazyLock<Vec<OnceLock<Plan>>> =
+    LazyLock::new(|| metadata::METADATA.iter().map(|_| OnceLock::new()).collect());
+
+/// Compile every region's plan and formats now instead of on first use
+#[cfg(feature = "phone_metadata")]
+pub(crate) fn preload() {
+    LazyLock::force(&EXTENSION);
+    LazyLock::force(&INTERNATIONAL_PREFIXES);
+    for region in Region::all() {
+        region.plan();
+        format::preload(region);
+    }
+}
+
+#[cfg(feature = "phone_metadata")]
+impl Plan {
+    fn compile(metadata: &Metadata) -> Self {
+        // The generator checks every pattern compiles
+        let anchored = |pattern: &str, end: &str| {
+            Regex::new(&format!("^(?:{pattern}){end}")).expect("generated phone pattern")
+        };
+
+        Self {
+            general: anchored(metadata.general, "$"),
+            lengths: metadata.lengths,
+            leading_digits: metadata.leading_digits.map(|p| anchored(p, "")),
+            national_prefix: metadata
+                .national_prefix_for_parsing
+                .map(|p| anchored(p, "")),
+            national_prefix_transform_rule: metadata.national_prefix_transform_rule,
+            types: metadata
+                .types
+                .iter()
+                .map(|(kind, desc)| number_type::Range::new(*kind, desc))
+                .collect(),
+        }
+    }
+}
+
+/// Split the country calling code off digits that follow a `+`. Calling
+/// codes are prefix-free, so at most one of the 1–3 digit prefixes matches.
+pub(crate) fn split_country_code(digits: &str) -> Option<(u16, &str)> {
+    (1..=3.min(digits.len())).find_map(|len| {
+        let code = digits[..len].parse::<u16>().ok()?;
+        Region::for_code(code)
+            .next()
+            .map(|_| (code, &digits[len..]))
+    })
+}
+
+/// A phone number reduced to its E.164 parts
+///
+/// Two numbers are equal when they dial the same line, however they were
+/// written.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PhoneNumber {
+    country_code: u16,
+    national: String,
+    extension: Option<String>,
+}
+
+impl PhoneNumber {
+    /// Parse a number in international (`+44 …`, `0044 …`), national or
+    /// `tel:` form. National numbers need `default_region`, such as `GB`,
+    /// whose trunk and international prefixes are then understood too.
+    pub fn parse(value: &str, default_region: Option<&str>) -> Result<Self, ValidationError> {
+        let err = |reason| Err(ValidationError::new("phone", reason));
+
+        let trimmed = value.trim();
+        if trimmed.is_empty() {
+            return err(Reason::Empty);
+        }
+
+        let region = match default_region {
+            Some(id) => match Region::get(id) {
+                Some(region) => Some(region),
+                None => return err(Reason::UnknownRegion(id.trim().to_string())),
+            },
+            None => None,
+        };
+
+        // `tel:+44-20-7946-0958;ext=12`
+        let (offset, number) = match trimmed.get(..4) {
+            Some(scheme) if scheme.eq_ignore_ascii_case("tel:") => (4, &trimmed[4..]),
+            _ => (0, trimmed),
+        };
+        let number = match number.split_once(';') {
+            Some((number, params)) => {
+                match params.split(';').find_map(|p| p.strip_prefix("ext=")) {
+                    Some(ext) => format!("{number};ext={ext}"),
+                    None => number.to_string(),
+                }
+            }
+            None => number.to_string(),
+        };
+
+        let (number, extension) = match EXTENSION.captures(&number) {
+            Some(c) => (c[1].to_string(), Some(c[2].to_string())),
+            None => (number, None),
+        };
+
+        for (pos, ch) in number.chars().enumerate() {
+            let allowed = ch.is_ascii_digit() || " -./()".contains(ch) || (ch == '+' && pos == 0);
+            if !allowed {
+                return err(Reason::BadCharacter {
+                    pos: offset + pos,
+                    ch,
+                });
+            }
+        }
+        let digits = number
+            .chars()
+            .filter(char::is_ascii_digit)
+            .collect::<String>();
+
+        // A leading `+` or the region's international prefix starts a
+        // country code; `00` is understood without a region
+        let international = match (number.starts_with('+'), region) {
+            (true, _) => Some(digits.as_str()),
+            (false, Some(region)) => INTERNATIONAL_PREFIXES[region.index()]
+                .as_ref()
+                .and_then(|idd| idd.find(&digits))
+                .map(|idd| &digits[idd.end()..]),
+            (false, None) => digits.strip_prefix("00"),
+        };
+
+        let (country_code, national) = match (international, region) {
+            (Some(digits), _) => match split_country_code(digits) {
+                Some((code, national)) => (code, strip_trunk_in_brackets(code, national, &number)),
+                None => {
+                    let code = digits.chars().take(3).collect();
+                    return err(Reason::UnknownCountryCode(code));
+                }
+            },
+            (None, Some(region)) => (region.code, strip_national_prefix(region, &digits)),
+            (None, None) => return err(Reason::MissingCountryCode),
+        };
+
+        if national.len() < MIN_NATIONAL {
+            return err(Reason::TooShort { min: MIN_NATIONAL });
+        }
+        if country_code.to_string().len() + national.len() > MAX_DIGITS {
+            return err(Reason::TooLong { max: MAX_DIGITS });
+        }
+
+        Ok(Self {
+            country_code,
+            national,
+            extension,
+        })
+    }
+
+    /// The country calling code, e.g. `44`
+    pub fn country_code(&self) -> u16 {
+        self.country_code
+    }
+
+    /// The national significant number: the digits after the country code,
+    /// without any trunk prefix
+    pub fn national_number(&self) -> &str {
+        &self.national
+    }
+
+    pub fn extension(&self) -> Option<&str> {
+        self.extension.as_deref()
+    }
+
+    /// The number as `+<country code><national number>`, without extension
+    pub fn e164(&self) -> String {
+        format!("+{}{}", self.country_code, self.national)
+    }
+
+    /// The main region dialing this country code, e.g. `US` for `+1`. With
+    /// the `phone_metadata` feature, the region whose numbering plan the
+    /// number belongs to, e.g. `JM` for `+1 876 …`.
+    pub fn region(&self) -> Option<&'static str> {
+        #[cfg(feature = "phone_metadata")]
+        if let Some(region) = self.numbering_region() {
+            return Some(region.id);
+        }
+
+        Region::main_for_code(self.country_code).map(|region| region.id)
+    }
+
+    /// Whether the number is in use in the region its country code and
+    /// leading digits point to
+    #[cfg(feature = "phone_metadata")]
+    pub fn is_valid(&self) -> bool {
+        self.numbering_region()
+            .is_some_and(|region| region.accepts(&self.national))
+    }
+
+    /// Whether the number belongs to region `id`, such as `GB`, and is in use
+    /// there. `+44 7624 …` is valid for `IM`, not `GB`.
+    #[cfg(feature = "phone_metadata")]
+    pub fn is_valid_for_region(&self, id: &str) -> bool {
+        self.numbering_region().is_some_and(|region| {
+            region.id.eq_ignore_ascii_case(id.trim()) && region.accepts(&self.national)
+        })
+    }
+
+    /// The region sharing the country code whose leading digits match, or
+    /// else the first one whose plan accepts the number, main region first
+    #[cfg(feature = "phone_metadata")]
+    fn numbering_region(&self) -> Option<&'static Region> {
+        let leading = |region: &&Region| {
+            region
+                .plan()
+                .leading_digits
+                .as_ref()
+                .is_some_and(|leading| leading.is_match(&self.national))
+        };
+
+        let mut regions = Region::for_code(self.country_code).collect::<Vec<_>>();
+        regions.sort_by_key(|region| !region.main);
+        regions.iter().copied().find(leading).or_else(|| {
+            regions
+                .into_iter()
+                .find(|region| region.accepts(&self.national))
+        })
+    }
+}
+
+/// Drop the trunk prefix a domestic caller dials, as in `020 7946 0958`
+#[cfg(not(feature = "phone_metadata"))]
+fn strip_national_prefix(region: &Region, digits: &str) -> String {
+    let national = match region.national_prefix {
+        Some(prefix) => digits
+            .strip_prefix(prefix)
+            .filter(|rest| rest.len() >= MIN_NATIONAL)
+            .unwrap_or(digits),
+        None => digits,
+    };
+    national.to_string()
+}
+
+/// Drop the trunk prefix a domestic caller dials, as in `020 7946 0958`,
+/// applying the region's rewrite rule where it has one (Argentine mobiles
+/// gain a `9`). A number that only fits the plan as written is kept as is.
+#[cfg(feature = "phone_metadata")]
+fn strip_national_prefix(region: &Region, digits: &str) -> String {
+    let plan = region.plan();
+    let Some(caps) = plan
+        .national_prefix
+        .as_ref()
+        .and_then(|re| re.captures(digits))
+    else {
+        return digits.to_string();
+    };
+    let matched = caps.get(0).expect("whole match");
+
+    let stripped = match (
+        plan.national_prefix_transform_rule,
+        caps.iter().last().flatten(),
+    ) {
+        // Only rewrite when the rule's last group took part in the match
+        (Some(rule), Some(_)) if caps.len() > 1 => {
+            let mut rewritten = String::new();
+            caps.expand(rule, &mut rewritten);
+            rewritten + &digits[matched.end()..]
+        }
+        _ => digits[matched.end()..].to_string(),
+    };
+
+    let viable_as_written = plan.general.is_match(digits);
+    if stripped.len() < MIN_NATIONAL || (viable_as_written && !plan.general.is_match(&stripped)) {
+        return digits.to_string();
+    }
+    stripped
+}
+
+/// Drop a trunk prefix written in brackets after the country code, as in
+/// `+44 (0)20 7946 0958`
+fn strip_trunk_in_brackets(code: u16, national: &str, written: &str) -> String {
+    let compact = written.replace([' ', '-', '.', '/'], "");
+    let bracketed = Region::for_code(code)
+        .filter_map(|region| region.national_prefix)
+        .find(|prefix| compact.contains(&format!("{code}({prefix})")));
+
+    match bracketed {
+        Some(prefix) => national
+            .strip_prefix(prefix)
+            .unwrap_or(national)
+            .to_string(),
+        None => national.to_string(),
+    }
+}
+
+impl fmt::Display for PhoneNumber {
+    /// Formats as E.164, followed by ` ext. <n>` when there is an extension
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.e164())?;
+        if let Some(extension) = &self.extension {
+            write!(f, " ext. {extension}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for PhoneNumber {
+    type Err = ValidationError;
+
+    /// Parse an international number; see [`PhoneNumber::parse`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, None)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PhoneNumber {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PhoneNumber {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(value: &str, region: Option<&str>) -> PhoneNumber {
+        PhoneNumber::parse(value, region).unwrap()
+    }
+
+    #[test]
+    fn test_parse() {
+        let us = parse("+1 (555) 123-4567", None);
+        assert_eq!(us.country_code(), 1);
+        assert_eq!(us.national_number(), "5551234567");
+        assert_eq!(us.region(), Some("US"));
+
+        for variant in [
+            ("(555) 123-4567", Some("US")),
+            ("1-555-123-4567", Some("us")),
+            ("011 1 555 123 4567", Some("US")),
+            ("00 1 555.123.4567", None),
+            ("tel:+1-555-123-4567", None),
+        ] {
+            assert_eq!(parse(variant.0, variant.1), us, "{variant:?}");
+        }
+
+        let ng = parse("0803 123 4567", Some("NG"));
+        assert_eq!(ng.e164(), "+2348031234567");
+        let ru = parse("8 (912) 345-67-89", Some("RU"));
+        assert_eq!(ru.e164(), "+79123456789");
+        let it = parse("+39 06 1234 5678", None);
+        assert_eq!(it.national_number(), "0612345678");
+    }
+
+    #[test]
+    fn test_extension() {
+        let number = parse("+44 20 7946 0958 ext. 12", None);
+        assert_eq!(number.extension(), Some("12"));
+        assert_eq!(number.to_string(), "+442079460958 ext. 12");
+        assert_eq!(
+            number.to_string().parse::<PhoneNumber>(),
+            Ok(number.clone())
+        );
+
+        assert_eq!(parse("tel:+442079460958;ext=12", None), number);
+        assert_eq!(parse("020 7946 0958 x12", Some("GB")), number);
+        assert_eq!(parse("+44 20 7946 0958x12", None), number);
+        assert_eq!(parse("+1 650 253 4567x12", None).extension(), Some("12"));
+        assert_ne!(parse("+44 20 7946 0958", None), number);
+    }
+
+    #[test]
+    fn test_errors() {
+        let reason = |value, region| PhoneNumber::parse(value, region).unwrap_err().reason;
+
+        assert_eq!(reason("555 1234", None), Reason::MissingCountryCode);
+        assert_eq!(
+            reason("+999 1234567", None),
+            Reason::UnknownCountryCode("999".into())
+        );
+        assert_eq!(
+            reason("555 1234", Some("XX")),
+            Reason::UnknownRegion("XX".into())
+        );
+        assert_eq!(reason("+1 555", None), Reason::TooShort { min: 4 });
+        assert_eq!(
+            reason("+1 555 0000 0000 0000", None),
+            Reason::TooLong { max: 15 }
+        );
+        assert_eq!(
+            reason("tel:+1-555-CALL", None),
+            Reason::BadCharacter { pos: 11, ch: 'C' }
+        );
+    }
+
+    #[cfg(feature = "phone_metadata")]
+    #[test]
+    fn test_metadata() {
+        let london = parse("+44 20 7946 0958", None);
+        assert!(london.is_valid());
+        assert!(london.is_valid_for_region("gb"));
+        assert!(!london.is_valid_for_region("FR"));
+        assert!(!parse("+44 20 7946 095", None).is_valid());
+
+        // Regions sharing a country code
+        let douglas = parse("+44 1624 612345", None);
+        assert_eq!(douglas.region(), Some("IM"));
+        assert!(douglas.is_valid_for_region("IM"));
+        assert!(!douglas.is_valid_for_region("GB"));
+        assert_eq!(parse("+1 876 555 1234", None).region(), Some("JM"));
+
+        // Trunk prefixes and rewrite rules
+        assert_eq!(parse("612345", Some("IM")), douglas);
+        let ar = parse("011 15 2345 6789", Some("AR"));
+        assert_eq!(ar.e164(), "+5491123456789");
+        assert!(ar.is_valid());
+
+        assert_eq!(
+            crate::validation::validate_phone_for_region("+33 1 23 45 67 89", "gb")
+                .unwrap_err()
+                .reason,
+            Reason::InvalidForRegion("GB".into())
+        );
+        assert_eq!(
+            crate::validation::validate_phone("+44 20 7946 095")
+                .unwrap_err()
+                .reason,
+            Reason::InvalidForRegion("GB".into())
+        );
+    }
+}
@@ -0,0 +1,188 @@
+//! Telling mobile numbers from landlines, toll-free and premium-rate ones
+
+use super::{NumberDesc, PhoneNumber, Region};
+use regex::Regex;
+use std::fmt;
+
+/// What kind of line a number reaches, from its region's number ranges
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "snake_case")
+)]
+pub enum PhoneType {
+    FixedLine,
+    Mobile,
+
+    /// The region does not tell its landlines and mobiles apart, as
+    /// throughout North America
+    FixedLineOrMobile,
+    TollFree,
+    PremiumRate,
+
+    /// Caller and receiver split the cost, e.g. UK `084x` numbers
+    SharedCost,
+    Voip,
+
+    /// Follows a person from line to line, e.g. UK `070` numbers
+    PersonalNumber,
+    Pager,
+
+    /// Universal access number: one number for an organisation's many lines
+    Uan,
+    Voicemail,
+
+    /// Not in any range the region hands out
+    Unknown,
+}
+
+impl PhoneType {
+    /// Whether the number may be a mobile, so can take an SMS
+    pub fn is_mobile(self) -> bool {
+        matches!(self, PhoneType::Mobile | PhoneType::FixedLineOrMobile)
+    }
+}
+
+impl fmt::Display for PhoneType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            PhoneType::FixedLine => "fixed-line",
+            PhoneType::Mobile => "mobile",
+            PhoneType::FixedLineOrMobile => "fixed-line or mobile",
+            PhoneType::TollFree => "toll-free",
+            PhoneType::PremiumRate => "premium-rate",
+            PhoneType::SharedCost => "shared-cost",
+            PhoneType::Voip => "VoIP",
+            PhoneType::PersonalNumber => "personal",
+            PhoneType::Pager => "pager",
+            PhoneType::Uan => "UAN",
+            PhoneType::Voicemail => "voicemail",
+            PhoneType::Unknown => "unknown",
+        };
+        f.write_str(name)
+    }
+}
+
+/// A [`NumberDesc`] with its pattern compiled
+pub(super) struct Range {
+    kind: PhoneType,
+    pattern: Regex,
+    lengths: &'static [u8],
+}
+
+impl Range {
+    pub(super) fn new(kind: PhoneType, desc: &NumberDesc) -> Self {
+        Self {
+            kind,
+            // The generator checks every pattern compiles
+            pattern: Regex::new(&format!("^(?:{})$", desc.pattern))
+                .expect("generated phone pattern"),
+            lengths: desc.lengths,
+        }
+    }
+
+    fn matches(&self, national: &str) -> bool {
+        self.lengths.contains(&(national.len() as u8)) && self.pattern.is_match(national)
+    }
+}
+
+/// The type of a national number under `region`'s plan. Special services
+/// are checked first, as in libphonenumber, since their ranges can overlap
+/// ordinary ones.
+pub(super) fn classify(region: &Region, national: &str) -> PhoneType {
+    let plan = region.plan();
+    if !plan.lengths.contains(&(national.len() as u8)) || !plan.general.is_match(national) {
+        return PhoneType::Unknown;
+    }
+
+    let matches = |kind| {
+        plan.types
+            .iter()
+            .any(|range: &Range| range.kind == kind && range.matches(national))
+    };
+
+    let special = [
+        PhoneType::PremiumRate,
+        PhoneType::TollFree,
+        PhoneType::SharedCost,
+        PhoneType::Voip,
+        PhoneType::PersonalNumber,
+        PhoneType::Pager,
+        PhoneType::Uan,
+        PhoneType::Voicemail,
+    ];
+    if let Some(kind) = special.into_iter().find(|&kind| matches(kind)) {
+        return kind;
+    }
+
+    // Regions whose ranges overlap cannot tell the two apart
+    match (matches(PhoneType::FixedLine), matches(PhoneType::Mobile)) {
+        (true, true) => PhoneType::FixedLineOrMobile,
+        (true, false) => PhoneType::FixedLine,
+        (false, true) => PhoneType::Mobile,
+        (false, false) => PhoneType::Unknown,
+    }
+}
+
+impl PhoneNumber {
+    /// What kind of line the number reaches, or [`PhoneType::Unknown`] when
+    /// it is not valid
+    ///
+    /// ```
+    /// use toolbox::validation::{PhoneNumber, PhoneType};
+    ///
+    /// let number = PhoneNumber::parse("07400 123456", Some("GB")).unwrap();
+    /// assert_eq!(number.number_type(), PhoneType::Mobile);
+    /// assert!(number.is_mobile());
+    /// ```
+    pub fn number_type(&self) -> PhoneType {
+        self.numbering_region()
+            .map_or(PhoneType::Unknown, |region| {
+                classify(region, &self.national)
+            })
+    }
+
+    /// Whether the number may be a mobile, so can take an SMS
+    pub fn is_mobile(&self) -> bool {
+        self.number_type().is_mobile()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::validation::{Reason, validate_mobile};
+
+    #[test]
+    fn test_number_type() {
+        let kind = |value, region| {
+            PhoneNumber::parse(value, Some(region))
+                .unwrap()
+                .number_type()
+        };
+
+        assert_eq!(kind("07400 123456", "GB"), PhoneType::Mobile);
+        assert_eq!(kind("020 7946 0958", "GB"), PhoneType::FixedLine);
+        assert_eq!(kind("0800 123 4567", "GB"), PhoneType::TollFree);
+        assert_eq!(kind("0906 123 4567", "GB"), PhoneType::PremiumRate);
+        assert_eq!(kind("056 1234 5678", "GB"), PhoneType::Voip);
+        assert_eq!(kind("650 253 0000", "US"), PhoneType::FixedLineOrMobile);
+        assert_eq!(kind("555 123 4567", "US"), PhoneType::Unknown);
+        assert_eq!(kind("06 12 34 56 78", "FR"), PhoneType::Mobile);
+
+        assert!(validate_mobile("+234 803 123 4567", None).is_ok());
+        assert_eq!(
+            validate_mobile("020 7946 0958", Some("GB"))
+                .unwrap_err()
+                .reason,
+            Reason::NotMobile("fixed-line".into())
+        );
+        assert_eq!(
+            validate_mobile("555 123 4567", Some("US"))
+                .unwrap_err()
+                .reason,
+            Reason::InvalidForRegion("US".into())
+        );
+    }
+}
@@ -0,0 +1,261 @@
+// @generated by examples/phone_metadata.rs from libphonenumber 9.0.21
+// PhoneNumberMetadata.xml (Apache License 2.0). Do not edit by hand.
+
+use super::Region;
+
+pub(crate) static REGIONS: [Region; 254] = [
+    Region { id: "AC", code: 247, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "AD", code: 376, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "AE", code: 971, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "AF", code: 93, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "AG", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "AI", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "AL", code: 355, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "AM", code: 374, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "AO", code: 244, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "AR", code: 54, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "AS", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "AT", code: 43, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "AU", code: 61, international_prefix: Some("001[14-689]|14(?:1[14]|34|4[17]|[56]6|7[47]|88)0011"), national_prefix: Some("0"), main: true },
+    Region { id: "AW", code: 297, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "AX", code: 358, international_prefix: Some("00|99(?:[01469]|5(?:[14]1|3[23]|5[59]|77|88|9[09]))"), national_prefix: Some("0"), main: false },
+    Region { id: "AZ", code: 994, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "BA", code: 387, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "BB", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "BD", code: 880, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "BE", code: 32, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "BF", code: 226, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "BG", code: 359, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "BH", code: 973, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "BI", code: 257, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "BJ", code: 229, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "BL", code: 590, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "BM", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "BN", code: 673, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "BO", code: 591, international_prefix: Some("00(?:1\\d)?"), national_prefix: Some("0"), main: false },
+    Region { id: "BQ", code: 599, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "BR", code: 55, international_prefix: Some("00(?:1[245]|2[1-35]|31|4[13]|[56]5|99)"), national_prefix: Some("0"), main: false },
+    Region { id: "BS", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "BT", code: 975, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "BW", code: 267, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "BY", code: 375, international_prefix: Some("810"), national_prefix: Some("8"), main: false },
+    Region { id: "BZ", code: 501, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "CA", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "CC", code: 61, international_prefix: Some("001[14-689]|14(?:1[14]|34|4[17]|[56]6|7[47]|88)0011"), national_prefix: Some("0"), main: false },
+    Region { id: "CD", code: 243, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "CF", code: 236, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "CG", code: 242, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "CH", code: 41, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "CI", code: 225, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "CK", code: 682, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "CL", code: 56, international_prefix: Some("(?:0|1(?:1[0-69]|2[02-5]|5[13-58]|69|7[0167]|8[018]))0"), national_prefix: None, main: false },
+    Region { id: "CM", code: 237, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "CN", code: 86, international_prefix: Some("00|1(?:[12]\\d|79)\\d\\d00"), national_prefix: Some("0"), main: false },
+    Region { id: "CO", code: 57, international_prefix: Some("00(?:4(?:[14]4|56)|[579])"), national_prefix: Some("0"), main: false },
+    Region { id: "CR", code: 506, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "CU", code: 53, international_prefix: Some("119"), national_prefix: Some("0"), main: false },
+    Region { id: "CV", code: 238, international_prefix: Some("0"), national_prefix: None, main: false },
+    Region { id: "CW", code: 599, international_prefix: Some("00"), national_prefix: None, main: true },
+    Region { id: "CX", code: 61, international_prefix: Some("001[14-689]|14(?:1[14]|34|4[17]|[56]6|7[47]|88)0011"), national_prefix: Some("0"), main: false },
+    Region { id: "CY", code: 357, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "CZ", code: 420, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "DE", code: 49, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "DJ", code: 253, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "DK", code: 45, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "DM", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "DO", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "DZ", code: 213, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "EC", code: 593, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "EE", code: 372, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "EG", code: 20, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "EH", code: 212, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "ER", code: 291, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "ES", code: 34, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "ET", code: 251, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "FI", code: 358, international_prefix: Some("00|99(?:[01469]|5(?:[14]1|3[23]|5[59]|77|88|9[09]))"), national_prefix: Some("0"), main: true },
+    Region { id: "FJ", code: 679, international_prefix: Some("0(?:0|52)"), national_prefix: None, main: false },
+    Region { id: "FK", code: 500, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "FM", code: 691, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "FO", code: 298, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "FR", code: 33, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "GA", code: 241, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "GB", code: 44, international_prefix: Some("00"), national_prefix: Some("0"), main: true },
+    Region { id: "GD", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "GE", code: 995, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "GF", code: 594, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "GG", code: 44, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "GH", code: 233, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "GI", code: 350, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "GL", code: 299, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "GM", code: 220, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "GN", code: 224, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "GP", code: 590, international_prefix: Some("00"), national_prefix: Some("0"), main: true },
+    Region { id: "GQ", code: 240, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "GR", code: 30, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "GT", code: 502, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "GU", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "GW", code: 245, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "GY", code: 592, international_prefix: Some("001"), national_prefix: None, main: false },
+    Region { id: "HK", code: 852, international_prefix: Some("00(?:30|5[09]|[126-9]?)"), national_prefix: None, main: false },
+    Region { id: "HN", code: 504, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "HR", code: 385, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "HT", code: 509, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "HU", code: 36, international_prefix: Some("00"), national_prefix: Some("06"), main: false },
+    Region { id: "ID", code: 62, international_prefix: Some("00[89]"), national_prefix: Some("0"), main: false },
+    Region { id: "IE", code: 353, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "IL", code: 972, international_prefix: Some("0(?:0|1[2-9])"), national_prefix: Some("0"), main: false },
+    Region { id: "IM", code: 44, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "IN", code: 91, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "IO", code: 246, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "IQ", code: 964, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "IR", code: 98, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "IS", code: 354, international_prefix: Some("00|1(?:0(?:01|[12]0)|100)"), national_prefix: None, main: false },
+    Region { id: "IT", code: 39, international_prefix: Some("00"), national_prefix: None, main: true },
+    Region { id: "JE", code: 44, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "JM", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "JO", code: 962, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "JP", code: 81, international_prefix: Some("010"), national_prefix: Some("0"), main: false },
+    Region { id: "KE", code: 254, international_prefix: Some("000"), national_prefix: Some("0"), main: false },
+    Region { id: "KG", code: 996, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "KH", code: 855, international_prefix: Some("00[14-9]"), national_prefix: Some("0"), main: false },
+    Region { id: "KI", code: 686, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "KM", code: 269, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "KN", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "KP", code: 850, international_prefix: Some("00|99"), national_prefix: Some("0"), main: false },
+    Region { id: "KR", code: 82, international_prefix: Some("00(?:[125689]|3(?:[46]5|91)|7(?:00|27|3|55|6[126]))"), national_prefix: Some("0"), main: false },
+    Region { id: "KW", code: 965, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "KY", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "KZ", code: 7, international_prefix: Some("810"), national_prefix: Some("8"), main: false },
+    Region { id: "LA", code: 856, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "LB", code: 961, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "LC", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "LI", code: 423, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "LK", code: 94, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "LR", code: 231, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "LS", code: 266, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "LT", code: 370, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "LU", code: 352, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "LV", code: 371, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "LY", code: 218, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "MA", code: 212, international_prefix: Some("00"), national_prefix: Some("0"), main: true },
+    Region { id: "MC", code: 377, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "MD", code: 373, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "ME", code: 382, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "MF", code: 590, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "MG", code: 261, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "MH", code: 692, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "MK", code: 389, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "ML", code: 223, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "MM", code: 95, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "MN", code: 976, international_prefix: Some("001"), national_prefix: Some("0"), main: false },
+    Region { id: "MO", code: 853, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "MP", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "MQ", code: 596, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "MR", code: 222, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "MS", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "MT", code: 356, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "MU", code: 230, international_prefix: Some("0(?:0|[24-7]0|3[03])"), national_prefix: None, main: false },
+    Region { id: "MV", code: 960, international_prefix: Some("0(?:0|19)"), national_prefix: None, main: false },
+    Region { id: "MW", code: 265, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "MX", code: 52, international_prefix: Some("0[09]"), national_prefix: None, main: false },
+    Region { id: "MY", code: 60, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "MZ", code: 258, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "NA", code: 264, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "NC", code: 687, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "NE", code: 227, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "NF", code: 672, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "NG", code: 234, international_prefix: Some("009"), national_prefix: Some("0"), main: false },
+    Region { id: "NI", code: 505, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "NL", code: 31, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "NO", code: 47, international_prefix: Some("00"), national_prefix: None, main: true },
+    Region { id: "NP", code: 977, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "NR", code: 674, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "NU", code: 683, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "NZ", code: 64, international_prefix: Some("0(?:0|161)"), national_prefix: Some("0"), main: false },
+    Region { id: "OM", code: 968, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "PA", code: 507, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "PE", code: 51, international_prefix: Some("00|19(?:1[124]|77|90)00"), national_prefix: Some("0"), main: false },
+    Region { id: "PF", code: 689, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "PG", code: 675, international_prefix: Some("00|140[1-3]"), national_prefix: None, main: false },
+    Region { id: "PH", code: 63, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "PK", code: 92, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "PL", code: 48, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "PM", code: 508, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "PR", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "PS", code: 970, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "PT", code: 351, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "PW", code: 680, international_prefix: Some("01[12]"), national_prefix: None, main: false },
+    Region { id: "PY", code: 595, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "QA", code: 974, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "RE", code: 262, international_prefix: Some("00"), national_prefix: Some("0"), main: true },
+    Region { id: "RO", code: 40, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "RS", code: 381, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "RU", code: 7, international_prefix: Some("810"), national_prefix: Some("8"), main: true },
+    Region { id: "RW", code: 250, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "SA", code: 966, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "SB", code: 677, international_prefix: Some("0[01]"), national_prefix: None, main: false },
+    Region { id: "SC", code: 248, international_prefix: Some("010|0[0-2]"), national_prefix: None, main: false },
+    Region { id: "SD", code: 249, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "SE", code: 46, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "SG", code: 65, international_prefix: Some("0[0-3]\\d"), national_prefix: None, main: false },
+    Region { id: "SH", code: 290, international_prefix: Some("00"), national_prefix: None, main: true },
+    Region { id: "SI", code: 386, international_prefix: Some("00|10(?:22|66|88|99)"), national_prefix: Some("0"), main: false },
+    Region { id: "SJ", code: 47, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "SK", code: 421, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "SL", code: 232, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "SM", code: 378, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "SN", code: 221, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "SO", code: 252, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "SR", code: 597, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "SS", code: 211, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "ST", code: 239, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "SV", code: 503, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "SX", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "SY", code: 963, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "SZ", code: 268, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "TA", code: 290, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "TC", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "TD", code: 235, international_prefix: Some("00|16"), national_prefix: None, main: false },
+    Region { id: "TG", code: 228, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "TH", code: 66, international_prefix: Some("00[1-9]"), national_prefix: Some("0"), main: false },
+    Region { id: "TJ", code: 992, international_prefix: Some("810"), national_prefix: None, main: false },
+    Region { id: "TK", code: 690, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "TL", code: 670, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "TM", code: 993, international_prefix: Some("810"), national_prefix: Some("8"), main: false },
+    Region { id: "TN", code: 216, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "TO", code: 676, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "TR", code: 90, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "TT", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "TV", code: 688, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "TW", code: 886, international_prefix: Some("0(?:0[25-79]|19)"), national_prefix: Some("0"), main: false },
+    Region { id: "TZ", code: 255, international_prefix: Some("00[056]"), national_prefix: Some("0"), main: false },
+    Region { id: "UA", code: 380, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "UG", code: 256, international_prefix: Some("00[057]"), national_prefix: Some("0"), main: false },
+    Region { id: "US", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: true },
+    Region { id: "UY", code: 598, international_prefix: Some("0(?:0|1[3-9]\\d)"), national_prefix: Some("0"), main: false },
+    Region { id: "UZ", code: 998, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "VA", code: 39, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "VC", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "VE", code: 58, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "VG", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "VI", code: 1, international_prefix: Some("011"), national_prefix: Some("1"), main: false },
+    Region { id: "VN", code: 84, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "VU", code: 678, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "WF", code: 681, international_prefix: Some("00"), national_prefix: None, main: false },
+    Region { id: "WS", code: 685, international_prefix: Some("0"), national_prefix: None, main: false },
+    Region { id: "XK", code: 383, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "YE", code: 967, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "YT", code: 262, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "ZA", code: 27, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "ZM", code: 260, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "ZW", code: 263, international_prefix: Some("00"), national_prefix: Some("0"), main: false },
+    Region { id: "001", code: 800, international_prefix: None, national_prefix: None, main: false },
+    Region { id: "001", code: 808, international_prefix: None, national_prefix: None, main: false },
+    Region { id: "001", code: 870, international_prefix: None, national_prefix: None, main: false },
+    Region { id: "001", code: 878, international_prefix: None, national_prefix: None, main: false },
+    Region { id: "001", code: 881, international_prefix: None, national_prefix: None, main: false },
+    Region { id: "001", code: 882, international_prefix: None, national_prefix: None, main: false },
+    Region { id: "001", code: 883, international_prefix: None, national_prefix: None, main: false },
+    Region { id: "001", code: 888, international_prefix: None, national_prefix: None, main: false },
+    Region { id: "001", code: 979, international_prefix: None, national_prefix: None, main: false },
+];
@@ -0,0 +1,516 @@
+//! Composable checks for form fields
+//!
+//! Rules report every reason a value fails, not only the first, and
+//! combine with [`Rule::and`], [`Rule::or`], [`Rule::not`] and
+//! [`Rule::optional`]. [`Checks`] runs them over a whole form:
+//!
+//! ```
+//! use toolbox::validation::rules::{email, length, one_of, range};
+//! use toolbox::validation::{Checks, Reason, Rule, Validate, ValidationError};
+//!
+//! struct Signup {
+//!     name: String,
+//!     email: String,
+//!     age: u32,
+//!     plan: String,
+//! }
+//!
+//! impl Validate for Signup {
+//!     fn validate(&self) -> Result<(), Vec<ValidationError>> {
+//!         Checks::new()
+//!             .check("name", &self.name, &length(1, 50))
+//!             .check("email", &self.email, &email())
+//!             .check("age", &self.age, &range(18, 130))
+//!             .check("plan", &self.plan, &one_of(["free", "pro"]))
+//!             .finish()
+//!     }
+//! }
+//!
+//! let signup = Signup {
+//!     name: String::new(),
+//!     email: "john@example.com".into(),
+//!     age: 12,
+//!     plan: "pro".into(),
+//! };
+//! let errors = signup.validate().unwrap_err();
+//! assert_eq!(errors.len(), 2);
+//! assert_eq!(errors[0].field, "name");
+//! assert_eq!(errors[1].to_string(), "The age must be between 18 and 130");
+//!
+//! let code = length(6, 6).and(|v: &str| match v.bytes().all(|b| b.is_ascii_digit()) {
+//!     true => Ok(()),
+//!     false => Err(Reason::Custom("must be digits only".into())),
+//! });
+//! assert_eq!(code.validate("12a").unwrap_err().len(), 2);
+//! ```
+
+use super::error::{Reason, ValidationError};
+use super::validator::Validator;
+use regex::Regex;
+use std::borrow::Borrow;
+use std::fmt;
+use std::net::{IpAddr, Ipv6Addr};
+
+/// A check on one value
+///
+/// Closures from `&T` to `Result<(), Reason>` are rules too.
+pub trait Rule<T: ?Sized> {
+    /// Push every reason `value` breaks the rule onto `failures`
+    fn check(&self, value: &T, failures: &mut Vec<Reason>);
+
+    /// Every reason `value` breaks the rule, or `Ok` if none
+    fn validate(&self, value: &T) -> Result<(), Vec<Reason>> {
+        let mut failures = Vec::new();
+        self.check(value, &mut failures);
+        match failures.is_empty() {
+            true => Ok(()),
+            false => Err(failures),
+        }
+    }
+
+    /// Both rules must pass; the failures of each are reported
+    fn and<R: Rule<T>>(self, other: R) -> And<Self, R>
+    where
+        Self: Sized,
+    {
+        And(self, other)
+    }
+
+    /// Either rule may pass; if neither does, both sets of failures are
+    /// reported
+    fn or<R: Rule<T>>(self, other: R) -> Or<Self, R>
+    where
+        Self: Sized,
+    {
+        Or(self, other)
+    }
+
+    /// Fails with `reason` when this rule passes
+    fn not(self, reason: Reason) -> Not<Self>
+    where
+        Self: Sized,
+    {
+        Not(self, reason)
+    }
+
+    /// Passes blank strings and `None` without checking them
+    fn optional(self) -> Optional<Self>
+    where
+        Self: Sized,
+    {
+        Optional(self)
+    }
+}
+
+impl<T: ?Sized, F> Rule<T> for F
+where
+    F: Fn(&T) -> Result<(), Reason>,
+{
+    fn check(&self, value: &T, failures: &mut Vec<Reason>) {
+        if let Err(reason) = self(value) {
+            failures.push(reason);
+        }
+    }
+}
+
+/// See [`Rule::and`]
+#[derive(Clone, Debug)]
+pub struct And<A, B>(A, B);
+
+impl<T: ?Sized, A: Rule<T>, B: Rule<T>> Rule<T> for And<A, B> {
+    fn check(&self, value: &T, failures: &mut Vec<Reason>) {
+        self.0.check(value, failures);
+        self.1.check(value, failures);
+    }
+}
+
+/// See [`Rule::or`]
+#[derive(Clone, Debug)]
+pub struct Or<A, B>(A, B);
+
+impl<T: ?Sized, A: Rule<T>, B: Rule<T>> Rule<T> for Or<A, B> {
+    fn check(&self, value: &T, failures: &mut Vec<Reason>) {
+        let Err(mut first) = self.0.validate(value) else {
+            return;
+        };
+        let Err(second) = self.1.validate(value) else {
+            return;
+        };
+        failures.append(&mut first);
+        failures.extend(second);
+    }
+}
+
+/// See [`Rule::not`]
+#[derive(Clone, Debug)]
+pub struct Not<R>(R, Reason);
+
+impl<T: ?Sized, R: Rule<T>> Rule<T> for Not<R> {
+    fn check(&self, value: &T, failures: &mut Vec<Reason>) {
+        if self.0.validate(value).is_ok() {
+            failures.push(self.1.clone());
+        }
+    }
+}
+
+/// See [`Rule::optional`]
+#[derive(Clone, Debug)]
+pub struct Optional<R>(R);
+
+impl<R: Rule<str>> Rule<str> for Optional<R> {
+    fn check(&self, value: &str, failures: &mut Vec<Reason>) {
+        if !value.trim().is_empty() {
+            self.0.check(value, failures);
+        }
+    }
+}
+
+impl<T, R: Rule<T>> Rule<Option<T>> for Optional<R> {
+    fn check(&self, value: &Option<T>, failures: &mut Vec<Reason>) {
+        if let Some(value) = value {
+            self.0.check(value, failures);
+        }
+    }
+}
+
+/// See [`length`]
+#[derive(Clone, Copy, Debug)]
+pub struct Length {
+    min: usize,
+    max: usize,
+}
+
+/// Between `min` and `max` characters, after trimming
+pub fn length(min: usize, max: usize) -> Length {
+    Length { min, max }
+}
+
+impl Rule<str> for Length {
+    fn check(&self, value: &str, failures: &mut Vec<Reason>) {
+        let len = value.trim().chars().count();
+        if len < self.min {
+            failures.push(match len {
+                0 => Reason::Empty,
+                _ => Reason::TooShort { min: self.min },
+            });
+        }
+        if len > self.max {
+            failures.push(Reason::TooLong { max: self.max });
+        }
+    }
+}
+
+/// See [`range`], [`at_least`] and [`at_most`]
+#[derive(Clone, Copy, Debug)]
+pub struct Range<N> {
+    min: Option<N>,
+    max: Option<N>,
+}
+
+/// From `min` to `max`, both included
+pub fn range<N>(min: N, max: N) -> Range<N> {
+    Range {
+        min: Some(min),
+        max: Some(max),
+    }
+}
+
+/// `min` or more
+pub fn at_least<N>(min: N) -> Range<N> {
+    Range {
+        min: Some(min),
+        max: None,
+    }
+}
+
+/// Up to `max`, included
+pub fn at_most<N>(max: N) -> Range<N> {
+    Range {
+        min: None,
+        max: Some(max),
+    }
+}
+
+impl<N: PartialOrd + fmt::Display> Rule<N> for Range<N> {
+    fn check(&self, value: &N, failures: &mut Vec<Reason>) {
+        let below = self.min.as_ref().is_some_and(|min| value < min);
+        let above = self.max.as_ref().is_some_and(|max| value > max);
+        if below || above {
+            failures.push(Reason::OutOfRange {
+                min: self.min.as_ref().map(ToString::to_string),
+                max: self.max.as_ref().map(ToString::to_string),
+            });
+        }
+    }
+}
+
+/// See [`pattern`]
+#[derive(Clone, Debug)]
+pub struct Pattern(Regex);
+
+/// Matches `regex`; anchor it with `^…$` to match the whole value
+pub fn pattern(regex: Regex) -> Pattern {
+    Pattern(regex)
+}
+
+impl Rule<str> for Pattern {
+    fn check(&self, value: &str, failures: &mut Vec<Reason>) {
+        if !self.0.is_match(value) {
+            failures.push(Reason::BadFormat);
+        }
+    }
+}
+
+/// See [`one_of`]
+#[derive(Clone, Debug)]
+pub struct OneOf<V>(Vec<V>);
+
+/// Exactly one of `choices`, e.g. plan names; for other types, use a
+/// closure
+pub fn one_of<V: AsRef<str>>(choices: impl IntoIterator<Item = V>) -> OneOf<V> {
+    OneOf(choices.into_iter().collect())
+}
+
+impl<V: AsRef<str>> Rule<str> for OneOf<V> {
+    fn check(&self, value: &str, failures: &mut Vec<Reason>) {
+        if !self.0.iter().any(|choice| choice.as_ref() == value) {
+            failures.push(Reason::NotAllowed);
+        }
+    }
+}
+
+/// One of the formats [`Validator`] knows, checked by the global one
+#[derive(Clone, Copy, Debug)]
+pub struct Format(FormatKind);
+
+#[derive(Clone, Copy, Debug)]
+enum FormatKind {
+    Email,
+    Phone,
+    Url,
+    Ip,
+}
+
+/// An email address, as [`Validator::validate_email`] takes it
+pub fn email() -> Format {
+    Format(FormatKind::Email)
+}
+
+/// A phone number, as [`Validator::validate_phone`] takes it
+pub fn phone() -> Format {
+    Format(FormatKind::Phone)
+}
+
+/// An absolute `http` or `https` URL
+pub fn url() -> Format {
+    Format(FormatKind::Url)
+}
+
+/// An IPv4 or IPv6 address
+pub fn ip() -> Format {
+    Format(FormatKind::Ip)
+}
+
+impl Rule<str> for Format {
+    fn check(&self, value: &str, failures: &mut Vec<Reason>) {
+        let validator = Validator::global();
+        let result = match self.0 {
+            FormatKind::Email => validator.validate_email(value).map(drop),
+            FormatKind::Phone => validator.validate_phone(value).map(drop),
+            FormatKind::Url => check_url(value),
+            FormatKind::Ip => match value.trim().parse::<IpAddr>() {
+                Ok(_) => Ok(()),
+                Err(_) => Err(ValidationError::new("ip", Reason::BadFormat)),
+            },
+        };
+        if let Err(e) = result {
+            failures.push(e.reason);
+        }
+    }
+}
+
+/// `http(s)://host[:port][/…]`, with a host name, IPv4 address or
+/// bracketed IPv6 address, and no whitespace
+fn check_url(value: &str) -> Result<(), ValidationError> {
+    let err = |reason| Err(ValidationError::new("url", reason));
+
+    let value = value.trim();
+    if value.is_empty() {
+        return err(Reason::Empty);
+    }
+    if let Some((pos, ch)) = value.chars().enumerate().find(|(_, ch)| ch.is_whitespace()) {
+        return err(Reason::BadCharacter { pos, ch });
+    }
+
+    let Some((scheme, rest)) = value.split_once("://") else {
+        return err(Reason::BadFormat);
+    };
+    if !scheme.eq_ignore_ascii_case("http") && !scheme.eq_ignore_ascii_case("https") {
+        return err(Reason::BadFormat);
+    }
+
+    let authority = rest.split(['/', '?', '#']).next().unwrap_or_default();
+    let host_port = authority.rsplit('@').next().unwrap_or_default();
+    let (host_ok, port) = match host_port.strip_prefix('[') {
+        Some(bracketed) => match bracketed.split_once(']') {
+            Some((ipv6, port)) => (ipv6.parse::<Ipv6Addr>().is_ok(), port),
+            None => return err(Reason::BadFormat),
+        },
+        None => {
+            let (host, port) = match host_port.find(':') {
+                Some(colon) => host_port.split_at(colon),
+                None => (host_port, ""),
+            };
+            let host_ok = host.split('.').all(|label| {
+                !label.is_empty()
+                    && !label.starts_with('-')
+                    && !label.ends_with('-')
+                    && label.chars().all(|ch| ch.is_alphanumeric() || ch == '-')
+            });
+            (host_ok, port)
+        }
+    };
+
+    let port_ok = match port.strip_prefix(':') {
+        Some(port) => port.parse::<u16>().is_ok(),
+        None => port.is_empty(),
+    };
+    match host_ok && port_ok {
+        true => Ok(()),
+        false => err(Reason::BadFormat),
+    }
+}
+
+/// A type that checks all its own fields
+pub trait Validate {
+    /// Every failure, each against its field
+    fn validate(&self) -> Result<(), Vec<ValidationError>>;
+}
+
+/// Runs rules over several fields, collecting every failure
+#[derive(Clone, Debug, Default)]
+pub struct Checks {
+    errors: Vec<ValidationError>,
+}
+
+impl Checks {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Check `value` against `rule`, reporting failures against `field`.
+    /// Values are borrowed as the rule takes them, so a `String` field
+    /// goes to a `str` rule as is.
+    pub fn check<T, V>(&mut self, field: &str, value: &V, rule: &impl Rule<T>) -> &mut Self
+    where
+        T: ?Sized,
+        V: Borrow<T> + ?Sized,
+    {
+        let mut failures = Vec::new();
+        rule.check(value.borrow(), &mut failures);
+        self.errors.extend(
+            failures
+                .into_iter()
+                .map(|reason| ValidationError::new(field, reason)),
+        );
+        self
+    }
+
+    /// Every failure so far, or `Ok` if there were none
+    pub fn finish(&mut self) -> Result<(), Vec<ValidationError>> {
+        match self.errors.is_empty() {
+            true => Ok(()),
+            false => Err(std::mem::take(&mut self.errors)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rules() {
+        assert!(length(2, 4).validate("abc").is_ok());
+        assert_eq!(length(2, 4).validate("  ").unwrap_err(), [Reason::Empty]);
+        assert_eq!(
+            length(2, 4).validate("abcde").unwrap_err(),
+            [Reason::TooLong { max: 4 }]
+        );
+        assert_eq!(
+            at_least(1.5).validate(&1.0).unwrap_err()[0].to_string(),
+            "must be at least 1.5"
+        );
+
+        let slug = pattern(Regex::new("^[a-z-]+$").unwrap());
+        assert!(slug.validate("a-slug").is_ok());
+        assert_eq!(slug.validate("A Slug").unwrap_err(), [Reason::BadFormat]);
+
+        assert!(email().validate("john@example.com").is_ok());
+        assert_eq!(email().validate("john").unwrap_err(), [Reason::MissingAt]);
+        assert!(phone().validate("+44 20 7946 0958").is_ok());
+        assert!(ip().validate("2001:db8::1").is_ok());
+        assert!(ip().validate("300.1.1.1").is_err());
+
+        for valid in [
+            "https://example.com",
+            "http://example.com:8080/path?q=1#top",
+            "https://user@192.0.2.1/",
+            "http://[2001:db8::1]:80/",
+        ] {
+            assert!(url().validate(valid).is_ok(), "{valid}");
+        }
+        for invalid in [
+            "example.com",
+            "ftp://example.com",
+            "https://",
+            "https://exa mple.com",
+            "https://-example.com",
+            "https://example.com:99999",
+            "http://[::zz]/",
+        ] {
+            assert!(url().validate(invalid).is_err(), "{invalid}");
+        }
+
+        // Combinators
+        let contact = email().or(phone());
+        assert!(contact.validate("john@example.com").is_ok());
+        assert!(contact.validate("+44 20 7946 0958").is_ok());
+        assert_eq!(contact.validate("nope").unwrap_err().len(), 2);
+
+        let username =
+            length(3, 16).and(one_of(["admin", "root"]).not(Reason::Custom("is reserved".into())));
+        assert!(username.validate("jane").is_ok());
+        assert_eq!(
+            username.validate("admin").unwrap_err(),
+            [Reason::Custom("is reserved".into())]
+        );
+        assert_eq!(username.validate("x").unwrap_err().len(), 1);
+
+        let website = url().optional();
+        assert!(website.validate("").is_ok());
+        assert!(website.validate("nope").is_err());
+        assert!(range(1, 10).optional().validate(&None).is_ok());
+        assert!(range(1, 10).optional().validate(&Some(11)).is_err());
+
+        let errors = Checks::new()
+            .check("name", "", &length(1, 10))
+            .check("age", &200, &range(0, 150))
+            .check("email", "john@example.com", &email())
+            .finish()
+            .unwrap_err();
+        assert_eq!(
+            errors,
+            [
+                ValidationError::new("name", Reason::Empty),
+                ValidationError::new(
+                    "age",
+                    Reason::OutOfRange {
+                        min: Some("0".into()),
+                        max: Some("150".into())
+                    }
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,309 @@
+use super::email::EmailAddress;
+use super::error::{Reason, ValidationError};
+#[cfg(feature = "phone_metadata")]
+use super::phone::PhoneNumber;
+use super::phone::split_country_code;
+use regex::Regex;
+use std::sync::LazyLock;
+use std::thread;
+
+/// Below this many items `validate_many` stays on the calling thread
+const PARALLEL_THRESHOLD: usize = 10_000;
+
+static GLOBAL: LazyLock<Validator> = LazyLock::new(Validator::new);
+
+/// What [`Validator::validate_many`] checks each item as
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Kind {
+    Email,
+    Phone,
+    MeansOfAuth,
+}
+
+/// Checks emails and phone numbers against a phone pattern compiled once.
+/// With the `phone_metadata` feature, each region's numbering plan is
+/// compiled the first time a number from it is checked and then shared by
+/// every validator; [`Validator::preload`] compiles them all up front.
+///
+/// It is `Send + Sync`; build one and share it, or use [`Validator::global`].
+#[derive(Clone, Debug)]
+pub struct Validator {
+    phone: Regex,
+}
+
+impl Default for Validator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Validator {
+    pub fn new() -> Self {
+        Self {
+            phone: Regex::new(
+                r"^(?:\+?(\d{1,3}))?[-. (]*(\d{1,4})[-. )]*(\d{1,4})[-. ]*(\d{1,9})$",
+            )
+            .expect("phone pattern"),
+        }
+    }
+
+    /// The validator behind the free functions, built on first use
+    pub fn global() -> &'static Validator {
+        &GLOBAL
+    }
+
+    /// Compile every region's numbering plan now, so no later check pays
+    /// for it, e.g. at startup before serving requests. Without the
+    /// `phone_metadata` feature there is nothing to compile.
+    pub fn preload(&self) -> &Self {
+        #[cfg(feature = "phone_metadata")]
+        super::phone::preload();
+        self
+    }
+
+    /// Check if a string is a valid email address or phone number
+    pub fn is_means_of_auth(&self, value: &str) -> bool {
+        self.is_email(value) || self.is_phone(value)
+    }
+
+    /// Check if a string is a valid phone number
+    pub fn is_phone(&self, value: &str) -> bool {
+        self.validate_phone(value).is_ok()
+    }
+
+    /// Check if a string is a valid email address
+    pub fn is_email(&self, value: &str) -> bool {
+        self.validate_email(value).is_ok()
+    }
+
+    /// Validate an email address or phone number. Input containing `@` is
+    /// reported as an email address, anything else as a phone number.
+    pub fn validate_means_of_auth(&self, value: &str) -> Result<String, ValidationError> {
+        let email = self.validate_email(value);
+        if email.is_ok() || value.contains('@') {
+            return email;
+        }
+        self.validate_phone(value)
+    }
+
+    /// Validate a phone number, returning its digits with any leading `+`.
+    /// With the `phone_metadata` feature, international numbers must also be
+    /// valid for their region.
+    pub fn validate_phone(&self, value: &str) -> Result<String, ValidationError> {
+        let err = |reason| Err(ValidationError::new("phone", reason));
+
+        let normalized = value.trim();
+        if normalized.is_empty() {
+            return err(Reason::Empty);
+        }
+
+        for (pos, ch) in normalized.chars().enumerate() {
+            let allowed = ch.is_ascii_digit() || " -.()".contains(ch) || (ch == '+' && pos == 0);
+            if !allowed {
+                return err(Reason::BadCharacter { pos, ch });
+            }
+        }
+
+        // Clean phone number for validation: remove all non-digit characters except leading +
+        let phone_clean = normalized
+            .chars()
+            .enumerate()
+            .filter(|(i, c)| *c == '+' && *i == 0 || c.is_ascii_digit())
+            .map(|(_, c)| c)
+            .collect::<String>();
+
+        // Validate phone number length (international standards)
+        let digit_count = phone_clean.chars().filter(|c| c.is_ascii_digit()).count();
+        if digit_count < 7 {
+            return err(Reason::TooShort { min: 7 });
+        }
+        if digit_count > 15 {
+            return err(Reason::TooLong { max: 15 });
+        }
+
+        if !self.phone.is_match(normalized) {
+            return err(Reason::BadFormat);
+        }
+
+        // Additional validation: country code if present should be valid
+        if let Some(digits) = phone_clean.strip_prefix('+')
+            && split_country_code(digits).is_none()
+        {
+            let country_code = digits.chars().take(3).collect::<String>();
+            return err(Reason::UnknownCountryCode(country_code));
+        }
+
+        #[cfg(feature = "phone_metadata")]
+        if phone_clean.starts_with('+') {
+            let number = PhoneNumber::parse(&phone_clean, None)?;
+            if !number.is_valid() {
+                let region = number.region().unwrap_or_default().to_string();
+                return err(Reason::InvalidForRegion(region));
+            }
+        }
+
+        Ok(phone_clean)
+    }
+
+    /// Validate an email address as an SMTP mailbox (see
+    /// [`EmailAddress::parse`]), returning it trimmed with its domain
+    /// lowercased. The local part keeps its case, which only the receiving
+    /// server may fold.
+    pub fn validate_email(&self, value: &str) -> Result<String, ValidationError> {
+        EmailAddress::parse(value).map(|address| address.to_string())
+    }
+
+    /// Check one value as `kind`
+    pub fn validate(&self, kind: Kind, value: &str) -> Result<String, ValidationError> {
+        match kind {
+            Kind::Email => self.validate_email(value),
+            Kind::Phone => self.validate_phone(value),
+            Kind::MeansOfAuth => self.validate_means_of_auth(value),
+        }
+    }
+
+    /// Check every value as `kind`, returning one result per value in the
+    /// same order. Large batches are split across the available cores.
+    pub fn validate_many<S: AsRef<str> + Sync>(
+        &self,
+        kind: Kind,
+        values: &[S],
+    ) -> Vec<Result<String, ValidationError>> {
+        let workers = thread::available_parallelism().map_or(1, |n| n.get());
+        if values.len() < PARALLEL_THRESHOLD || workers == 1 {
+            return values
+                .iter()
+                .map(|value| self.validate(kind, value.as_ref()))
+                .collect();
+        }
+
+        let chunk = values.len().div_ceil(workers);
+        thread::scope(|scope| {
+            let handles = values
+                .chunks(chunk)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|value| self.validate(kind, value.as_ref()))
+                            .collect::<Vec<_>>()
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            handles
+                .into_iter()
+                .flat_map(|handle| handle.join().expect("validation worker panicked"))
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checks() {
+        let validator = Validator::global().preload();
+
+        assert!(validator.is_email("john.doe@example.com"));
+        assert!(!validator.is_email("john.doe@example"));
+        assert!(!validator.is_email("  "));
+        assert!(!validator.is_email("john..doe@example.com"));
+        assert!(validator.is_email("user@[192.0.2.1]"));
+
+        assert!(validator.is_phone("+234 803 123 4567"));
+        assert!(validator.is_phone("+44 20 7946 0958"));
+        assert!(validator.is_phone("(555) 123-4567"));
+        assert!(!validator.is_phone("+999 1234567"));
+        assert!(!validator.is_phone("12345"));
+
+        assert!(validator.is_means_of_auth("555-123-4567"));
+        assert!(!validator.is_means_of_auth("hello"));
+    }
+
+    #[test]
+    fn test_validate_many() {
+        let rows = ["a@example.com", "nope", "b@example.org"];
+        let results = Validator::new().validate_many(Kind::Email, &rows);
+        assert_eq!(
+            results.iter().map(Result::is_ok).collect::<Vec<_>>(),
+            [true, false, true]
+        );
+
+        // Enough rows to take the threaded path, order preserved
+        let rows = (0..PARALLEL_THRESHOLD + 7)
+            .map(|i| match i % 3 {
+                0 => format!("user{i}@example.com"),
+                _ => format!("user{i}"),
+            })
+            .collect::<Vec<_>>();
+        let results = Validator::global().validate_many(Kind::Email, &rows);
+        assert_eq!(results.len(), rows.len());
+        assert!(
+            results
+                .iter()
+                .enumerate()
+                .all(|(i, r)| r.is_ok() == (i % 3 == 0))
+        );
+    }
+
+    #[test]
+    fn test_reasons() {
+        let validator = Validator::global();
+        let reason = |result: Result<String, ValidationError>| result.unwrap_err().reason;
+
+        assert_eq!(
+            validator.validate_email("  John.Doe@Example.COM "),
+            Ok("John.Doe@example.com".to_string())
+        );
+        assert_eq!(
+            validator.validate_email("\"Jo Hn\"@Example.com"),
+            Ok("\"Jo Hn\"@example.com".to_string())
+        );
+        assert_eq!(reason(validator.validate_email(" ")), Reason::Empty);
+        assert_eq!(reason(validator.validate_email("john")), Reason::MissingAt);
+        assert_eq!(
+            reason(validator.validate_email("@example.com")),
+            Reason::MissingLocalPart
+        );
+        assert_eq!(
+            reason(validator.validate_email("john@")),
+            Reason::MissingDomain
+        );
+        assert_eq!(
+            reason(validator.validate_email("john@localhost")),
+            Reason::MissingTld
+        );
+        assert_eq!(
+            reason(validator.validate_email("jo hn@example.com")),
+            Reason::BadCharacter { pos: 2, ch: ' ' }
+        );
+        assert_eq!(
+            reason(validator.validate_email("john@example..com")),
+            Reason::BadCharacter { pos: 13, ch: '.' }
+        );
+
+        assert_eq!(
+            validator.validate_phone("+234 (803) 123-4567"),
+            Ok("+2348031234567".to_string())
+        );
+        assert_eq!(
+            reason(validator.validate_phone("12345")),
+            Reason::TooShort { min: 7 }
+        );
+        assert_eq!(
+            reason(validator.validate_phone("555-CALL-NOW")),
+            Reason::BadCharacter { pos: 4, ch: 'C' }
+        );
+        assert_eq!(
+            reason(validator.validate_phone("+999 1234567")),
+            Reason::UnknownCountryCode("999".to_string())
+        );
+
+        let err = validator.validate_means_of_auth("john@").unwrap_err();
+        assert_eq!(err.field, "email");
+        assert_eq!(err.to_string(), "The email is missing the domain after @");
+    }
+}